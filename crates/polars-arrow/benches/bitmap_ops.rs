@@ -0,0 +1,36 @@
+#![feature(test)]
+extern crate test;
+
+use polars_arrow::bitmap::{Bitmap, MutableBitmap, or, sparse_or};
+use test::Bencher;
+
+const LEN: usize = 1 << 20;
+const N_MASKS: usize = 64;
+
+/// `N_MASKS` masks of length `LEN` that each have a single bit set, spread over the length.
+fn single_bit_masks() -> Vec<Bitmap> {
+    (0..N_MASKS)
+        .map(|i| {
+            let mut mask = MutableBitmap::from_len_zeroed(LEN);
+            mask.set(i * (LEN / N_MASKS) + i, true);
+            mask.into()
+        })
+        .collect()
+}
+
+#[bench]
+fn bench_sparse_or_single_bit_masks(b: &mut Bencher) {
+    let masks = single_bit_masks();
+    let masks = masks.iter().collect::<Vec<_>>();
+    b.iter(|| sparse_or(&masks));
+}
+
+#[bench]
+fn bench_or_fold_single_bit_masks(b: &mut Bencher) {
+    let masks = single_bit_masks();
+    b.iter(|| {
+        masks[1..]
+            .iter()
+            .fold(masks[0].clone(), |acc, mask| or(&acc, mask))
+    });
+}
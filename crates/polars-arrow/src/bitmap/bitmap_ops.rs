@@ -175,6 +175,34 @@ where
     fold(result, op(rem_lhs, rem_rhs))
 }
 
+/// Fold the bits of `bitmap` as `u64` words, least significant bit first.
+///
+/// If the length isn't a multiple of 64 the last word holds the remaining bits, with the bits
+/// past the end of the bitmap set to zero.
+pub fn fold_chunks<B, F>(bitmap: &Bitmap, init: B, f: F) -> B
+where
+    F: Fn(B, u64) -> B,
+{
+    let chunks = bitmap.chunks::<u64>();
+    let remainder_len = bitmap.len() % 64;
+    let remainder = chunks.remainder() & ((1u64 << remainder_len) - 1);
+
+    let result = chunks.fold(init, &f);
+    if remainder_len == 0 {
+        result
+    } else {
+        f(result, remainder)
+    }
+}
+
+/// Whether the number of set bits in `bitmap` is odd.
+///
+/// This XORs the words together and only counts the bits of the result, which is cheaper than
+/// [`Bitmap::set_bits`] if the count isn't cached.
+pub fn parity(bitmap: &Bitmap) -> bool {
+    fold_chunks(bitmap, 0u64, |acc, word| acc ^ word).count_ones() % 2 == 1
+}
+
 fn unary_impl<F, I>(iter: I, op: F, length: usize) -> Bitmap
 where
     I: BitChunkIterExact<u64>,
@@ -187,6 +215,10 @@ where
 }
 
 /// Apply a bitwise operation `op` to one input and return the result as a [`Bitmap`].
+///
+/// The bits are passed to `op` as `u64` words, least significant bit first. If the length isn't a
+/// multiple of 64 the last word holds the remaining bits in its low bits, and any bits `op` moves
+/// past the end of the bitmap are dropped.
 pub fn unary<F>(lhs: &Bitmap, op: F) -> Bitmap
 where
     F: Fn(u64) -> u64,
@@ -212,9 +244,18 @@ pub(crate) fn align(bitmap: &Bitmap, new_offset: usize) -> Bitmap {
     bitmap.sliced(new_offset, length)
 }
 
+/// Whether both bitmaps are views of the same bits in the same buffer.
+fn same_bits(lhs: &Bitmap, rhs: &Bitmap) -> bool {
+    let (lhs_bytes, lhs_offset, lhs_len) = lhs.as_slice();
+    let (rhs_bytes, rhs_offset, rhs_len) = rhs.as_slice();
+    lhs_len == rhs_len && lhs_offset == rhs_offset && std::ptr::eq(lhs_bytes, rhs_bytes)
+}
+
 /// Compute bitwise A AND B operation.
 pub fn and(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
-    if lhs.unset_bits() == lhs.len() || rhs.unset_bits() == rhs.len() {
+    if same_bits(lhs, rhs) {
+        lhs.clone()
+    } else if lhs.unset_bits() == lhs.len() || rhs.unset_bits() == rhs.len() {
         assert_eq!(lhs.len(), rhs.len());
         Bitmap::new_zeroed(lhs.len())
     } else {
@@ -222,14 +263,117 @@ pub fn and(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
     }
 }
 
+/// Compute the bitwise AND of all `masks` in a single pass, without intermediate bitmaps.
+///
+/// # Panics
+/// Panics if `masks` is empty or if the bitmaps have different lengths.
+pub fn all_and(masks: &[&Bitmap]) -> Bitmap {
+    let (first, rest) = masks
+        .split_first()
+        .expect("all_and requires at least one bitmap");
+    let length = first.len();
+    assert!(rest.iter().all(|mask| mask.len() == length));
+
+    match rest {
+        [] => return (*first).clone(),
+        [other] => return and(first, other),
+        _ => {},
+    }
+    if masks
+        .iter()
+        .any(|mask| mask.lazy_unset_bits() == Some(length))
+    {
+        return Bitmap::new_zeroed(length);
+    }
+
+    let mut chunks = masks
+        .iter()
+        .map(|mask| mask.chunks::<u64>())
+        .collect::<Vec<_>>();
+    let remainder = chunks
+        .iter()
+        .fold(u64::MAX, |acc, chunks| acc & chunks.remainder());
+    let words = (0..length / 64).map(|_| {
+        chunks
+            .iter_mut()
+            .fold(u64::MAX, |acc, chunks| acc & chunks.next().unwrap())
+    });
+
+    let buffer = chunk_iter_to_vec_and_remainder(words, remainder);
+    Bitmap::from_u8_vec(buffer, length)
+}
+
 /// Compute bitwise A AND NOT B operation.
 pub fn and_not(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
     binary(lhs, rhs, |x, y| x & !y)
 }
 
+/// Check that the bits past the end of `bitmap` in its last byte are unset, in debug builds.
+///
+/// Kernels that build a [`Bitmap`] word by word can call this on their output to catch garbage
+/// left in the padding. Not every bitmap upholds this: slicing keeps the bits past the new end,
+/// and e.g. negating with [`unary`] sets them.
+///
+/// # Panics
+/// Panics in debug builds if a padding bit is set.
+#[inline]
+pub fn debug_assert_padding_zeroed(bitmap: &Bitmap) {
+    if cfg!(debug_assertions) {
+        let (slice, offset, length) = bitmap.as_slice();
+        let end = offset + length;
+        if end % 8 != 0 {
+            let padding = slice[end / 8] >> (end % 8);
+            assert!(
+                padding == 0,
+                "bitmap of length {length} has set padding bits: {padding:#010b}"
+            );
+        }
+    }
+}
+
+/// Bitwise OR of many masks of the same length, e.g. the selections of several predicates.
+///
+/// Only the words between the first and last set bit of each mask are visited, which is much
+/// cheaper than [`or`]-ing full masks if they are mostly unset.
+///
+/// # Panics
+/// Panics if `masks` is empty or the masks differ in length.
+pub fn sparse_or(masks: &[&Bitmap]) -> Bitmap {
+    let length = masks
+        .first()
+        .expect("sparse_or requires at least one bitmap")
+        .len();
+    assert!(masks.iter().all(|mask| mask.len() == length));
+
+    let mut words = vec![0u64; length.div_ceil(64)];
+    for mask in masks {
+        let (Some(first), Some(last)) = (mask.first_set(), mask.last_set()) else {
+            continue;
+        };
+        let start = first / 64 * 64;
+        let end = usize::min((last / 64 + 1) * 64, length);
+        let populated = (*mask).clone().sliced(start, end - start);
+
+        let chunks = populated.chunks::<u64>();
+        let remainder_len = populated.len() % 64;
+        let remainder = chunks.remainder() & ((1u64 << remainder_len) - 1);
+        let words = &mut words[start / 64..end.div_ceil(64)];
+        for (word, chunk) in words.iter_mut().zip(chunks) {
+            *word |= chunk;
+        }
+        if remainder_len != 0 {
+            *words.last_mut().unwrap() |= remainder;
+        }
+    }
+
+    Bitmap::from_u8_vec(chunk_iter_to_vec(words.into_iter()), length)
+}
+
 /// Compute bitwise A OR B operation.
 pub fn or(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
-    if lhs.unset_bits() == 0 || rhs.unset_bits() == 0 {
+    if same_bits(lhs, rhs) {
+        lhs.clone()
+    } else if lhs.unset_bits() == 0 || rhs.unset_bits() == 0 {
         assert_eq!(lhs.len(), rhs.len());
         let mut mutable = MutableBitmap::with_capacity(lhs.len());
         mutable.extend_constant(lhs.len(), true);
@@ -239,6 +383,47 @@ pub fn or(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
     }
 }
 
+/// If exactly one of the bitmaps has length 1 returns its value and the other bitmap.
+///
+/// # Panics
+/// Panics if the lengths differ and neither bitmap has length 1.
+fn broadcast_scalar<'a>(lhs: &'a Bitmap, rhs: &'a Bitmap) -> Option<(bool, &'a Bitmap)> {
+    if lhs.len() == rhs.len() {
+        return None;
+    }
+    match (lhs.len(), rhs.len()) {
+        (1, _) => Some((lhs.get_bit(0), rhs)),
+        (_, 1) => Some((rhs.get_bit(0), lhs)),
+        (l, r) => panic!("cannot broadcast bitmaps of length {l} and {r}"),
+    }
+}
+
+/// Compute bitwise A AND B operation, where a bitmap of length 1 is broadcast to the length of
+/// the other.
+///
+/// # Panics
+/// Panics if the lengths differ and neither bitmap has length 1.
+pub fn and_broadcast(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
+    match broadcast_scalar(lhs, rhs) {
+        Some((true, other)) => other.clone(),
+        Some((false, other)) => Bitmap::new_zeroed(other.len()),
+        None => and(lhs, rhs),
+    }
+}
+
+/// Compute bitwise A OR B operation, where a bitmap of length 1 is broadcast to the length of
+/// the other.
+///
+/// # Panics
+/// Panics if the lengths differ and neither bitmap has length 1.
+pub fn or_broadcast(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
+    match broadcast_scalar(lhs, rhs) {
+        Some((true, other)) => Bitmap::new_with_value(true, other.len()),
+        Some((false, other)) => other.clone(),
+        None => or(lhs, rhs),
+    }
+}
+
 /// Compute bitwise A OR NOT B operation.
 pub fn or_not(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
     binary(lhs, rhs, |x, y| x | !y)
@@ -319,6 +504,36 @@ pub fn intersects_with_mut(lhs: &MutableBitmap, rhs: &MutableBitmap) -> bool {
     )
 }
 
+/// Whether any bit of `bitmap` is set. Stops at the first word with a set bit, unlike counting
+/// the set bits.
+pub fn any(bitmap: &Bitmap) -> bool {
+    if let Some(set_bits) = bitmap.lazy_set_bits() {
+        return set_bits > 0;
+    }
+
+    let mut chunks = bitmap.chunks::<u64>();
+    if chunks.by_ref().any(|chunk| chunk != 0) {
+        return true;
+    }
+    let remainder_mask = (1u64 << (bitmap.len() % 64)) - 1;
+    chunks.remainder() & remainder_mask != 0
+}
+
+/// Whether all bits of `bitmap` are set, which is trivially true if it is empty. Stops at the
+/// first word with an unset bit, unlike counting the unset bits.
+pub fn all(bitmap: &Bitmap) -> bool {
+    if let Some(unset_bits) = bitmap.lazy_unset_bits() {
+        return unset_bits == 0;
+    }
+
+    let mut chunks = bitmap.chunks::<u64>();
+    if !chunks.by_ref().all(|chunk| chunk == u64::MAX) {
+        return false;
+    }
+    let remainder_mask = (1u64 << (bitmap.len() % 64)) - 1;
+    chunks.remainder() & remainder_mask == remainder_mask
+}
+
 pub fn num_edges(lhs: &Bitmap) -> usize {
     if lhs.is_empty() {
         return 0;
@@ -335,6 +550,40 @@ pub fn num_edges(lhs: &Bitmap) -> usize {
     )
 }
 
+/// Number of maximal runs of equal bits, i.e. one more than [`num_edges`] or zero if empty.
+pub fn count_runs(lhs: &Bitmap) -> usize {
+    if lhs.is_empty() {
+        0
+    } else {
+        num_edges(lhs) + 1
+    }
+}
+
+/// Rotate the bits of `bitmap` by `n` positions towards the start, wrapping the first `n` bits
+/// around to the end, like [`slice::rotate_left`]. `n` may exceed the length.
+pub fn rotate_left(bitmap: &Bitmap, n: usize) -> Bitmap {
+    let len = bitmap.len();
+    if len == 0 || n % len == 0 {
+        return bitmap.clone();
+    }
+    let n = n % len;
+
+    let mut out = MutableBitmap::with_capacity(len);
+    out.extend_from_bitmap_range(bitmap, n, len - n);
+    out.extend_from_bitmap_range(bitmap, 0, n);
+    out.freeze()
+}
+
+/// Rotate the bits of `bitmap` by `n` positions towards the end, wrapping the last `n` bits
+/// around to the start, like [`slice::rotate_right`]. `n` may exceed the length.
+pub fn rotate_right(bitmap: &Bitmap, n: usize) -> Bitmap {
+    let len = bitmap.len();
+    if len == 0 {
+        return bitmap.clone();
+    }
+    rotate_left(bitmap, len - n % len)
+}
+
 /// Compute `out[i] = if selector[i] { truthy[i] } else { falsy }`.
 pub fn select_constant(selector: &Bitmap, truthy: &Bitmap, falsy: bool) -> Bitmap {
     let falsy_mask: u64 = if falsy {
@@ -346,6 +595,27 @@ pub fn select_constant(selector: &Bitmap, truthy: &Bitmap, falsy: bool) -> Bitma
     binary(selector, truthy, |s, t| (s & t) | (!s & falsy_mask))
 }
 
+/// Compute `out[i] = if selector[i] { truthy } else { falsy[i] }`.
+pub fn select_truthy_constant(selector: &Bitmap, truthy: bool, falsy: &Bitmap) -> Bitmap {
+    let truthy_mask: u64 = if truthy {
+        0xFFFF_FFFF_FFFF_FFFF
+    } else {
+        0x0000_0000_0000_0000
+    };
+
+    binary(selector, falsy, |s, f| (s & truthy_mask) | (!s & f))
+}
+
+/// Compute `out[i] = if selector[i] { truthy } else { falsy }`.
+pub fn select_both_constant(selector: &Bitmap, truthy: bool, falsy: bool) -> Bitmap {
+    match (truthy, falsy) {
+        (true, false) => selector.clone(),
+        (false, true) => !selector,
+        (true, true) => Bitmap::new_with_value(true, selector.len()),
+        (false, false) => Bitmap::new_zeroed(selector.len()),
+    }
+}
+
 /// Compute `out[i] = if selector[i] { truthy[i] } else { falsy[i] }`.
 pub fn select(selector: &Bitmap, truthy: &Bitmap, falsy: &Bitmap) -> Bitmap {
     ternary(selector, truthy, falsy, |s, t, f| (s & t) | (!s & f))
@@ -417,7 +687,119 @@ mod tests {
         })
     }
 
+    fn equal_length_bitmaps() -> impl Strategy<Value = Vec<Bitmap>> {
+        (0..=250usize, 1..=8usize).prop_flat_map(|(length, n)| {
+            proptest::collection::vec(
+                (bitmap(length..300), 0..=50usize).prop_map(move |(bitmap, offset)| {
+                    let offset = offset.min(bitmap.len() - length);
+                    bitmap.sliced(offset, length)
+                }),
+                n,
+            )
+        })
+    }
+
     proptest! {
+        #[test]
+        fn test_all_and(masks in equal_length_bitmaps()) {
+            let refs = masks.iter().collect::<Vec<_>>();
+            let reference_out = masks[1..].iter().fold(masks[0].clone(), |acc, mask| and(&acc, mask));
+
+            prop_assert_eq!(all_and(&refs), reference_out);
+        }
+
+        #[test]
+        fn test_select_truthy_constant(
+            (selector, falsy) in two_equal_length_bitmaps(),
+            truthy in any::<bool>(),
+        ) {
+            let reference_out = selector
+                .iter()
+                .zip(falsy.iter())
+                .map(|(s, f)| if s { truthy } else { f })
+                .collect::<Bitmap>();
+
+            prop_assert_eq!(select_truthy_constant(&selector, truthy, &falsy), reference_out);
+        }
+
+        #[test]
+        fn test_select_both_constant(
+            (selector, _) in two_equal_length_bitmaps(),
+            truthy in any::<bool>(),
+            falsy in any::<bool>(),
+        ) {
+            let out = select_both_constant(&selector, truthy, falsy);
+            let reference_out = selector
+                .iter()
+                .map(|s| if s { truthy } else { falsy })
+                .collect::<Bitmap>();
+
+            prop_assert_eq!(out.unset_bits(), reference_out.unset_bits());
+            prop_assert_eq!(out, reference_out);
+        }
+
+        #[test]
+        fn test_parity(
+            (bitmap, offset) in (bitmap(0..300), 0..64usize),
+        ) {
+            let offset = offset.min(bitmap.len());
+            let length = bitmap.len() - offset;
+            let bitmap = bitmap.sliced(offset, length);
+
+            prop_assert_eq!(parity(&bitmap), bitmap.set_bits() % 2 == 1);
+        }
+
+        #[test]
+        fn test_sparse_or(masks in equal_length_bitmaps()) {
+            let refs = masks.iter().collect::<Vec<_>>();
+            let reference_out = masks[1..].iter().fold(masks[0].clone(), |acc, mask| or(&acc, mask));
+
+            let out = sparse_or(&refs);
+            debug_assert_padding_zeroed(&out);
+            prop_assert_eq!(out, reference_out);
+        }
+
+        #[test]
+        fn test_sparse_or_single_bits(
+            length in 1..300usize,
+            bits in proptest::collection::vec(0..300usize, 1..10),
+        ) {
+            let masks = bits
+                .iter()
+                .map(|&bit| Bitmap::from_iter((0..length).map(|i| i == bit % length)))
+                .collect::<Vec<_>>();
+            let refs = masks.iter().collect::<Vec<_>>();
+            let reference_out = masks[1..].iter().fold(masks[0].clone(), |acc, mask| or(&acc, mask));
+
+            let out = sparse_or(&refs);
+            debug_assert_padding_zeroed(&out);
+            prop_assert_eq!(out, reference_out);
+        }
+
+        #[test]
+        fn test_rotate(
+            (bitmap, offset) in (bitmap(0..300), 0..64usize),
+            n in 0..400usize,
+        ) {
+            let offset = offset.min(bitmap.len());
+            let length = bitmap.len() - offset;
+            let bitmap = bitmap.sliced(offset, length);
+            let mut bits = bitmap.iter().collect::<Vec<_>>();
+
+            if !bits.is_empty() {
+                bits.rotate_left(n % length);
+            }
+            let left = rotate_left(&bitmap, n);
+            prop_assert_eq!(left.len(), length);
+            prop_assert_eq!(left.iter().collect::<Vec<_>>(), bits.clone());
+
+            if !bits.is_empty() {
+                bits.rotate_right(n % length);
+                bits.rotate_right(n % length);
+            }
+            prop_assert_eq!(rotate_right(&bitmap, n).iter().collect::<Vec<_>>(), bits);
+        }
+
         #[test]
         fn test_num_intersections_with(
             (lhs, rhs) in two_equal_length_bitmaps()
@@ -431,4 +813,168 @@ mod tests {
             prop_assert_eq!(kernel_out, reference_out);
         }
     }
+
+    #[test]
+    fn test_all_and_all_unset() {
+        let a = Bitmap::from_iter((0..100).map(|i| i % 3 == 0));
+        let zeroed = Bitmap::new_zeroed(100);
+        assert_eq!(all_and(&[&a, &zeroed, &a]), zeroed);
+        assert_eq!(all_and(&[&a]), a);
+    }
+
+    #[test]
+    fn test_and_or_same_bits() {
+        let a = Bitmap::from_iter((0..100).map(|i| i % 3 == 0)).sliced(5, 90);
+        let b = a.clone();
+
+        for out in [and(&a, &b), or(&a, &b), &a & &a, &a | &a] {
+            assert_eq!(out, a);
+            // The short-circuit shares the buffer instead of computing a new one.
+            assert!(same_bits(&out, &a));
+        }
+
+        // Same buffer but different offsets must still be computed.
+        let c = Bitmap::from_iter((0..100).map(|i| i % 3 == 0)).sliced(6, 90);
+        let shifted = a.clone().sliced(1, 89);
+        assert!(!same_bits(&a.clone().sliced(0, 89), &shifted));
+        assert_eq!(
+            and(&a.clone().sliced(0, 89), &shifted),
+            Bitmap::new_zeroed(89)
+        );
+        assert_eq!(and(&a, &c), Bitmap::new_zeroed(90));
+    }
+
+    #[test]
+    fn test_and_or_broadcast() {
+        let mask = Bitmap::from_iter((0..100).map(|i| i % 3 == 0)).sliced(3, 90);
+        let t = Bitmap::new_with_value(true, 1);
+        let f = Bitmap::new_zeroed(1);
+
+        for (scalar, other) in [(&t, &mask), (&f, &mask)] {
+            let full = Bitmap::new_with_value(scalar.get_bit(0), other.len());
+            for (lhs, rhs) in [(scalar, other), (other, scalar)] {
+                assert_eq!(and_broadcast(lhs, rhs), and(&full, other));
+                assert_eq!(or_broadcast(lhs, rhs), or(&full, other));
+            }
+        }
+
+        // Equal lengths, including two scalars, behave like `and` and `or`.
+        assert_eq!(and_broadcast(&mask, &mask), mask);
+        assert_eq!(or_broadcast(&t, &f), t);
+        assert_eq!(and_broadcast(&t, &f), f);
+        // A scalar broadcasts to an empty bitmap.
+        assert_eq!(and_broadcast(&t, &Bitmap::new()), Bitmap::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot broadcast")]
+    fn test_and_broadcast_length_mismatch() {
+        and_broadcast(&Bitmap::new_zeroed(2), &Bitmap::new_zeroed(3));
+    }
+
+    #[test]
+    fn test_fold_chunks() {
+        let bitmap = Bitmap::from_iter((0..300).map(|i| i % 3 == 0 || i % 7 == 0));
+        for (offset, length) in [(0, 300), (0, 128), (5, 200), (13, 60), (70, 0)] {
+            // The sliced bitmap shares the buffer, so padding bits are set past the end.
+            let sliced = bitmap.clone().sliced(offset, length);
+            let set_bits = fold_chunks(&sliced, 0, |acc, word| acc + word.count_ones() as usize);
+            assert_eq!(set_bits, sliced.set_bits());
+
+            let n_words = fold_chunks(&sliced, 0, |acc, _| acc + 1);
+            assert_eq!(n_words, length.div_ceil(64));
+        }
+    }
+
+    #[test]
+    fn test_any_all() {
+        // Not the `any` strategy of proptest.
+        use super::{all, any};
+
+        for length in [0, 1, 63, 64, 65, 200] {
+            let set = Bitmap::new_with_value(true, length);
+            let unset = Bitmap::new_zeroed(length);
+            assert_eq!(any(&set), length > 0);
+            assert!(all(&set));
+            assert!(!any(&unset));
+            assert_eq!(all(&unset), length == 0);
+        }
+
+        // From an iterator, so the set bits are not known up front.
+        for bit in [0, 63, 64, 130, 199] {
+            let single = Bitmap::from_iter((0..200).map(|i| i == bit));
+            assert!(any(&single));
+            assert!(!all(&single));
+            let single_unset = Bitmap::from_iter((0..200).map(|i| i != bit));
+            assert!(any(&single_unset));
+            assert!(!all(&single_unset));
+        }
+
+        // Slices ignore the bits outside of them.
+        let bitmap = Bitmap::from_iter((0..200).map(|i| (70..130).contains(&i)));
+        for (offset, length, expected_any, expected_all) in [
+            (0, 70, false, false),
+            (130, 70, false, false),
+            (3, 68, true, false),
+            (70, 60, true, true),
+            (75, 50, true, true),
+            (69, 61, true, false),
+        ] {
+            let sliced = bitmap.clone().sliced(offset, length);
+            assert_eq!(any(&sliced), expected_any, "{offset} {length}");
+            assert_eq!(all(&sliced), expected_all, "{offset} {length}");
+        }
+    }
+
+    #[test]
+    fn test_count_runs() {
+        assert_eq!(count_runs(&Bitmap::new()), 0);
+        assert_eq!(count_runs(&Bitmap::new_with_value(true, 100)), 1);
+        assert_eq!(count_runs(&Bitmap::new_zeroed(1)), 1);
+
+        let alternating = Bitmap::from_iter((0..150).map(|i| i % 2 == 0));
+        assert_eq!(count_runs(&alternating), 150);
+        assert_eq!(count_runs(&alternating.clone().sliced(3, 70)), 70);
+
+        let blocks = Bitmap::from_iter((0..100).map(|i| (i / 10) % 2 == 0));
+        assert_eq!(count_runs(&blocks), 10);
+    }
+
+    #[test]
+    fn test_debug_assert_padding_zeroed() {
+        let bitmap = Bitmap::from_iter((0..13).map(|i| i % 3 == 0));
+        debug_assert_padding_zeroed(&bitmap);
+        // A slice ending on a byte boundary has no padding.
+        debug_assert_padding_zeroed(&bitmap.sliced(3, 5));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "set padding bits")]
+    fn test_debug_assert_padding_zeroed_corrupt() {
+        // Only the low 4 bits are in the bitmap.
+        let bitmap = Bitmap::from_u8_vec(vec![0b0100_0101], 4);
+        debug_assert_padding_zeroed(&bitmap);
+    }
+
+    #[test]
+    fn test_unary_reverse_bits() {
+        let bitmap = Bitmap::from_iter((0..300).map(|i| i % 3 == 0 || i % 7 == 0));
+        for (offset, length) in [(0, 256), (3, 128), (0, 200), (11, 150), (0, 0)] {
+            let sliced = bitmap.clone().sliced(offset, length);
+            let reversed = unary(&sliced, u64::reverse_bits);
+            assert_eq!(reversed.len(), length);
+
+            // Every full word is mirrored.
+            let full = length / 64 * 64;
+            for i in 0..full {
+                let mirrored = i / 64 * 64 + 63 - i % 64;
+                assert_eq!(reversed.get_bit(i), sliced.get_bit(mirrored));
+            }
+
+            // Applying it twice restores the full words.
+            let restored = unary(&reversed, u64::reverse_bits);
+            assert_eq!(restored.sliced(0, full), sliced.sliced(0, full));
+        }
+    }
 }
@@ -0,0 +1,112 @@
+use std::ops::Index;
+
+use super::Bitmap;
+use super::bitmask::BitMask;
+use super::utils::BitmapIter;
+
+/// A `&[bool]`-like view of a [`Bitmap`].
+///
+/// This borrows the bitmap and reads its bits on access, so unlike collecting into a
+/// [`Vec<bool>`] it doesn't allocate. Use [`as_bool_vec`](Self::as_bool_vec) if an owned copy is
+/// needed.
+#[derive(Clone, Copy)]
+pub struct BitmapBoolSlice<'a> {
+    mask: BitMask<'a>,
+}
+
+impl<'a> BitmapBoolSlice<'a> {
+    pub fn new(bitmap: &'a Bitmap) -> Self {
+        Self {
+            mask: BitMask::from_bitmap(bitmap),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.mask.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value at `idx`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<bool> {
+        (idx < self.len()).then(|| unsafe { self.mask.get_bit_unchecked(idx) })
+    }
+
+    pub fn iter(&self) -> BitmapIter<'a> {
+        self.mask.iter()
+    }
+
+    /// Copy the values into a [`Vec<bool>`].
+    pub fn as_bool_vec(&self) -> Vec<bool> {
+        self.iter().collect()
+    }
+}
+
+impl std::fmt::Debug for BitmapBoolSlice<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl Index<usize> for BitmapBoolSlice<'_> {
+    type Output = bool;
+
+    /// # Panics
+    /// Panics iff `idx >= self.len()`.
+    #[inline]
+    fn index(&self, idx: usize) -> &bool {
+        assert!(
+            idx < self.len(),
+            "index {idx} out of bounds for length {}",
+            self.len()
+        );
+        if unsafe { self.mask.get_bit_unchecked(idx) } {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+impl<'a> IntoIterator for BitmapBoolSlice<'a> {
+    type Item = bool;
+    type IntoIter = BitmapIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bool_slice_matches_iter() {
+        let bm = Bitmap::from_iter((0..200).map(|i| i % 3 == 0 || i == 100));
+        // Slices don't start at a byte boundary.
+        for bm in [bm.clone(), bm.clone().sliced(5, 130), bm.sliced(67, 0)] {
+            let view = bm.as_bool_slice();
+            assert_eq!(view.len(), bm.len());
+            let expected = bm.iter().collect::<Vec<_>>();
+            for (i, v) in expected.iter().enumerate() {
+                assert_eq!(view[i], *v);
+                assert_eq!(view.get(i), Some(*v));
+            }
+            assert_eq!(view.get(bm.len()), None);
+            assert_eq!(view.as_bool_vec(), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn bool_slice_out_of_bounds() {
+        let bm = Bitmap::new_zeroed(10);
+        let _ = bm.as_bool_slice()[10];
+    }
+}
@@ -1,18 +1,20 @@
 #![allow(unsafe_op_in_unsafe_fn)]
+use std::io::{Read, Write};
 use std::ops::Deref;
 
 use either::Either;
 use polars_buffer::{Buffer, SharedStorage};
 use polars_error::{PolarsResult, polars_bail};
+use polars_utils::IdxSize;
 use polars_utils::relaxed_cell::RelaxedCell;
 
 use super::utils::{self, BitChunk, BitChunks, BitmapIter, count_zeros, fmt, get_bit_unchecked};
-use super::{IntoIter, MutableBitmap, chunk_iter_to_vec, num_intersections_with};
+use super::{BitmapBoolSlice, IntoIter, MutableBitmap, chunk_iter_to_vec, num_intersections_with};
 use crate::array::Splitable;
 use crate::bitmap::BitmapBuilder;
 use crate::bitmap::aligned::AlignedBitmapSlice;
 use crate::bitmap::iterator::{
-    FastU32BitmapIter, FastU56BitmapIter, FastU64BitmapIter, TrueIdxIter,
+    FastU32BitmapIter, FastU56BitmapIter, FastU64BitmapIter, RunIter, TrueIdxIter,
 };
 use crate::bitmap::utils::bytes_for;
 use crate::legacy::utils::FromTrustedLenIterator;
@@ -20,6 +22,9 @@ use crate::trusted_len::TrustedLen;
 
 const UNKNOWN_BIT_COUNT: u64 = u64::MAX;
 
+const SERIALIZE_MAGIC: [u8; 4] = *b"PLBM";
+const SERIALIZE_VERSION: u8 = 1;
+
 /// An immutable container semantically equivalent to `Arc<Vec<bool>>` but represented as `Arc<Vec<u8>>` where
 /// each boolean is represented as a single bit.
 ///
@@ -132,6 +137,11 @@ impl Bitmap {
         BitmapIter::new(&self.storage, self.offset, self.length)
     }
 
+    /// Returns a `&[bool]`-like view of this bitmap that supports indexing, without copying it.
+    pub fn as_bool_slice(&self) -> BitmapBoolSlice<'_> {
+        BitmapBoolSlice::new(self)
+    }
+
     /// Returns an iterator over bits in bit chunks [`BitChunk`].
     ///
     /// This iterator is useful to operate over multiple bits via e.g. bitwise.
@@ -162,6 +172,13 @@ impl Bitmap {
         TrueIdxIter::new(self.len(), Some(self))
     }
 
+    /// Returns an iterator over the runs of equal bits as `(bit, run length)`.
+    ///
+    /// Runs are found a word at a time, so long runs are cheap to skip over.
+    pub fn runs(&self) -> RunIter<'_> {
+        RunIter::new(self)
+    }
+
     /// Returns the bits of this [`Bitmap`] as a [`AlignedBitmapSlice`].
     pub fn aligned<T: BitChunk>(&self) -> AlignedBitmapSlice<'_, T> {
         AlignedBitmapSlice::new(&self.storage, self.offset, self.length)
@@ -447,12 +464,210 @@ impl Bitmap {
         }
     }
 
+    /// Initializes a new [`Bitmap`] that repeats `pattern` `times` times, resulting in a length
+    /// of `pattern.len() * times`.
+    ///
+    /// # Panics
+    /// Panics if the resulting length overflows a `usize`.
+    pub fn tile(pattern: &Bitmap, times: usize) -> Self {
+        let pattern_len = pattern.len();
+        let length = pattern_len
+            .checked_mul(times)
+            .expect("length of tiled bitmap overflows");
+        if length == 0 {
+            return Self::new();
+        }
+        let unset_bits = pattern.lazy_unset_bits().map(|n| n * times);
+
+        if !pattern_len.is_power_of_two() {
+            let mut bitmap = MutableBitmap::with_capacity(length);
+            for _ in 0..times {
+                bitmap.extend_from_bitmap(pattern);
+            }
+            return bitmap.freeze();
+        }
+
+        // Power of two lengths either divide or are a multiple of 64, so we can replicate whole
+        // words instead of shifting every copy into place.
+        let words = if pattern_len <= 64 {
+            let mut word = pattern
+                .iter()
+                .rev()
+                .fold(0u64, |word, bit| (word << 1) | bit as u64);
+            let mut width = pattern_len;
+            while width < 64 {
+                word |= word << width;
+                width *= 2;
+            }
+            vec![word; length.div_ceil(64)]
+        } else {
+            let chunks = pattern.chunks::<u64>();
+            debug_assert_eq!(chunks.remainder_len(), 0);
+            chunks.collect::<Vec<_>>().repeat(times)
+        };
+        let bytes = chunk_iter_to_vec(words.into_iter());
+
+        // SAFETY: `bytes` holds at least `length` bits and the unset bits are scaled from the
+        // pattern.
+        unsafe {
+            Bitmap::from_inner_unchecked(SharedStorage::from_vec(bytes), 0, length, unset_bits)
+        }
+    }
+
+    /// Gather the bits at `indices` into a new [`Bitmap`], i.e. `out[i] = self[indices[i]]`.
+    ///
+    /// # Safety
+    /// All indices must be in bounds. This is checked with a debug assertion only.
+    pub unsafe fn take(&self, indices: &[IdxSize]) -> Self {
+        let (slice, offset, length) = self.as_slice();
+        let mut bytes = Vec::with_capacity(indices.len().div_ceil(64) * 8);
+        for chunk in indices.chunks(64) {
+            let word = chunk.iter().enumerate().fold(0u64, |word, (i, &idx)| {
+                debug_assert!((idx as usize) < length, "index {idx} out of bounds");
+                let bit_idx = offset + idx as usize;
+                // SAFETY: the caller guarantees that `idx` is in bounds.
+                let byte = unsafe { *slice.get_unchecked(bit_idx / 8) };
+                word | ((((byte >> (bit_idx % 8)) & 1) as u64) << i)
+            });
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        Bitmap::from_u8_vec(bytes, indices.len())
+    }
+
+    /// Keep only the bits of `self` at the positions where `keep` is set, i.e. filter `self` by
+    /// `keep`. The result has `keep.set_bits()` bits.
+    ///
+    /// This keeps a validity mask aligned with the values after they are filtered by `keep`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `keep` have different lengths.
+    pub fn apply_mask(&self, keep: &Bitmap) -> Self {
+        assert_eq!(self.len(), keep.len());
+        let length = keep.set_bits();
+        if length == self.len() {
+            return self.clone();
+        }
+
+        let values = super::bitmask::BitMask::from_bitmap(self);
+        let mut idx = keep.true_idx_iter();
+        let mut bytes = Vec::with_capacity(length.div_ceil(64) * 8);
+        for n in (0..length)
+            .step_by(64)
+            .map(|start| (length - start).min(64))
+        {
+            let word = idx
+                .by_ref()
+                .take(n)
+                .enumerate()
+                .fold(0u64, |word, (i, idx)| {
+                    // SAFETY: `idx` is a set position of `keep`, which has the same length as `self`.
+                    word | ((unsafe { values.get_bit_unchecked(idx) } as u64) << i)
+                });
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        Bitmap::from_u8_vec(bytes, length)
+    }
+
+    /// Writes the logical bits of this [`Bitmap`] in a compact, versioned format that can be read
+    /// back with [`Bitmap::deserialize`].
+    ///
+    /// The format is a header (magic, version, encoding and the length in bits) followed by the
+    /// bits as little-endian `u64` words. The words are run-length encoded if that is smaller,
+    /// which is the case for mostly set or mostly unset masks. The offset of a sliced bitmap is
+    /// not preserved.
+    pub fn serialize(&self, w: &mut impl Write) -> PolarsResult<()> {
+        let words = || {
+            let chunks = self.chunks::<u64>();
+            let remainder_len = chunks.remainder_len();
+            // Bits past the end of a sliced bitmap are not guaranteed to be zero.
+            let remainder = (remainder_len > 0)
+                .then(|| chunks.remainder() & (u64::MAX >> (64 - remainder_len)));
+            chunks.chain(remainder)
+        };
+
+        let mut runs: Vec<(u64, u64)> = Vec::new();
+        for word in words() {
+            match runs.last_mut() {
+                Some((count, last)) if *last == word => *count += 1,
+                _ => runs.push((1, word)),
+            }
+        }
+        let rle = runs.len() * 2 < self.length.div_ceil(64);
+
+        w.write_all(&SERIALIZE_MAGIC)?;
+        w.write_all(&[SERIALIZE_VERSION, rle as u8])?;
+        w.write_all(&(self.length as u64).to_le_bytes())?;
+        if rle {
+            for (count, word) in runs {
+                w.write_all(&count.to_le_bytes())?;
+                w.write_all(&word.to_le_bytes())?;
+            }
+        } else {
+            for word in words() {
+                w.write_all(&word.to_le_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a [`Bitmap`] written by [`Bitmap::serialize`].
+    pub fn deserialize(r: &mut impl Read) -> PolarsResult<Self> {
+        let mut header = [0u8; 14];
+        r.read_exact(&mut header)?;
+        if header[..4] != SERIALIZE_MAGIC {
+            polars_bail!(ComputeError: "not a serialized bitmap");
+        }
+        if header[4] != SERIALIZE_VERSION {
+            polars_bail!(ComputeError: "unsupported serialized bitmap version {}", header[4]);
+        }
+        let rle = match header[5] {
+            0 => false,
+            1 => true,
+            v => polars_bail!(ComputeError: "unknown serialized bitmap encoding {v}"),
+        };
+        let Ok(length) = usize::try_from(u64::from_le_bytes(header[6..].try_into().unwrap()))
+        else {
+            polars_bail!(ComputeError: "serialized bitmap is too long");
+        };
+
+        let n_words = length.div_ceil(64);
+        let mut read_u64 = || -> PolarsResult<u64> {
+            let mut buf = [0u8; 8];
+            r.read_exact(&mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        };
+        let mut words = Vec::new();
+        while words.len() < n_words {
+            let count = if rle { read_u64()? } else { 1 };
+            let word = read_u64()?;
+            let Some(count) = usize::try_from(count)
+                .ok()
+                .filter(|c| *c > 0 && *c <= n_words - words.len())
+            else {
+                polars_bail!(ComputeError: "corrupt serialized bitmap");
+            };
+            words.extend(std::iter::repeat_n(word, count));
+        }
+
+        Bitmap::try_new(chunk_iter_to_vec(words.into_iter()), length)
+    }
+
     /// Counts the nulls (unset bits) starting from `offset` bits and for `length` bits.
     #[inline]
     pub fn null_count_range(&self, offset: usize, length: usize) -> usize {
         count_zeros(&self.storage, self.offset + offset, length)
     }
 
+    /// Counts the set bits in `[start, start + len)` without slicing the bitmap.
+    ///
+    /// # Panics
+    /// Panics if `start + len > self.len()`.
+    #[inline]
+    pub fn count_ones_in_range(&self, start: usize, len: usize) -> usize {
+        assert!(start + len <= self.length);
+        len - count_zeros(&self.storage, self.offset + start, len)
+    }
+
     /// Creates a new [`Bitmap`] from a slice and length.
     /// # Panic
     /// Panics iff `length > bytes.len() * 8`
@@ -521,6 +736,15 @@ impl Bitmap {
         )
     }
 
+    /// Compute the bitwise AND of all `masks` in a single pass, e.g. to combine the validities
+    /// of many arguments.
+    ///
+    /// # Panics
+    /// Panics if `masks` is empty or if the bitmaps have different lengths.
+    pub fn all_and(masks: &[&Bitmap]) -> Self {
+        super::bitmap_ops::all_and(masks)
+    }
+
     /// Select between `truthy` and `falsy` based on `self`.
     ///
     /// This essentially performs:
@@ -539,11 +763,53 @@ impl Bitmap {
         super::bitmap_ops::select_constant(self, truthy, falsy)
     }
 
+    /// Select between constant `truthy` and `falsy` based on `self`.
+    ///
+    /// This essentially performs:
+    ///
+    /// `out[i] = if self[i] { truthy } else { falsy[i] }`
+    pub fn select_truthy_constant(&self, truthy: bool, falsy: &Self) -> Self {
+        super::bitmap_ops::select_truthy_constant(self, truthy, falsy)
+    }
+
+    /// Select between constants `truthy` and `falsy` based on `self`.
+    ///
+    /// This essentially performs:
+    ///
+    /// `out[i] = if self[i] { truthy } else { falsy }`
+    pub fn select_both_constant(&self, truthy: bool, falsy: bool) -> Self {
+        super::bitmap_ops::select_both_constant(self, truthy, falsy)
+    }
+
     /// Calculates the number of edges from `0 -> 1` and `1 -> 0`.
     pub fn num_edges(&self) -> usize {
         super::bitmap_ops::num_edges(self)
     }
 
+    /// Returns the index of the single flip in a monotone [`Bitmap`], i.e. one that is all `0`s
+    /// followed by all `1`s or vice versa.
+    ///
+    /// Returns `None` if there are zero or multiple transitions. Once a single edge is confirmed
+    /// the transition is located by binary search.
+    pub fn transition_point(&self) -> Option<usize> {
+        if self.num_edges() != 1 {
+            return None;
+        }
+
+        // SAFETY: `num_edges() == 1` implies `len() >= 2` and all indices below are in bounds.
+        let first = unsafe { self.get_bit_unchecked(0) };
+        let (mut lo, mut hi) = (1, self.length - 1);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if unsafe { self.get_bit_unchecked(mid) } == first {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        Some(lo)
+    }
+
     /// Returns the number of zero bits from the start before a one bit is seen
     pub fn leading_zeros(&self) -> usize {
         utils::leading_zeros(&self.storage, self.offset, self.length)
@@ -561,6 +827,26 @@ impl Bitmap {
         utils::trailing_ones(&self.storage, self.offset, self.length)
     }
 
+    /// Returns the index of the first set bit, or `None` if no bit is set.
+    pub fn first_set(&self) -> Option<usize> {
+        if self.lazy_set_bits() == Some(0) {
+            return None;
+        }
+
+        let leading_zeros = self.leading_zeros();
+        (leading_zeros < self.length).then_some(leading_zeros)
+    }
+
+    /// Returns the index of the last set bit, or `None` if no bit is set.
+    pub fn last_set(&self) -> Option<usize> {
+        if self.lazy_set_bits() == Some(0) {
+            return None;
+        }
+
+        let trailing_zeros = self.trailing_zeros();
+        (trailing_zeros < self.length).then(|| self.length - trailing_zeros - 1)
+    }
+
     /// Take all `0` bits at the start of the [`Bitmap`] before a `1` is seen, returning how many
     /// bits were taken
     pub fn take_leading_zeros(&mut self) -> usize {
@@ -703,6 +989,31 @@ impl Bitmap {
         MutableBitmap::from_trusted_len_iter(iterator).into()
     }
 
+    /// Creates a new [`Bitmap`] of `length` bits where bit `i` is `f(i)`, evaluating `f` in
+    /// parallel.
+    ///
+    /// Each task fills its own range of whole bytes, so no synchronization is needed.
+    #[cfg(feature = "parallel")]
+    pub fn par_from_trusted_len(length: usize, f: impl Fn(usize) -> bool + Sync) -> Self {
+        use rayon::prelude::*;
+
+        let mut bytes = vec![0u8; length.div_ceil(8)];
+        bytes
+            .par_chunks_mut(8)
+            .with_min_len(64)
+            .enumerate()
+            .for_each(|(chunk_idx, chunk)| {
+                let start = chunk_idx * 64;
+                let end = usize::min(start + 64, length);
+                let mut word = 0u64;
+                for i in start..end {
+                    word |= (f(i) as u64) << (i - start);
+                }
+                chunk.copy_from_slice(&word.to_le_bytes()[..chunk.len()]);
+            });
+        Bitmap::from_u8_vec(bytes, length)
+    }
+
     /// Creates a new [`Bitmap`] from a fallible iterator of booleans.
     #[inline]
     pub fn try_from_trusted_len_iter<E, I: TrustedLen<Item = std::result::Result<bool, E>>>(
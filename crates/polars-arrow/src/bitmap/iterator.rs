@@ -1,7 +1,10 @@
+use std::iter::FusedIterator;
+
 use polars_utils::slice::load_padded_le_u64;
 
 use super::Bitmap;
 use super::bitmask::BitMask;
+use super::utils::BitmapIter;
 use crate::trusted_len::TrustedLen;
 
 /// Calculates how many iterations are remaining, assuming:
@@ -95,6 +98,45 @@ impl Iterator for TrueIdxIter<'_> {
 
 unsafe impl TrustedLen for TrueIdxIter<'_> {}
 
+/// An iterator over the runs of equal bits, yielding each bit with the length of its run.
+#[derive(Clone)]
+pub struct RunIter<'a> {
+    iter: BitmapIter<'a>,
+}
+
+impl<'a> RunIter<'a> {
+    pub fn new(bitmap: &'a Bitmap) -> Self {
+        Self {
+            iter: bitmap.iter(),
+        }
+    }
+}
+
+impl Iterator for RunIter<'_> {
+    type Item = (bool, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.num_remaining() == 0 {
+            return None;
+        }
+
+        let num_ones = self.iter.take_leading_ones();
+        if num_ones > 0 {
+            return Some((true, num_ones));
+        }
+        Some((false, self.iter.take_leading_zeros()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.iter.num_remaining();
+        (remaining.min(1), Some(remaining))
+    }
+}
+
+impl FusedIterator for RunIter<'_> {}
+
 pub struct FastU32BitmapIter<'a> {
     bytes: &'a [u8],
     shift: u32,
@@ -8,6 +8,9 @@ pub use iterator::IntoIter;
 mod mutable;
 pub use mutable::MutableBitmap;
 
+mod bool_slice;
+pub use bool_slice::BitmapBoolSlice;
+
 mod bitmap_ops;
 pub use bitmap_ops::*;
 
@@ -1,6 +1,5 @@
 use std::hash::BuildHasher;
 
-use polars_utils::aliases::PlRandomState;
 use polars_utils::hashing::BytesHash;
 use rayon::prelude::*;
 
@@ -12,7 +11,7 @@ use crate::utils::{_set_partition_size, _split_offsets};
 fn fill_bytes_hashes<'a, T>(
     ca: &'a ChunkedArray<T>,
     null_h: u64,
-    hb: PlRandomState,
+    hb: impl BuildHasher,
 ) -> Vec<BytesHash<'a>>
 where
     T: PolarsDataType,
@@ -44,7 +43,7 @@ where
     pub fn to_bytes_hashes<'a>(
         &'a self,
         mut multithreaded: bool,
-        hb: PlRandomState,
+        hb: impl BuildHasher + Clone + Send + Sync,
     ) -> Vec<Vec<BytesHash<'a>>> {
         multithreaded &= RAYON.current_num_threads() > 1;
         let null_h = hb.hash_one(0xde259df92c607d49_u64);
@@ -720,6 +720,68 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_cast_datetime_downscale_strict() -> PolarsResult<()> {
+        let ns = Int64Chunked::new(
+            PlSmallStr::from_static("dt"),
+            &[Some(-2_000_000), None, Some(3_000_000), Some(3_000_001)],
+        )
+        .into_datetime(TimeUnit::Nanoseconds, None)
+        .into_series();
+        let ms = DataType::Datetime(TimeUnit::Milliseconds, None);
+
+        // Whole milliseconds cast losslessly.
+        let out = ns.slice(0, 3).strict_cast(&ms)?;
+        assert_eq!(
+            Vec::from(out.datetime()?.physical()),
+            &[Some(-2), None, Some(3)]
+        );
+
+        let err = ns.strict_cast(&ms).unwrap_err();
+        assert!(err.to_string().contains("index 3"));
+
+        // Non-strict casts keep truncating.
+        let out = ns.cast(&ms)?;
+        assert_eq!(
+            Vec::from(out.datetime()?.physical()),
+            &[Some(-2), None, Some(3), Some(3)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_cast_datetime_upscale_overflow() -> PolarsResult<()> {
+        let ms = Int64Chunked::new(
+            PlSmallStr::from_static("dt"),
+            &[Some(1), None, Some(i64::MAX / 1_000), Some(-1)],
+        )
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+        let ns = DataType::Datetime(TimeUnit::Nanoseconds, None);
+
+        let err = ms.strict_cast(&ns).unwrap_err();
+        assert!(matches!(err, PolarsError::InvalidOperation(_)));
+        assert!(err.to_string().contains("value at index 2 is out of range"));
+
+        // A value that fits in microseconds but not in nanoseconds.
+        let us = DataType::Datetime(TimeUnit::Microseconds, None);
+        let out = ms.strict_cast(&us)?;
+        assert_eq!(
+            out.datetime()?.physical().get(2),
+            Some(i64::MAX / 1_000 * 1_000)
+        );
+
+        // Non-strict casts set the overflowing values to null.
+        let out = ms.cast(&ns)?;
+        assert_eq!(
+            Vec::from(out.datetime()?.physical()),
+            &[Some(1_000_000), None, None, Some(-1_000_000)]
+        );
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "dtype-categorical")]
     fn test_cast_noop() {
@@ -11,6 +11,51 @@ impl Int64Chunked {
     }
 }
 
+/// Raise if a strict cast from `input` to `output`, which has a different time unit, overflowed
+/// or silently dropped sub-unit precision.
+fn check_strict_time_unit_cast(
+    input: &DatetimeChunked,
+    output: &DatetimeChunked,
+) -> PolarsResult<()> {
+    let units_per_second = |tu| match tu {
+        TimeUnit::Nanoseconds => 1_000_000_000i64,
+        TimeUnit::Microseconds => 1_000_000,
+        TimeUnit::Milliseconds => 1_000,
+    };
+    let from = units_per_second(input.time_unit());
+    let to = units_per_second(output.time_unit());
+    if from < to {
+        // Overflowing values became null.
+        if output.null_count() != input.null_count()
+            && let Some(idx) = input
+                .physical()
+                .iter()
+                .zip(output.physical().iter())
+                .position(|(v, out)| v.is_some() && out.is_none())
+        {
+            polars_bail!(
+                InvalidOperation:
+                "casting from {:?} to {:?} overflows: value at index {} is out of range",
+                input.dtype(), output.dtype(), idx
+            );
+        }
+    } else if from > to {
+        let d = from / to;
+        if let Some(idx) = input
+            .physical()
+            .iter()
+            .position(|v| v.is_some_and(|v| v.rem_euclid(d) != 0))
+        {
+            polars_bail!(
+                InvalidOperation:
+                "casting from {:?} to {:?} loses precision: value at index {} is not a whole number of {}",
+                input.dtype(), output.dtype(), idx, output.time_unit()
+            );
+        }
+    }
+    Ok(())
+}
+
 impl LogicalType for DatetimeChunked {
     fn dtype(&self) -> &DataType {
         &self.dtype
@@ -51,21 +96,21 @@ impl LogicalType for DatetimeChunked {
                     (Microseconds, Milliseconds) => (None, Some(1_000i64)),
                     _ => return self.phys.cast_with_options(dtype, cast_options),
                 };
-                match multiplier {
+                let out = match multiplier {
                     // scale to higher precision (eg: ms → us, ms → ns, us → ns)
-                    Some(m) => Ok((self.phys.as_ref().checked_mul_scalar(m))
-                        .into_datetime(*to_unit, tz.clone())
-                        .into_series()),
+                    // Overflowing values become null, which a strict cast raises on.
+                    Some(m) => self.phys.as_ref().checked_mul_scalar(m),
                     // scale to lower precision (eg: ns → us, ns → ms, us → ms)
                     None => match divisor {
-                        Some(d) => Ok(self
-                            .phys
-                            .apply_values(|v| v.div_euclid(d))
-                            .into_datetime(*to_unit, tz.clone())
-                            .into_series()),
+                        Some(d) => self.phys.apply_values(|v| v.div_euclid(d)),
                         None => unreachable!("must always have a time unit divisor here"),
                     },
                 }
+                .into_datetime(*to_unit, tz.clone());
+                if cast_options.is_strict() {
+                    check_strict_time_unit_cast(self, &out)?;
+                }
+                Ok(out.into_series())
             },
             #[cfg(feature = "dtype-date")]
             Date => {
@@ -4,7 +4,11 @@ use arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
 };
 #[cfg(feature = "timezones")]
-use chrono::TimeZone as TimeZoneTrait;
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime_opt, timestamp_ns_to_datetime_opt, timestamp_us_to_datetime_opt,
+};
+#[cfg(feature = "timezones")]
+use chrono::{Offset, TimeZone as TimeZoneTrait};
 
 use super::*;
 use crate::prelude::DataType::Datetime;
@@ -92,6 +96,60 @@ impl DatetimeChunked {
         self.to_string(format)
     }
 
+    /// Convert a timezone-aware Datetime into the naive wall-clock Datetime observed in its
+    /// time zone.
+    ///
+    /// Unlike [`DatetimeChunked::set_time_zone`] this modifies the physical values. The UTC
+    /// offset is determined per element, so daylight saving time transitions are respected.
+    /// Timezone-naive data is returned unchanged.
+    #[cfg(feature = "timezones")]
+    pub fn to_naive_local(&self) -> PolarsResult<DatetimeChunked> {
+        let Some(time_zone) = self.time_zone() else {
+            return Ok(self.clone());
+        };
+        let tz = time_zone.to_chrono()?;
+        let conversion_f = match self.time_unit() {
+            TimeUnit::Nanoseconds => timestamp_ns_to_datetime_opt,
+            TimeUnit::Microseconds => timestamp_us_to_datetime_opt,
+            TimeUnit::Milliseconds => timestamp_ms_to_datetime_opt,
+        };
+        let units_per_second = match self.time_unit() {
+            TimeUnit::Nanoseconds => 1_000_000_000,
+            TimeUnit::Microseconds => 1_000_000,
+            TimeUnit::Milliseconds => 1_000,
+        };
+
+        let ca: Int64Chunked = self.physical().try_apply_nonnull_values_generic(|v| {
+            let out_of_range = || {
+                polars_err!(
+                    ComputeError: "datetime value {} is out of range in time zone {}", v, time_zone
+                )
+            };
+            let ndt = conversion_f(v).ok_or_else(out_of_range)?;
+            let offset = tz.offset_from_utc_datetime(&ndt).fix().local_minus_utc();
+            v.checked_add(offset as i64 * units_per_second)
+                .ok_or_else(out_of_range)
+        })?;
+        Ok(ca.into_datetime(self.time_unit(), None))
+    }
+
+    /// Relabel a timezone-aware Datetime as UTC.
+    ///
+    /// The physical values are UTC instants already, so only the time zone changes. Timezone-naive
+    /// data raises, as it is ambiguous which time zone its wall-clock values are in.
+    #[cfg(feature = "timezones")]
+    pub fn to_utc(&self) -> PolarsResult<DatetimeChunked> {
+        polars_ensure!(
+            self.time_zone().is_some(),
+            InvalidOperation:
+            "cannot convert time zone naive Datetime to UTC; \
+            use `replace_time_zone` to declare the time zone of its values"
+        );
+        let mut out = self.clone();
+        out.set_time_zone(TimeZone::UTC)?;
+        Ok(out)
+    }
+
     /// Construct a new [`DatetimeChunked`] from an iterator over [`NaiveDateTime`].
     pub fn from_naive_datetime<I: IntoIterator<Item = NaiveDateTime>>(
         name: PlSmallStr,
@@ -166,6 +224,36 @@ impl DatetimeChunked {
         }
     }
 
+    /// Get the number of `tu` units since the Unix epoch.
+    ///
+    /// Downscaling floors towards negative infinity. Values that overflow when upscaling raise
+    /// an error if `strict`, otherwise they become null.
+    pub fn timestamp(&self, tu: TimeUnit, strict: bool) -> PolarsResult<Int64Chunked> {
+        let units_per_second = |tu| match tu {
+            TimeUnit::Nanoseconds => 1_000_000_000i64,
+            TimeUnit::Microseconds => 1_000_000,
+            TimeUnit::Milliseconds => 1_000,
+        };
+        let from = units_per_second(self.time_unit());
+        let to = units_per_second(tu);
+
+        let phys = self.physical();
+        if from == to {
+            Ok(phys.clone())
+        } else if from > to {
+            Ok(phys.wrapping_floor_div_scalar(from / to))
+        } else {
+            let factor = to / from;
+            let out = phys.apply(|opt_v| opt_v.and_then(|v| v.checked_mul(factor)));
+            polars_ensure!(
+                !strict || out.null_count() == phys.null_count(),
+                ComputeError: "overflow when converting datetime from '{}' to a timestamp in '{}'",
+                self.time_unit(), tu
+            );
+            Ok(out)
+        }
+    }
+
     /// Change the underlying [`TimeUnit`]. This does not modify the data.
     pub fn set_time_unit(&mut self, time_unit: TimeUnit) {
         self.dtype = Datetime(time_unit, self.time_zone().clone());
@@ -196,6 +284,7 @@ impl DatetimeChunked {
 
 #[cfg(test)]
 mod test {
+    use arrow::bitmap::Bitmap;
     use chrono::NaiveDateTime;
 
     use crate::prelude::*;
@@ -226,4 +315,112 @@ mod test {
             dt.physical().cont_slice().unwrap()
         );
     }
+
+    #[test]
+    fn timestamp() {
+        let ca = Int64Chunked::new(
+            PlSmallStr::from_static("name"),
+            &[Some(1_500), Some(-1_500), None, Some(i64::MAX / 100)],
+        )
+        .into_datetime(TimeUnit::Milliseconds, None);
+
+        let out = ca.timestamp(TimeUnit::Milliseconds, true).unwrap();
+        assert_eq!(Vec::from(&out), Vec::from(ca.physical()));
+
+        // Downscaling floors, so pre-epoch values round down.
+        let out = ca
+            .clone()
+            .into_physical()
+            .into_datetime(TimeUnit::Microseconds, None)
+            .timestamp(TimeUnit::Milliseconds, true)
+            .unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1), Some(-2), None, Some(i64::MAX / 100 / 1_000)]
+        );
+
+        let out = ca
+            .slice(0, 3)
+            .timestamp(TimeUnit::Nanoseconds, true)
+            .unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1_500_000_000), Some(-1_500_000_000), None]
+        );
+
+        assert!(ca.timestamp(TimeUnit::Nanoseconds, true).is_err());
+        let out = ca.timestamp(TimeUnit::Nanoseconds, false).unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1_500_000_000), Some(-1_500_000_000), None, None]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn to_naive_local_dst() {
+        // Europe/Amsterdam switches from UTC+1 to UTC+2 at 2021-03-28 01:00:00 UTC.
+        let ca = Int64Chunked::new(
+            PlSmallStr::from_static("name"),
+            &[1_616_891_400_000, 1_616_895_000_000],
+        )
+        .into_datetime(
+            TimeUnit::Milliseconds,
+            TimeZone::opt_try_new(Some("Europe/Amsterdam")).unwrap(),
+        );
+
+        let out = ca.to_naive_local().unwrap();
+        assert_eq!(out.time_zone(), &None);
+        let expected: Vec<_> = ["2021-03-28 01:30:00", "2021-03-28 03:30:00"]
+            .iter()
+            .map(|s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap())
+            .map(Some)
+            .collect();
+        assert_eq!(out.as_datetime_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn to_naive_local_overflow() {
+        let tz = TimeZone::opt_try_new(Some("Asia/Tokyo")).unwrap();
+        // The value under the null slot is never converted.
+        let physical = Int64Chunked::from_slice_options(
+            PlSmallStr::from_static("name"),
+            &[Some(0), Some(i64::MAX)],
+        )
+        .with_validity(Some(Bitmap::from_iter([true, false])));
+        let ca = physical.into_datetime(TimeUnit::Nanoseconds, tz.clone());
+        let out = ca.to_naive_local().unwrap();
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[Some(9 * 3_600 * 1_000_000_000), None]
+        );
+
+        let ca = Int64Chunked::new(PlSmallStr::from_static("name"), &[i64::MAX - 1])
+            .into_datetime(TimeUnit::Nanoseconds, tz);
+        assert!(ca.to_naive_local().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn to_utc() {
+        let physical = Int64Chunked::new(
+            PlSmallStr::from_static("name"),
+            &[Some(1_616_891_400_000), None],
+        );
+        let ca = physical.clone().into_datetime(
+            TimeUnit::Milliseconds,
+            TimeZone::opt_try_new(Some("Europe/Amsterdam")).unwrap(),
+        );
+
+        let out = ca.to_utc().unwrap();
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC))
+        );
+        assert_eq!(Vec::from(out.physical()), Vec::from(&physical));
+
+        let naive = physical.into_datetime(TimeUnit::Milliseconds, None);
+        assert!(naive.to_utc().is_err());
+    }
 }
@@ -235,7 +235,7 @@ impl IntoGroupsType for BinaryChunked {
         }
 
         multithreaded &= RAYON.current_num_threads() > 1;
-        let bh = self.to_bytes_hashes(multithreaded, Default::default());
+        let bh = self.to_bytes_hashes(multithreaded, PlRandomState::default());
 
         let out = if multithreaded {
             let n_partitions = bh.len();
@@ -303,7 +303,7 @@ impl IntoGroupsType for BinaryOffsetChunked {
         }
 
         multithreaded &= RAYON.current_num_threads() > 1;
-        let bh = self.to_bytes_hashes(multithreaded, Default::default());
+        let bh = self.to_bytes_hashes(multithreaded, PlRandomState::default());
 
         let out = if multithreaded {
             let n_partitions = bh.len();
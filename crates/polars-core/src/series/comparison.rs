@@ -51,6 +51,12 @@ macro_rules! impl_eq_compare {
                 })
             },
 
+            #[cfg(feature = "dtype-datetime")]
+            (Datetime(lu, ltz), Datetime(ru, rtz)) if lu != ru || ltz != rtz => {
+                let (lhs, rhs) = normalize_datetime_units(lhs, rhs)?;
+                return lhs.$method(&rhs);
+            },
+
             #[cfg(feature = "dtype-extension")]
             (le @ Extension(_, _), re @ Extension(_, _)) if le == re => {
                 let lhs = lhs.ext().unwrap();
@@ -169,6 +175,12 @@ macro_rules! impl_ineq_compare {
                     rhs.cat::<$C>().unwrap().$rev_method(lhs.str().unwrap())
                 })
             },
+            #[cfg(feature = "dtype-datetime")]
+            (Datetime(lu, ltz), Datetime(ru, rtz)) if lu != ru || ltz != rtz => {
+                let (lhs, rhs) = normalize_datetime_units(lhs, rhs)?;
+                return lhs.$method(&rhs);
+            },
+
             #[cfg(feature = "dtype-extension")]
             (le @ Extension(_, _), re @ Extension(_, _)) if le == re => {
                 let lhs = lhs.ext().unwrap();
@@ -232,6 +244,33 @@ macro_rules! impl_ineq_compare {
     }};
 }
 
+/// Cast two datetimes to the finer of their time units, so that the same instant compares equal
+/// regardless of the unit it is stored in.
+#[cfg(feature = "dtype-datetime")]
+fn normalize_datetime_units(lhs: &Series, rhs: &Series) -> PolarsResult<(Series, Series)> {
+    use TimeUnit::*;
+
+    let (lhs_ca, rhs_ca) = (lhs.datetime()?, rhs.datetime()?);
+    polars_ensure!(
+        lhs_ca.time_zone() == rhs_ca.time_zone(),
+        SchemaMismatch: "cannot compare datetimes with different time zones: series '{}' of dtype: {} and series '{}' of dtype: {}",
+        lhs.name(), lhs.dtype(), rhs.name(), rhs.dtype()
+    );
+    let tu = match (lhs_ca.time_unit(), rhs_ca.time_unit()) {
+        (Nanoseconds, _) | (_, Nanoseconds) => Nanoseconds,
+        (Microseconds, _) | (_, Microseconds) => Microseconds,
+        _ => Milliseconds,
+    };
+    let tz = lhs_ca.time_zone().clone();
+    // Upscaling may overflow, which must error rather than silently compare against a null.
+    let normalize = |ca: &DatetimeChunked| -> PolarsResult<Series> {
+        let mut out = ca.timestamp(tu, true)?.into_datetime(tu, tz.clone());
+        out.rename(ca.name().clone());
+        Ok(out.into_series())
+    };
+    Ok((normalize(lhs_ca)?, normalize(rhs_ca)?))
+}
+
 fn validate_types(left: &DataType, right: &DataType) -> PolarsResult<()> {
     use DataType::*;
 
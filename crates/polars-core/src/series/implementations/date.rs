@@ -412,6 +412,10 @@ impl SeriesTrait for SeriesWrap<DateChunked> {
         Ok(Scalar::new(self.dtype().clone(), av))
     }
 
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        Err(super::sum_of_instants_err(self.dtype()))
+    }
+
     #[cfg(feature = "dtype-datetime")]
     fn mean_reduce(&self) -> PolarsResult<Scalar> {
         let mean = self.mean().map(|v| (v * US_IN_DAY as f64) as i64);
@@ -409,6 +409,10 @@ impl SeriesTrait for SeriesWrap<DatetimeChunked> {
         Ok(Scalar::new(self.dtype().clone(), av))
     }
 
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        Err(super::sum_of_instants_err(self.dtype()))
+    }
+
     fn mean_reduce(&self) -> PolarsResult<Scalar> {
         let mean = self.mean().map(|v| v as i64);
         let av = AnyValue::from(mean).as_datetime_owned(self.0.time_unit(), self.0.time_zone_arc());
@@ -507,6 +507,16 @@ impl_dyn_series!(Int64Chunked, Int64Type);
 #[cfg(feature = "dtype-i128")]
 impl_dyn_series!(Int128Chunked, Int128Type);
 
+/// Summing points in time has no meaning, unlike summing durations or taking the mean.
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+fn sum_of_instants_err(dtype: &DataType) -> PolarsError {
+    polars_err!(
+        InvalidOperation: "`sum` is not supported for dtype `{}` as it represents points in time; \
+        subtract a reference point to sum durations instead",
+        dtype
+    )
+}
+
 impl<T: PolarsNumericType> private::PrivateSeriesNumeric for SeriesWrap<ChunkedArray<T>> {
     fn bit_repr(&self) -> Option<BitRepr> {
         Some(self.0.to_bit_repr())
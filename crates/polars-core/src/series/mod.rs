@@ -46,6 +46,7 @@ use crate::chunked_array::cast::CastOptions;
 use crate::runtime::RAYON;
 #[cfg(feature = "zip_with")]
 use crate::series::arithmetic::coerce_lhs_rhs;
+use crate::utils::series::is_time_unit_cast;
 use crate::utils::{Wrap, handle_casting_failures, materialize_dyn_int};
 
 /// # Series
@@ -462,6 +463,9 @@ impl Series {
         }
 
         let new_options = match options {
+            // Datetime time unit casts can lose precision without producing nulls, so they
+            // check strictness themselves.
+            CastOptions::Strict if is_time_unit_cast(slf.dtype(), dtype) => CastOptions::Strict,
             // Strictness is handled on this level to improve error messages, if not nested.
             // Nested types could hide cast errors, so have to be done internally.
             CastOptions::Strict if !dtype.is_nested() => CastOptions::NonStrict,
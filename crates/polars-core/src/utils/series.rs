@@ -47,6 +47,15 @@ pub fn is_deprecated_cast(input_dtype: &DataType, output_dtype: &DataType) -> bo
     }
 }
 
+/// Whether casting `input_dtype` to `output_dtype` changes the time unit of a Datetime.
+pub(crate) fn is_time_unit_cast(input_dtype: &DataType, output_dtype: &DataType) -> bool {
+    match (input_dtype, output_dtype) {
+        #[cfg(feature = "dtype-datetime")]
+        (DataType::Datetime(from, _), DataType::Datetime(to, _)) => from != to,
+        _ => false,
+    }
+}
+
 pub fn handle_casting_failures(input: &Series, output: &Series) -> PolarsResult<()> {
     // @Hack to deal with deprecated cast
     // @2.0
@@ -432,21 +432,68 @@ impl ParsedBuilder for DecimalField {
     }
 }
 
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
+type ParseWithFormat<T> = fn(&str, &str, Option<TimeUnit>) -> Option<T>;
+
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 pub struct DatetimeField<T: PolarsNumericType> {
     compiled: Option<DatetimeInfer<T>>,
     builder: PrimitiveChunkedBuilder<T>,
+    /// User supplied formats, tried in order before the inferred pattern.
+    date_formats: Vec<PlSmallStr>,
+    parse_with_format: ParseWithFormat<T::Native>,
 }
 
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 impl<T: PolarsNumericType> DatetimeField<T> {
-    fn new(name: PlSmallStr, capacity: usize) -> Self {
+    fn new(
+        name: PlSmallStr,
+        capacity: usize,
+        date_formats: &[PlSmallStr],
+        parse_with_format: ParseWithFormat<T::Native>,
+    ) -> Self {
         let builder = PrimitiveChunkedBuilder::<T>::new(name, capacity);
         Self {
             compiled: None,
             builder,
+            date_formats: date_formats.to_vec(),
+            parse_with_format,
         }
     }
+
+    fn parse_with_date_formats(
+        &self,
+        bytes: &[u8],
+        time_unit: Option<TimeUnit>,
+    ) -> Option<T::Native> {
+        let val = std::str::from_utf8(bytes).ok()?;
+        self.date_formats
+            .iter()
+            .find_map(|fmt| (self.parse_with_format)(val, fmt, time_unit))
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+fn parse_date_with_format(val: &str, fmt: &str, _time_unit: Option<TimeUnit>) -> Option<i32> {
+    use chrono::Datelike;
+
+    let date = chrono::NaiveDate::parse_from_str(val, fmt).ok()?;
+    Some(date.num_days_from_ce() - arrow::temporal_conversions::EPOCH_DAYS_FROM_CE)
+}
+
+#[cfg(feature = "dtype-datetime")]
+fn parse_datetime_with_format(val: &str, fmt: &str, time_unit: Option<TimeUnit>) -> Option<i64> {
+    let datetime = chrono::NaiveDateTime::parse_from_str(val, fmt)
+        .or_else(|_| {
+            chrono::NaiveDate::parse_from_str(val, fmt)
+                .map(|date| date.and_time(Default::default()))
+        })
+        .ok()?;
+    Some(match time_unit.unwrap_or(TimeUnit::Microseconds) {
+        TimeUnit::Nanoseconds => datetime_to_timestamp_ns(datetime),
+        TimeUnit::Microseconds => datetime_to_timestamp_us(datetime),
+        TimeUnit::Milliseconds => datetime_to_timestamp_ms(datetime),
+    })
 }
 
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
@@ -544,6 +591,13 @@ where
             return Ok(());
         }
 
+        if !self.date_formats.is_empty()
+            && let Some(parsed) = self.parse_with_date_formats(bytes, time_unit)
+        {
+            self.builder.append_value(parsed);
+            return Ok(());
+        }
+
         match &mut self.compiled {
             None => slow_datetime_parser(self, bytes, time_unit, ignore_errors),
             Some(compiled) => {
@@ -569,11 +623,20 @@ pub fn init_builders(
     quote_char: Option<u8>,
     encoding: CsvEncoding,
     decimal_comma: bool,
+    date_formats: &[PlSmallStr],
+    parse_dates_in: impl Fn(&str) -> bool,
+    integral_floats_as_int: bool,
 ) -> PolarsResult<Vec<Builder>> {
     projection
         .iter()
         .map(|&i| {
             let (name, dtype) = schema.get_at_index(i).unwrap();
+            // User date formats only apply where date parsing is enabled, like in inference.
+            let date_formats = if parse_dates_in(name.as_str()) {
+                date_formats
+            } else {
+                &[]
+            };
             let name = name.clone();
             let builder = match dtype {
                 &DataType::Boolean => Builder::Boolean(BooleanChunkedBuilder::new(name, capacity)),
@@ -582,7 +645,16 @@ pub fn init_builders(
                 #[cfg(feature = "dtype-i16")]
                 &DataType::Int16 => Builder::Int16(PrimitiveChunkedBuilder::new(name, capacity)),
                 &DataType::Int32 => Builder::Int32(PrimitiveChunkedBuilder::new(name, capacity)),
-                &DataType::Int64 => Builder::Int64(PrimitiveChunkedBuilder::new(name, capacity)),
+                &DataType::Int64 => {
+                    if integral_floats_as_int {
+                        Builder::IntegralFloatInt64(
+                            PrimitiveChunkedBuilder::new(name, capacity),
+                            Default::default(),
+                        )
+                    } else {
+                        Builder::Int64(PrimitiveChunkedBuilder::new(name, capacity))
+                    }
+                },
                 #[cfg(feature = "dtype-i128")]
                 &DataType::Int128 => Builder::Int128(PrimitiveChunkedBuilder::new(name, capacity)),
                 #[cfg(feature = "dtype-u8")]
@@ -639,12 +711,22 @@ pub fn init_builders(
                 },
                 #[cfg(feature = "dtype-datetime")]
                 DataType::Datetime(time_unit, time_zone) => Builder::Datetime {
-                    buf: DatetimeField::new(name, capacity),
+                    buf: DatetimeField::new(
+                        name,
+                        capacity,
+                        date_formats,
+                        parse_datetime_with_format,
+                    ),
                     time_unit: *time_unit,
                     time_zone: time_zone.clone(),
                 },
                 #[cfg(feature = "dtype-date")]
-                &DataType::Date => Builder::Date(DatetimeField::new(name, capacity)),
+                &DataType::Date => Builder::Date(DatetimeField::new(
+                    name,
+                    capacity,
+                    date_formats,
+                    parse_date_with_format,
+                )),
                 #[cfg(feature = "dtype-categorical")]
                 DataType::Categorical(_, _) | DataType::Enum(_, _) => {
                     match dtype.cat_physical().unwrap() {
@@ -728,6 +810,8 @@ pub enum Builder {
     DecimalFloat16(PrimitiveChunkedBuilder<Float16Type>, Vec<u8>),
     DecimalFloat32(PrimitiveChunkedBuilder<Float32Type>, Vec<u8>),
     DecimalFloat64(PrimitiveChunkedBuilder<Float64Type>, Vec<u8>),
+    /// Int64 that also accepts integers written as floats, e.g. `3.0`.
+    IntegralFloatInt64(PrimitiveChunkedBuilder<Int64Type>, Vec<u8>),
 }
 
 impl Builder {
@@ -758,6 +842,7 @@ impl Builder {
             Builder::DecimalFloat16(v, _) => v.finish().into_series(),
             Builder::DecimalFloat32(v, _) => v.finish().into_series(),
             Builder::DecimalFloat64(v, _) => v.finish().into_series(),
+            Builder::IntegralFloatInt64(v, _) => v.finish().into_series(),
             #[cfg(feature = "dtype-decimal")]
             Builder::Decimal(DecimalField {
                 builder,
@@ -834,6 +919,7 @@ impl Builder {
             Builder::DecimalFloat16(v, _) => v.append_null(),
             Builder::DecimalFloat32(v, _) => v.append_null(),
             Builder::DecimalFloat64(v, _) => v.append_null(),
+            Builder::IntegralFloatInt64(v, _) => v.append_null(),
             Builder::Utf8(v) => {
                 if valid {
                     v.mutable.push_value("")
@@ -862,7 +948,7 @@ impl Builder {
             #[cfg(feature = "dtype-i16")]
             Builder::Int16(_) => DataType::Int16,
             Builder::Int32(_) => DataType::Int32,
-            Builder::Int64(_) => DataType::Int64,
+            Builder::Int64(_) | Builder::IntegralFloatInt64(_, _) => DataType::Int64,
             #[cfg(feature = "dtype-i128")]
             Builder::Int128(_) => DataType::Int128,
             #[cfg(feature = "dtype-u8")]
@@ -1058,6 +1144,17 @@ impl Builder {
                     None,
                 )
             },
+            IntegralFloatInt64(buf, scratch) => {
+                prepare_integral_float(bytes, scratch);
+                <PrimitiveChunkedBuilder<Int64Type> as ParsedBuilder>::parse_bytes(
+                    buf,
+                    scratch,
+                    ignore_errors,
+                    needs_escaping,
+                    missing_is_null,
+                    None,
+                )
+            },
             #[cfg(feature = "dtype-decimal")]
             Decimal(buf) => <DecimalField as ParsedBuilder>::parse_bytes(
                 buf,
@@ -1111,6 +1208,25 @@ impl Builder {
     }
 }
 
+/// Drop a fraction that only consists of zeros, e.g. `3.0` becomes `3`. Other values are copied
+/// as is.
+#[inline]
+fn prepare_integral_float(bytes: &[u8], scratch: &mut Vec<u8>) {
+    scratch.clear();
+
+    if let Some(dot) = bytes.iter().position(|&b| b == b'.') {
+        let zeros = bytes[dot + 1..].iter().take_while(|&&b| b == b'0').count();
+        let rest = &bytes[dot + 1 + zeros..];
+        // The rest may hold a closing quote or trailing whitespace.
+        if !rest.iter().any(u8::is_ascii_digit) {
+            scratch.extend_from_slice(&bytes[..dot]);
+            scratch.extend_from_slice(rest);
+            return;
+        }
+    }
+    scratch.extend_from_slice(bytes);
+}
+
 #[inline]
 fn prepare_decimal_comma(bytes: &[u8], scratch: &mut Vec<u8>) {
     scratch.clear();
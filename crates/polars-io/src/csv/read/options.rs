@@ -7,6 +7,7 @@ use polars_core::datatypes::{DataType, Field};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_error::PolarsResult;
 use polars_utils::pl_str::PlSmallStr;
+use polars_utils::total_ord::TotalOrdWrap;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +30,10 @@ pub struct CsvReadOptions {
     pub projection: Option<Arc<Vec<usize>>>,
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
+    /// Only used by the lazy reader when it re-applies `schema_overwrite` after a schema modify
+    /// function, see [`with_dtype_overrides_only`](Self::with_dtype_overrides_only).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dtype_overrides_only: bool,
     /// Override the names from the file. This is Python `scan_csv(new_columns=...)`
     pub column_names_overwrite: Option<Buffer<PlSmallStr>>,
     pub dtype_overwrite: Option<Arc<Vec<DataType>>>,
@@ -42,6 +47,14 @@ pub struct CsvReadOptions {
     pub skip_lines: usize,
     pub skip_rows_after_header: usize,
     pub infer_schema_length: Option<usize>,
+    /// Raise during schema inference if a line has more fields than this.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_inferred_columns: Option<usize>,
+    /// The row after the header declares the dtype of each column instead of holding data.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub types_row: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub infer_nullability: bool,
     pub raise_if_empty: bool,
     pub ignore_errors: bool,
     pub fields_to_cast: Vec<Field>,
@@ -60,7 +73,29 @@ pub struct CsvParseOptions {
     pub truncate_ragged_lines: bool,
     pub comment_prefix: Option<CommentPrefix>,
     pub try_parse_dates: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub try_parse_dates_columns: Vec<PlSmallStr>,
     pub decimal_comma: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub infer_json_columns: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub type_confidence: Option<TotalOrdWrap<f64>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub date_formats: Vec<PlSmallStr>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub duplicate_header_template: Option<PlSmallStr>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub integral_floats_as_int: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub float_special_values: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub large_ints_as_float: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub trim_fields_for_inference: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strict_schema_overwrite: bool,
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+    pub field_type_inferer: Option<FieldTypeInfererRef>,
 }
 
 impl Default for CsvReadOptions {
@@ -79,6 +114,7 @@ impl Default for CsvReadOptions {
             projection: None,
             schema: None,
             schema_overwrite: None,
+            dtype_overrides_only: false,
             column_names_overwrite: None,
             dtype_overwrite: None,
 
@@ -89,6 +125,9 @@ impl Default for CsvReadOptions {
             skip_lines: 0,
             skip_rows_after_header: 0,
             infer_schema_length: Some(100),
+            max_inferred_columns: None,
+            types_row: false,
+            infer_nullability: false,
             raise_if_empty: true,
             ignore_errors: false,
             fields_to_cast: vec![],
@@ -109,7 +148,18 @@ impl Default for CsvParseOptions {
             truncate_ragged_lines: false,
             comment_prefix: None,
             try_parse_dates: false,
+            try_parse_dates_columns: vec![],
             decimal_comma: false,
+            infer_json_columns: false,
+            type_confidence: None,
+            date_formats: vec![],
+            duplicate_header_template: None,
+            integral_floats_as_int: false,
+            float_special_values: false,
+            large_ints_as_float: false,
+            trim_fields_for_inference: false,
+            strict_schema_overwrite: false,
+            field_type_inferer: None,
         }
     }
 }
@@ -182,6 +232,17 @@ impl CsvReadOptions {
         self
     }
 
+    /// When the lazy reader re-applies the schema overwrite after a schema modify function, only
+    /// change the dtypes of columns in the modified schema. Overrides for other names are ignored
+    /// instead of adding a column.
+    ///
+    /// This has no effect on schema inference or the eager reader, which only ever apply the
+    /// schema overwrite to columns in the file.
+    pub fn with_dtype_overrides_only(mut self, dtype_overrides_only: bool) -> Self {
+        self.dtype_overrides_only = dtype_overrides_only;
+        self
+    }
+
     /// Overwrite the column names inferred from the file.
     pub fn with_column_names_overwrite(
         mut self,
@@ -227,7 +288,8 @@ impl CsvReadOptions {
 
     /// Start reading after `skip_lines` lines. The header will be parsed at this
     /// offset. Note that CSV escaping will not be respected when skipping lines.
-    /// If you want to skip valid CSV rows, use ``skip_rows``.
+    /// If you want to skip valid CSV rows, use ``skip_rows``. If both are set,
+    /// `skip_lines` is applied first and ``skip_rows`` skips rows after those lines.
     pub fn with_skip_lines(mut self, skip_lines: usize) -> Self {
         self.skip_lines = skip_lines;
         self
@@ -242,11 +304,38 @@ impl CsvReadOptions {
     /// Set the number of rows to use when inferring the csv schema.
     /// The default is 100 rows.
     /// Setting to [None] will do a full table scan, which is very slow.
+    /// Setting to `Some(0)` infers all columns as `String`. If the file has a header only the
+    /// header row is read, without scanning any data rows.
     pub fn with_infer_schema_length(mut self, infer_schema_length: Option<usize>) -> Self {
         self.infer_schema_length = infer_schema_length;
         self
     }
 
+    /// Raise an error during schema inference if the header or a data row has more fields than
+    /// `max_inferred_columns`. This guards against reading a file with the wrong separator,
+    /// which can split it into a huge number of columns.
+    pub fn with_max_inferred_columns(mut self, max_inferred_columns: Option<usize>) -> Self {
+        self.max_inferred_columns = max_inferred_columns;
+        self
+    }
+
+    /// Read the row after the header as the dtype of each column, e.g. `integer,string,date`
+    /// as in Frictionless Data, rather than as data. Columns with an unknown type name are
+    /// inferred as usual, and `schema_overwrite` takes precedence. Requires a header.
+    pub fn with_types_row(mut self, types_row: bool) -> Self {
+        self.types_row = types_row;
+        self
+    }
+
+    /// Record which columns have no null in the lines sampled for schema inference, see
+    /// [`SchemaInferenceResult::non_null_columns`].
+    ///
+    /// [`SchemaInferenceResult::non_null_columns`]: super::schema_inference::SchemaInferenceResult::non_null_columns
+    pub fn with_infer_nullability(mut self, infer_nullability: bool) -> Self {
+        self.infer_nullability = infer_nullability;
+        self
+    }
+
     /// Whether to raise an error if the frame is empty. By default an empty
     /// DataFrame is returned.
     pub fn with_raise_if_empty(mut self, raise_if_empty: bool) -> Self {
@@ -336,11 +425,152 @@ impl CsvParseOptions {
         self
     }
 
+    /// Only try to parse dates/datetimes and time in these columns, the other columns are
+    /// inferred without attempting date parsing. Has no effect if `try_parse_dates` is set, as
+    /// that already applies to all columns.
+    pub fn with_try_parse_dates_columns<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        self.try_parse_dates_columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Whether dates are parsed in `column`, i.e. `try_parse_dates` is set or `column` is one of
+    /// the `try_parse_dates_columns`.
+    pub fn try_parse_dates_in(&self, column: &str) -> bool {
+        self.try_parse_dates || self.try_parse_dates_columns.iter().any(|c| c == column)
+    }
+
     /// Parse floats with a comma as decimal separator.
     pub fn with_decimal_comma(mut self, decimal_comma: bool) -> Self {
         self.decimal_comma = decimal_comma;
         self
     }
+
+    /// Infer [`DataType::Struct`]/[`DataType::List`] for columns holding JSON objects or
+    /// arrays. Columns whose JSON values have conflicting shapes remain
+    /// [`DataType::String`]. Requires the `json` feature.
+    pub fn with_infer_json_columns(mut self, infer_json_columns: bool) -> Self {
+        self.infer_json_columns = infer_json_columns;
+        self
+    }
+
+    /// During schema inference, pick the most common type of a column if at least this fraction
+    /// (between 0 and 1) of its sampled non-null values has that type. The remaining values
+    /// fail to parse later, and become null if errors are ignored.
+    pub fn with_type_confidence(mut self, type_confidence: Option<f64>) -> Self {
+        self.type_confidence = type_confidence.map(TotalOrdWrap);
+        self
+    }
+
+    /// Chrono format strings that are tried in order before the built-in date/datetime
+    /// patterns in the columns where dates are parsed, see [`Self::try_parse_dates_in`]. The
+    /// values are parsed as naive dates or datetimes, with the first matching format used for
+    /// both inference and parsing. Other columns ignore the formats, even if they are read as
+    /// dates through the schema.
+    pub fn with_date_formats<I, S>(mut self, date_formats: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        self.date_formats = date_formats.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Template used to rename repeated header names, e.g. `"{name} ({n})"`. `{name}` is
+    /// replaced by the header name and `{n}` by its occurrence, so the first duplicate gets
+    /// `n = 2`. If the template does not produce unique names, a warning is emitted and the
+    /// default `name_duplicated_0`, `name_duplicated_1`, ... naming is used instead.
+    pub fn with_duplicate_header_template<S: Into<PlSmallStr>>(
+        mut self,
+        duplicate_header_template: Option<S>,
+    ) -> Self {
+        self.duplicate_header_template = duplicate_header_template.map(Into::into);
+        self
+    }
+
+    /// During schema inference, treat floats without a fractional part (e.g. `3.0`) as
+    /// integers, so that a column of such values is inferred as [`DataType::Int64`] instead of
+    /// [`DataType::Float64`]. Int64 columns then also accept these values when parsing.
+    pub fn with_integral_floats_as_int(mut self, integral_floats_as_int: bool) -> Self {
+        self.integral_floats_as_int = integral_floats_as_int;
+        self
+    }
+
+    /// During schema inference, recognize `nan`, `inf` and `infinity` with an optional sign, in
+    /// any case, as [`DataType::Float64`] values, the same way they are parsed. Otherwise only
+    /// `NaN` and `inf` are recognized and other spellings infer a column as a string.
+    pub fn with_float_special_values(mut self, float_special_values: bool) -> Self {
+        self.float_special_values = float_special_values;
+        self
+    }
+
+    /// During schema inference, treat integers that don't fit in an [`DataType::Int64`] as
+    /// [`DataType::Float64`] values instead of [`DataType::Int128`] (or strings, if 128-bit
+    /// integers are not enabled).
+    pub fn with_large_ints_as_float(mut self, large_ints_as_float: bool) -> Self {
+        self.large_ints_as_float = large_ints_as_float;
+        self
+    }
+
+    /// Trim leading and trailing spaces and tabs from unquoted fields, e.g. from a fixed-column
+    /// export padded with spaces. The trim applies to both schema inference and parsing, and a
+    /// field that is empty after trimming is read as null.
+    pub fn with_trim_fields_for_inference(mut self, trim_fields_for_inference: bool) -> Self {
+        self.trim_fields_for_inference = trim_fields_for_inference;
+        self
+    }
+
+    /// Raise instead of warn if a value sampled during schema inference does not fit in the
+    /// integer data type declared for its column in the schema overwrite.
+    pub fn with_strict_schema_overwrite(mut self, strict_schema_overwrite: bool) -> Self {
+        self.strict_schema_overwrite = strict_schema_overwrite;
+        self
+    }
+
+    /// Consult `inferer` for every value during schema inference, before the built-in inference.
+    /// A value for which it returns a data type is inferred as that type.
+    ///
+    /// The inferer is not serializable, it is dropped when the options are serialized.
+    pub fn with_field_type_inferer(mut self, inferer: Option<Arc<dyn FieldTypeInferer>>) -> Self {
+        self.field_type_inferer = inferer.map(FieldTypeInfererRef);
+        self
+    }
+}
+
+/// Custom type detection for CSV schema inference, e.g. to recognize domain-specific values.
+pub trait FieldTypeInferer: Send + Sync {
+    /// Returns the data type of a single non-null value, or `None` to fall back to the built-in
+    /// inference.
+    fn infer(&self, value: &str) -> Option<DataType>;
+}
+
+/// Wrapper that implements [`Debug`], [`PartialEq`], [`Hash`] etc. for a [`FieldTypeInferer`].
+///
+/// Inferers compare by identity and are skipped when serializing [`CsvParseOptions`].
+#[derive(Clone)]
+pub struct FieldTypeInfererRef(pub Arc<dyn FieldTypeInferer>);
+
+impl std::fmt::Debug for FieldTypeInfererRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FieldTypeInferer({:p})", Arc::as_ptr(&self.0))
+    }
+}
+
+impl PartialEq for FieldTypeInfererRef {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for FieldTypeInfererRef {}
+
+impl std::hash::Hash for FieldTypeInfererRef {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.0) as *const () as usize)
+    }
 }
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
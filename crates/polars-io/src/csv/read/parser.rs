@@ -401,6 +401,17 @@ pub(super) fn skip_whitespace(input: &[u8]) -> &[u8] {
     skip_condition(input, is_whitespace)
 }
 
+/// Remove whitespace from both ends of a field.
+#[inline]
+pub(super) fn trim_whitespace(input: &[u8]) -> &[u8] {
+    let input = skip_whitespace(input);
+    let end = input
+        .iter()
+        .rposition(|b| !is_whitespace(*b))
+        .map_or(0, |i| i + 1);
+    &input[..end]
+}
+
 /// An adapted version of std::iter::Split.
 /// This exists solely because we cannot split the file in lines naively as
 ///
@@ -1148,6 +1159,11 @@ pub(super) fn parse_lines(
                         };
                         let mut add_null = false;
 
+                        if parse_options.trim_fields_for_inference && !needs_escaping {
+                            field = trim_whitespace(field);
+                            add_null = field.is_empty();
+                        }
+
                         // if we have null values argument, check if this field equal null value
                         if let Some(null_values) = null_values {
                             let field = if needs_escaping && !field.is_empty() {
@@ -1158,10 +1174,13 @@ pub(super) fn parse_lines(
 
                             // SAFETY:
                             // process fields is in bounds
-                            add_null = unsafe { null_values.is_null(field, idx as usize) }
+                            add_null |= unsafe { null_values.is_null(field, idx as usize) }
                         }
                         if add_null {
-                            buf.add_null(!parse_options.missing_is_null && field.is_empty())
+                            // A field that is empty after trimming is always null.
+                            let empty_is_valid = !parse_options.missing_is_null
+                                && !parse_options.trim_fields_for_inference;
+                            buf.add_null(empty_is_valid && field.is_empty())
                         } else {
                             buf.add(field, ignore_errors, needs_escaping, parse_options.missing_is_null)
                                 .map_err(|e| {
@@ -57,6 +57,10 @@ pub fn cast_columns(
                     &StringChunked::from_iter(std::iter::once("raise")),
                 )
                 .map(|ca| ca.into_column()),
+            #[cfg(feature = "json")]
+            (DataType::String, dt @ (DataType::List(_) | DataType::Struct(_))) => {
+                decode_json(c.str().unwrap(), dt).map(Column::from)
+            },
             (_, dt) => c.cast(dt),
         }?;
         if !ignore_errors && c.null_count() != out.null_count() {
@@ -91,6 +95,22 @@ pub fn cast_columns(
     Ok(())
 }
 
+/// Decode a column of JSON strings into the nested `dtype`.
+#[cfg(feature = "json")]
+fn decode_json(ca: &StringChunked, dtype: &DataType) -> PolarsResult<Series> {
+    let buf_size = ca.get_values_size() + ca.null_count() * "null".len();
+    let iter = ca.iter().map(|x| x.unwrap_or("null"));
+
+    let array = polars_json::ndjson::deserialize::deserialize_iter(
+        iter,
+        dtype.to_arrow(CompatLevel::newest()),
+        buf_size,
+        ca.len(),
+        false,
+    )?;
+    Series::try_from((ca.name().clone(), array))
+}
+
 struct ReaderBytesAndDependents<'a> {
     // Ensure lifetime dependents are dropped before `reader_bytes`, since their drop impls
     // could access themselves, this is achieved by placing them before `reader_bytes`.
@@ -141,7 +161,9 @@ impl<'a> CoreReader<'a> {
         skip_lines: usize,
         mut projection: Option<Vec<usize>>,
         max_records: Option<usize>,
+        max_inferred_columns: Option<usize>,
         has_header: bool,
+        types_row: bool,
         ignore_errors: bool,
         schema: Option<SchemaRef>,
         columns: Option<Arc<[PlSmallStr]>>,
@@ -170,8 +192,13 @@ impl<'a> CoreReader<'a> {
         // again after decompression.
         #[cfg(feature = "decompress")]
         {
-            let total_n_rows =
-                n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
+            let total_n_rows = n_rows.map(|n| {
+                skip_rows
+                    + (has_header as usize)
+                    + (types_row as usize)
+                    + skip_rows_after_header
+                    + n
+            });
             if let Some(b) = decompress(
                 &reader_bytes,
                 total_n_rows,
@@ -212,6 +239,8 @@ impl<'a> CoreReader<'a> {
             row_index: row_index.clone(),
             raise_if_empty,
             infer_schema_length: max_records,
+            max_inferred_columns,
+            types_row,
             ..Default::default()
         };
 
@@ -225,7 +254,7 @@ impl<'a> CoreReader<'a> {
 
         let mut schema = match schema {
             Some(schema) => schema,
-            None => Arc::new(inferred_schema),
+            None => Arc::new(inferred_schema.schema),
         };
         if let Some(dtypes) = dtype_overwrite {
             polars_ensure!(
@@ -265,7 +294,7 @@ impl<'a> CoreReader<'a> {
             parse_options: (*parse_options).clone(),
             schema,
             projection,
-            current_line: usize::from(has_header),
+            current_line: usize::from(has_header) + usize::from(types_row),
             ignore_errors,
             n_rows,
             n_threads,
@@ -571,6 +600,9 @@ pub fn read_chunk(
         parse_options.quote_char,
         parse_options.encoding,
         parse_options.decimal_comma,
+        &parse_options.date_formats,
+        |name| parse_options.try_parse_dates_in(name),
+        parse_options.integral_floats_as_int,
     )?;
 
     debug_assert!(projection.is_sorted());
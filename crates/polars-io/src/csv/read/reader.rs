@@ -110,7 +110,9 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.skip_lines,
             self.options.projection.clone().map(|x| x.as_ref().clone()),
             self.options.infer_schema_length,
+            self.options.max_inferred_columns,
             self.options.has_header,
+            self.options.types_row,
             self.options.ignore_errors,
             self.options.schema.clone(),
             self.options.columns.clone(),
@@ -194,6 +196,13 @@ pub fn prepare_csv_schema(
                     fld.coerce(String);
                     PolarsResult::Ok(fld)
                 },
+                // JSON values are read as strings and decoded afterwards.
+                #[cfg(feature = "json")]
+                List(_) | Struct(_) => {
+                    fields_to_cast.push(fld.clone());
+                    fld.coerce(String);
+                    PolarsResult::Ok(fld)
+                },
                 _ => {
                     matched = false;
                     PolarsResult::Ok(fld)
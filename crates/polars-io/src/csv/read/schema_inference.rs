@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use polars_buffer::Buffer;
 use polars_core::prelude::*;
 #[cfg(feature = "polars-time")]
@@ -6,10 +8,101 @@ use polars_time::chunkedarray::string::infer as date_infer;
 use polars_time::prelude::string::Pattern;
 use polars_utils::format_pl_smallstr;
 
+use super::parser::trim_whitespace;
 use super::splitfields::SplitFields;
+use super::utils::escape_field;
 use super::{CsvParseOptions, NullValues};
 use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
 
+/// The encoding observed in the lines sampled for schema inference.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DetectedEncoding {
+    /// All sampled lines are valid UTF-8, so reading them with [`CsvEncoding::Utf8`] succeeds.
+    ///
+    /// [`CsvEncoding::Utf8`]: super::CsvEncoding::Utf8
+    Utf8,
+    /// The sample starts with a UTF-16 byte order mark, which neither encoding can read.
+    Utf16,
+    /// Some sampled bytes are not valid UTF-8, they can only be read with
+    /// [`CsvEncoding::LossyUtf8`].
+    ///
+    /// [`CsvEncoding::LossyUtf8`]: super::CsvEncoding::LossyUtf8
+    InvalidUtf8,
+}
+
+/// Result of inferring the schema of a CSV file.
+#[derive(Clone, Debug)]
+pub struct SchemaInferenceResult {
+    pub schema: Schema,
+    /// Encoding of the lines used for inference. Lines past the sample are not checked.
+    pub encoding: DetectedEncoding,
+    /// Zero-based physical line index of the header, counting lines skipped by `skip_lines`,
+    /// `skip_rows` and comments. `None` if the file has no header.
+    pub header_line_idx: Option<usize>,
+    /// Whether the input started with a UTF-8 byte order mark, which is stripped before inference.
+    pub had_bom: bool,
+    /// `String` columns with a sampled value containing the separator, the quote char or a line
+    /// break, which has to be quoted when written back to CSV.
+    pub columns_needing_quotes: Vec<PlSmallStr>,
+    /// Mean length in bytes of the sampled data lines, including the line break. Zero if no data
+    /// lines were sampled.
+    pub mean_line_len: usize,
+    /// Length in bytes of the longest sampled data line, including the line break.
+    pub max_line_len: usize,
+    /// Columns without a null in the sampled lines, if `infer_nullability` is set.
+    ///
+    /// Only the sample is checked, so a column listed here can still contain nulls further on in
+    /// the file.
+    pub non_null_columns: Option<Vec<PlSmallStr>>,
+}
+
+impl SchemaInferenceResult {
+    /// Suggest the number of bytes per chunk when reading `bytes_total` bytes with `n_threads`.
+    ///
+    /// This aims for a few chunks per thread, so threads that finish early can pick up more work,
+    /// while keeping chunks large enough to hold many lines of the mean sampled length and a few of
+    /// the longest sampled line. Chunk boundaries still have to be moved to the next line break by
+    /// the reader. The result is advisory and at most `bytes_total`.
+    pub fn recommended_chunk_bytes(&self, n_threads: usize, bytes_total: usize) -> usize {
+        const CHUNKS_PER_THREAD: usize = 4;
+        const MIN_LINES_PER_CHUNK: usize = 1024;
+        const MIN_LONGEST_LINES_PER_CHUNK: usize = 16;
+        const MIN_CHUNK_BYTES: usize = 1 << 16;
+        const MAX_CHUNK_BYTES: usize = 1 << 26;
+
+        let target = bytes_total.div_ceil(n_threads.max(1) * CHUNKS_PER_THREAD);
+        let min_chunk_bytes = MIN_CHUNK_BYTES
+            .max(self.mean_line_len * MIN_LINES_PER_CHUNK)
+            .max(self.max_line_len * MIN_LONGEST_LINES_PER_CHUNK);
+        target
+            .clamp(min_chunk_bytes, MAX_CHUNK_BYTES.max(min_chunk_bytes))
+            .min(bytes_total.max(1))
+    }
+}
+
+/// Detect the encoding of the sampled lines.
+///
+/// A UTF-8 byte order mark is stripped before the lines are split, so only a UTF-16 one can still
+/// be present at the start of the first line.
+pub(super) fn detect_sample_encoding<'a>(
+    mut lines: impl Iterator<Item = &'a [u8]>,
+) -> DetectedEncoding {
+    let Some(first) = lines.next() else {
+        return DetectedEncoding::Utf8;
+    };
+    if first.starts_with(&[0xFF, 0xFE]) || first.starts_with(&[0xFE, 0xFF]) {
+        return DetectedEncoding::Utf16;
+    }
+    if std::iter::once(first)
+        .chain(lines)
+        .all(super::builder::validate_utf8)
+    {
+        DetectedEncoding::Utf8
+    } else {
+        DetectedEncoding::InvalidUtf8
+    }
+}
+
 /// Low-level CSV schema inference function.
 ///
 /// Use `read_until_start_and_infer_schema` instead.
@@ -21,26 +114,81 @@ pub(super) fn infer_file_schema_impl(
     parse_options: &CsvParseOptions,
     column_names_overwrite: Option<&[PlSmallStr]>,
     schema_overwrite: Option<&Schema>,
-) -> PolarsResult<Schema> {
+    max_inferred_columns: Option<usize>,
+) -> PolarsResult<(Schema, Vec<PlSmallStr>, Vec<bool>)> {
+    if let Some(type_confidence) = parse_options.type_confidence {
+        polars_ensure!(
+            (0.0..=1.0).contains(&type_confidence.0),
+            InvalidOperation: "`type_confidence` must be between 0 and 1, got {}", type_confidence.0
+        );
+    }
+
+    let check_n_fields = |n_fields: usize, line: &str| {
+        if let Some(max) = max_inferred_columns {
+            polars_ensure!(
+                n_fields <= max,
+                ComputeError:
+                "the {} has {} fields, more than the maximum of {} inferred columns; \
+                is the separator {:?} correct?",
+                line, n_fields, max, parse_options.separator as char
+            );
+        }
+        Ok(())
+    };
+
     let mut headers = header_line
         .as_ref()
         .map(|line| infer_headers(line, parse_options))
         .unwrap_or_else(|| Vec::with_capacity(8));
+    check_n_fields(headers.len(), "header")?;
 
     let extend_header_with_unknown_column = header_line.is_none();
 
-    let mut column_types = vec![PlIndexSet::<DataType>::with_capacity(4); headers.len()];
+    let mut column_types = vec![PlIndexMap::<DataType, usize>::with_capacity(4); headers.len()];
     let mut nulls = vec![false; headers.len()];
+    let mut string_final = vec![false; headers.len()];
+    let mut needs_quoting = vec![false; headers.len()];
 
+    let mut field_counts = Vec::with_capacity(content_lines.len() + 1);
+    if header_line.is_some() {
+        field_counts.push(headers.len());
+    }
     for content_line in content_lines {
-        infer_types_from_line(
+        let n_fields = infer_types_from_line(
             content_line,
             infer_all_as_str,
             &mut headers,
             extend_header_with_unknown_column,
+            max_inferred_columns,
             parse_options,
             &mut column_types,
             &mut nulls,
+            &mut string_final,
+            &mut needs_quoting,
+        );
+        check_n_fields(n_fields, "data row")?;
+        if !matches!(&content_line[..], b"" | b"\r") {
+            field_counts.push(n_fields);
+        }
+    }
+
+    if !parse_options.truncate_ragged_lines
+        && is_field_count_uneven(&field_counts)
+        && let Some(boundaries) = guess_fixed_width_boundaries(
+            header_line
+                .iter()
+                .chain(content_lines)
+                .map(|line| &line[..]),
+        )
+    {
+        // Only a hint, short lines are still read with the missing fields set to null.
+        polars_warn!(
+            "the number of fields per line varies between {} and {}; the file may be fixed-width \
+            rather than delimited, with columns starting at byte offsets {:?}. Set \
+            `truncate_ragged_lines` to silence this warning if the file is delimited.",
+            field_counts.iter().min().unwrap(),
+            field_counts.iter().max().unwrap(),
+            boundaries
         );
     }
 
@@ -59,12 +207,65 @@ pub(super) fn infer_file_schema_impl(
             }
 
             if i >= column_types.len() {
-                column_types.push(PlIndexSet::from_iter(Some(DataType::Null)))
+                column_types.push(PlIndexMap::from_iter([(DataType::Null, 1)]))
             }
         }
     }
 
-    Ok(build_schema(&headers, &column_types, schema_overwrite))
+    if let Some(schema_overwrite) = schema_overwrite {
+        check_schema_overwrite_ranges(&headers, content_lines, schema_overwrite, parse_options)?;
+    }
+
+    let schema = build_schema(
+        &headers,
+        &column_types,
+        parse_options.type_confidence.map(|c| c.0),
+        schema_overwrite,
+    );
+    let columns_needing_quotes = headers
+        .into_iter()
+        .zip(needs_quoting)
+        .filter_map(|(name, needs_quoting)| needs_quoting.then_some(name))
+        .collect();
+    Ok((schema, columns_needing_quotes, nulls))
+}
+
+/// Parse a row that declares the dtype of each column by name. Unknown names give `None`, so the
+/// dtype of that column is inferred instead.
+pub(super) fn parse_types_row(
+    mut types_line: &[u8],
+    parse_options: &CsvParseOptions,
+) -> Vec<Option<DataType>> {
+    if types_line.last().copied() == Some(b'\r') {
+        types_line = &types_line[..types_line.len() - 1];
+    }
+
+    SplitFields::new(
+        types_line,
+        parse_options.separator,
+        parse_options.quote_char,
+        parse_options.eol_char,
+    )
+    .map(|(slice, needs_escaping)| {
+        let slice_escaped = unescape_field(slice, needs_escaping, parse_options.quote_char);
+        let name = String::from_utf8_lossy(&slice_escaped)
+            .trim()
+            .to_ascii_lowercase();
+        Some(match name.as_str() {
+            "string" | "str" => DataType::String,
+            "integer" | "int" => DataType::Int64,
+            "number" | "float" => DataType::Float64,
+            "boolean" | "bool" => DataType::Boolean,
+            #[cfg(feature = "dtype-date")]
+            "date" => DataType::Date,
+            #[cfg(feature = "dtype-datetime")]
+            "datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
+            #[cfg(feature = "dtype-time")]
+            "time" => DataType::Time,
+            _ => return None,
+        })
+    })
+    .collect()
 }
 
 fn infer_headers(mut header_line: &[u8], parse_options: &CsvParseOptions) -> Vec<PlSmallStr> {
@@ -83,138 +284,386 @@ fn infer_headers(mut header_line: &[u8], parse_options: &CsvParseOptions) -> Vec
 
     let headers = byterecord
         .map(|(slice, needs_escaping)| {
-            let slice_escaped = if needs_escaping && (slice.len() >= 2) {
-                &slice[1..(slice.len() - 1)]
-            } else {
-                slice
-            };
-            String::from_utf8_lossy(slice_escaped)
+            let slice_escaped = unescape_field(slice, needs_escaping, parse_options.quote_char);
+            String::from_utf8_lossy(&slice_escaped).into_owned()
         })
         .collect::<Vec<_>>();
 
+    let default_name = |name: &str, n: usize| format_pl_smallstr!("{}_duplicated_{}", name, n - 2);
+
+    let Some(template) = &parse_options.duplicate_header_template else {
+        return deduplicate_headers(&headers, default_name);
+    };
+    let deduplicated_headers = deduplicate_headers(&headers, |name, n| {
+        template
+            .replace("{n}", &n.to_string())
+            .replace("{name}", name)
+            .into()
+    });
+    let unique_names = deduplicated_headers.iter().collect::<PlHashSet<_>>();
+    if unique_names.len() == deduplicated_headers.len() {
+        deduplicated_headers
+    } else {
+        polars_warn!(
+            "duplicate header template '{template}' did not produce unique column names, falling back to the default names"
+        );
+        deduplicate_headers(&headers, default_name)
+    }
+}
+
+/// Whether the field counts of the sampled lines differ by at least half of the smallest count
+/// (and at least 2 fields), which suggests the lines are not split on a real separator.
+fn is_field_count_uneven(field_counts: &[usize]) -> bool {
+    let (Some(&min), Some(&max)) = (field_counts.iter().min(), field_counts.iter().max()) else {
+        return false;
+    };
+    max - min >= 2 && 2 * (max - min) >= min
+}
+
+/// Guess the start offsets of fixed-width columns from the byte positions that are blank on
+/// every line. Returns `None` if that yields fewer than 2 columns.
+fn guess_fixed_width_boundaries<'a>(lines: impl Iterator<Item = &'a [u8]>) -> Option<Vec<usize>> {
+    // `blank[i]` is whether byte `i` is a space (or past the end) on all lines seen so far.
+    let mut blank: Vec<bool> = Vec::new();
+    for mut line in lines {
+        if line.last().copied() == Some(b'\r') {
+            line = &line[..line.len() - 1];
+        }
+        if line.len() > blank.len() {
+            blank.resize(line.len(), true);
+        }
+        for (b, c) in blank.iter_mut().zip(line) {
+            *b &= *c == b' ';
+        }
+    }
+
+    let boundaries = (0..blank.len())
+        .filter(|&i| !blank[i] && (i == 0 || blank[i - 1]))
+        .collect::<Vec<_>>();
+    (boundaries.len() >= 2).then_some(boundaries)
+}
+
+/// Rename repeated header names with `rename(name, n)`, where `n` is the occurrence of the name
+/// counting from 1. The first occurrence keeps its name.
+fn deduplicate_headers(
+    headers: &[String],
+    rename: impl Fn(&str, usize) -> PlSmallStr,
+) -> Vec<PlSmallStr> {
     let mut deduplicated_headers = Vec::with_capacity(headers.len());
     let mut header_names = PlHashMap::with_capacity(headers.len());
 
-    for name in &headers {
-        let count = header_names.entry(name.as_ref()).or_insert(0usize);
-        if *count != 0 {
-            deduplicated_headers.push(format_pl_smallstr!("{}_duplicated_{}", name, *count - 1))
+    for name in headers {
+        let count = header_names.entry(name.as_str()).or_insert(0usize);
+        *count += 1;
+        if *count != 1 {
+            deduplicated_headers.push(rename(name, *count))
         } else {
             deduplicated_headers.push(PlSmallStr::from_str(name))
         }
-        *count += 1;
     }
 
     deduplicated_headers
 }
 
+/// Whether `s` is an `i64` written with a fraction of only zeros, e.g. `3.0`.
+fn is_integral_float(s: &str) -> bool {
+    s.split_once('.').is_some_and(|(int, fraction)| {
+        fraction.bytes().all(|b| b == b'0') && int.parse::<i64>().is_ok()
+    })
+}
+
+/// Whether `s` is a NaN or infinity in a spelling that is accepted by the float parser, e.g.
+/// `nan`, `-Inf` or `+INFINITY`.
+fn is_float_special_value(s: &str) -> bool {
+    let s = s.strip_prefix(['+', '-']).unwrap_or(s);
+    ["nan", "inf", "infinity"]
+        .iter()
+        .any(|special| s.eq_ignore_ascii_case(special))
+}
+
+/// Unescape a quoted field the same way the parser does, so that inference sees the value
+/// that will actually be parsed.
+fn unescape_field(slice: &[u8], needs_escaping: bool, quote_char: Option<u8>) -> Cow<'_, [u8]> {
+    let Some(quote_char) = quote_char.filter(|_| needs_escaping && slice.len() >= 2) else {
+        return Cow::Borrowed(slice);
+    };
+    let inner = &slice[1..slice.len() - 1];
+    if slice.last() != Some(&quote_char) || !inner.contains(&quote_char) {
+        return Cow::Borrowed(inner);
+    }
+
+    let mut buf = Vec::with_capacity(slice.len());
+    // SAFETY: the buffer has capacity for `slice.len()` bytes, `slice` is longer than one byte
+    // and ends with the quote character.
+    unsafe {
+        let n_written = escape_field(slice, quote_char, buf.spare_capacity_mut());
+        buf.set_len(n_written);
+    }
+    Cow::Owned(buf)
+}
+
+/// Whether a column with these possible types is inferred as `String` no matter which types are
+/// added to it.
+///
+/// This doesn't hold with a type confidence, which depends on the number of values of each type.
+fn is_string_final(possibilities: &PlIndexMap<DataType, usize>) -> bool {
+    // A set of only nested types may still be merged into a nested supertype.
+    possibilities.keys().any(|dtype| !dtype.is_nested())
+        && finish_infer_field_schema(&possibilities.keys().cloned().collect()) == DataType::String
+}
+
+/// `string_final` marks columns for which [`is_string_final`] holds, their values are no longer
+/// inferred and their type set is reduced to `String`.
+#[allow(clippy::too_many_arguments)]
 fn infer_types_from_line(
     mut line: &[u8],
     infer_all_as_str: bool,
     headers: &mut Vec<PlSmallStr>,
     extend_header_with_unknown_column: bool,
+    max_inferred_columns: Option<usize>,
     parse_options: &CsvParseOptions,
-    column_types: &mut Vec<PlIndexSet<DataType>>,
+    column_types: &mut Vec<PlIndexMap<DataType, usize>>,
     nulls: &mut Vec<bool>,
-) {
+    string_final: &mut Vec<bool>,
+    needs_quoting: &mut Vec<bool>,
+) -> usize {
     let line_len = line.len();
     if line.last().copied() == Some(b'\r') {
         line = &line[..line_len - 1];
     }
 
-    let record = SplitFields::new(
+    let mut record = SplitFields::new(
         line,
         parse_options.separator,
         parse_options.quote_char,
         parse_options.eol_char,
     );
+    let mut n_fields = 0;
 
-    for (i, (slice, needs_escaping)) in record.enumerate() {
+    for (i, (slice, needs_escaping)) in record.by_ref().enumerate() {
+        n_fields += 1;
         if i >= headers.len() {
-            if extend_header_with_unknown_column {
+            // Stop at the limit, the caller raises on the field count.
+            if extend_header_with_unknown_column
+                && max_inferred_columns.is_none_or(|max| headers.len() < max)
+            {
                 headers.push(column_name(i));
                 column_types.push(Default::default());
                 nulls.push(false);
+                string_final.push(false);
+                needs_quoting.push(false);
             } else {
                 break;
             }
         }
 
-        if infer_all_as_str {
-            column_types[i].insert(DataType::String);
+        // Only a quoted field can contain the separator or a line break.
+        if needs_escaping && !needs_quoting[i] {
+            let slice_escaped = unescape_field(slice, needs_escaping, parse_options.quote_char);
+            needs_quoting[i] = slice_escaped.iter().any(|&c| {
+                c == parse_options.separator
+                    || c == parse_options.eol_char
+                    || c == b'\r'
+                    || Some(c) == parse_options.quote_char
+            });
+        }
+
+        // Must match the trim applied by the parser.
+        let slice = if parse_options.trim_fields_for_inference && !needs_escaping {
+            trim_whitespace(slice)
+        } else {
+            slice
+        };
+        if infer_all_as_str || string_final[i] {
+            if infer_all_as_str {
+                *column_types[i].entry(DataType::String).or_default() += 1;
+            }
+            // The type is settled, but the nulls are still recorded.
+            if !nulls[i] {
+                nulls[i] = slice.is_empty()
+                    || parse_options.null_values.is_some() && {
+                        let slice_escaped =
+                            unescape_field(slice, needs_escaping, parse_options.quote_char);
+                        is_null_value(
+                            &String::from_utf8_lossy(&slice_escaped),
+                            &headers[i],
+                            parse_options.null_values.as_ref(),
+                        )
+                    };
+            }
             continue;
         }
 
-        if slice.is_empty() {
+        let slice_escaped = unescape_field(slice, needs_escaping, parse_options.quote_char);
+        let s = String::from_utf8_lossy(&slice_escaped);
+        if slice.is_empty() || is_null_value(&s, &headers[i], parse_options.null_values.as_ref()) {
             nulls[i] = true;
         } else {
-            let slice_escaped = if needs_escaping && (slice.len() >= 2) {
-                &slice[1..(slice.len() - 1)]
-            } else {
-                slice
-            };
-            let s = String::from_utf8_lossy(slice_escaped);
-            let dtype = match &parse_options.null_values {
-                None => Some(infer_field_schema(
-                    &s,
-                    parse_options.try_parse_dates,
-                    parse_options.decimal_comma,
-                )),
-                Some(NullValues::AllColumns(names)) => {
-                    if !names.iter().any(|nv| nv == s.as_ref()) {
-                        Some(infer_field_schema(
-                            &s,
-                            parse_options.try_parse_dates,
-                            parse_options.decimal_comma,
-                        ))
-                    } else {
-                        None
-                    }
-                },
-                Some(NullValues::AllColumnsSingle(name)) => {
-                    if s.as_ref() != name.as_str() {
-                        Some(infer_field_schema(
-                            &s,
-                            parse_options.try_parse_dates,
-                            parse_options.decimal_comma,
-                        ))
-                    } else {
-                        None
-                    }
-                },
-                Some(NullValues::Named(names)) => {
-                    let current_name = &headers[i];
-                    let null_name = &names.iter().find(|name| name.0 == current_name);
-
-                    if let Some(null_name) = null_name {
-                        if null_name.1.as_str() != s.as_ref() {
-                            Some(infer_field_schema(
-                                &s,
-                                parse_options.try_parse_dates,
-                                parse_options.decimal_comma,
-                            ))
-                        } else {
-                            None
-                        }
-                    } else {
-                        Some(infer_field_schema(
-                            &s,
-                            parse_options.try_parse_dates,
-                            parse_options.decimal_comma,
-                        ))
+            let try_parse_dates = parse_options.try_parse_dates_in(&headers[i]);
+            let infer = |s: &str| {
+                if let Some(inferer) = &parse_options.field_type_inferer
+                    && let Some(dtype) = inferer.0.infer(s)
+                {
+                    return dtype;
+                }
+
+                #[cfg(feature = "json")]
+                if parse_options.infer_json_columns {
+                    if let Some(dtype) = infer_json_field_schema(s) {
+                        return dtype;
                     }
-                },
+                }
+
+                // An escaped quote char remains part of the parsed value, so it can't be
+                // parsed as anything but a string.
+                if needs_escaping
+                    && parse_options
+                        .quote_char
+                        .is_some_and(|q| s.as_bytes().contains(&q))
+                {
+                    return DataType::String;
+                }
+
+                #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+                if try_parse_dates
+                    && let Some(dtype) = infer_with_date_formats(s, &parse_options.date_formats)
+                {
+                    return dtype;
+                }
+
+                if parse_options.float_special_values && is_float_special_value(s) {
+                    return DataType::Float64;
+                }
+
+                infer_field_schema(
+                    s,
+                    FieldInferenceOptions {
+                        try_parse_dates,
+                        decimal_comma: parse_options.decimal_comma,
+                        large_ints_as_float: parse_options.large_ints_as_float,
+                        integral_floats_as_int: parse_options.integral_floats_as_int,
+                    },
+                )
             };
-            if let Some(dtype) = dtype {
-                column_types[i].insert(dtype);
+            let types = &mut column_types[i];
+            let n_types = types.len();
+            *types.entry(infer(&s)).or_default() += 1;
+
+            if types.len() != n_types
+                && parse_options.type_confidence.is_none()
+                && is_string_final(types)
+            {
+                *types = PlIndexMap::from_iter([(DataType::String, 1)]);
+                string_final[i] = true;
+            }
+        }
+    }
+
+    let n_fields = n_fields + record.count();
+    // Missing fields are null.
+    for null in nulls.iter_mut().skip(n_fields) {
+        *null = true;
+    }
+    n_fields
+}
+
+/// Whether `value` of `column` is one of the `null_values`.
+fn is_null_value(value: &str, column: &str, null_values: Option<&NullValues>) -> bool {
+    match null_values {
+        None => false,
+        Some(NullValues::AllColumnsSingle(null_value)) => value == null_value.as_str(),
+        Some(NullValues::AllColumns(null_values)) => {
+            null_values.iter().any(|nv| nv.as_str() == value)
+        },
+        Some(NullValues::Named(names)) => names
+            .iter()
+            .find(|(name, _)| name.as_str() == column)
+            .is_some_and(|(_, null_value)| null_value.as_str() == value),
+    }
+}
+
+fn integer_range(dtype: &DataType) -> Option<(i128, i128)> {
+    use DataType::*;
+    Some(match dtype {
+        Int8 => (i8::MIN.into(), i8::MAX.into()),
+        Int16 => (i16::MIN.into(), i16::MAX.into()),
+        Int32 => (i32::MIN.into(), i32::MAX.into()),
+        Int64 => (i64::MIN.into(), i64::MAX.into()),
+        UInt8 => (0, u8::MAX.into()),
+        UInt16 => (0, u16::MAX.into()),
+        UInt32 => (0, u32::MAX.into()),
+        UInt64 => (0, u64::MAX.into()),
+        _ => return None,
+    })
+}
+
+/// Check the sampled values of the columns that `schema_overwrite` declares as an integer type
+/// against the range of that type. Otherwise a wrong declaration only shows up while parsing.
+///
+/// Warns once per column, or raises if `strict_schema_overwrite` is set.
+fn check_schema_overwrite_ranges(
+    headers: &[PlSmallStr],
+    content_lines: &[Buffer<u8>],
+    schema_overwrite: &Schema,
+    parse_options: &CsvParseOptions,
+) -> PolarsResult<()> {
+    let mut declared = headers
+        .iter()
+        .enumerate()
+        .filter_map(|(i, name)| {
+            let dtype = schema_overwrite.get(name)?;
+            Some((i, name, dtype, integer_range(dtype)?))
+        })
+        .collect::<Vec<_>>();
+
+    for line in content_lines {
+        if declared.is_empty() {
+            break;
+        }
+        let mut line = &line[..];
+        if line.last().copied() == Some(b'\r') {
+            line = &line[..line.len() - 1];
+        }
+        let fields = SplitFields::new(
+            line,
+            parse_options.separator,
+            parse_options.quote_char,
+            parse_options.eol_char,
+        )
+        .collect::<Vec<_>>();
+
+        let mut j = 0;
+        while j < declared.len() {
+            let (i, name, dtype, (min, max)) = declared[j];
+            let value = fields.get(i).and_then(|&(slice, needs_escaping)| {
+                let slice_escaped = unescape_field(slice, needs_escaping, parse_options.quote_char);
+                std::str::from_utf8(&slice_escaped)
+                    .ok()?
+                    .trim()
+                    .parse::<i128>()
+                    .ok()
+            });
+            match value {
+                Some(value) if value < min || value > max => {
+                    let msg = format!(
+                        "sampled value {value} of column {name:?} does not fit in the data type \
+                        {dtype} declared in the schema overwrite"
+                    );
+                    polars_ensure!(!parse_options.strict_schema_overwrite, SchemaMismatch: "{msg}");
+                    polars_warn!("{msg}");
+                    declared.swap_remove(j);
+                },
+                _ => j += 1,
             }
         }
     }
+    Ok(())
 }
 
 fn build_schema(
     headers: &[PlSmallStr],
-    column_types: &[PlIndexSet<DataType>],
+    column_types: &[PlIndexMap<DataType, usize>],
+    type_confidence: Option<f64>,
     schema_overwrite: Option<&Schema>,
 ) -> Schema {
     assert!(headers.len() == column_types.len());
@@ -235,11 +684,11 @@ fn build_schema(
         headers
             .iter()
             .zip(column_types)
-            .map(|(field_name, type_possibilities)| {
+            .map(|(field_name, type_counts)| {
                 let (name, dtype) = get_schema_overwrite(field_name).unwrap_or_else(|| {
                     (
                         field_name.clone(),
-                        finish_infer_field_schema(type_possibilities),
+                        finish_infer_field_schema_with_confidence(type_counts, type_confidence),
                     )
                 });
 
@@ -248,6 +697,28 @@ fn build_schema(
     )
 }
 
+/// Determine the data type of a column from the number of sampled values of each type.
+///
+/// If `type_confidence` is set and the most common type makes up at least that fraction of the
+/// values, that type is picked regardless of the other types.
+pub fn finish_infer_field_schema_with_confidence(
+    type_counts: &PlIndexMap<DataType, usize>,
+    type_confidence: Option<f64>,
+) -> DataType {
+    if let Some(type_confidence) = type_confidence
+        && type_counts.len() > 1
+    {
+        let total: usize = type_counts.values().sum();
+        let (dtype, count) = type_counts.iter().max_by_key(|(_, count)| **count).unwrap();
+
+        if *count as f64 >= type_confidence * total as f64 {
+            return dtype.clone();
+        }
+    }
+
+    finish_infer_field_schema(&type_counts.keys().cloned().collect())
+}
+
 pub fn finish_infer_field_schema(possibilities: &PlIndexSet<DataType>) -> DataType {
     // determine data type based on possible types
     // if there are incompatible types, use DataType::String
@@ -273,13 +744,83 @@ pub fn finish_infer_field_schema(possibilities: &PlIndexSet<DataType>) -> DataTy
             // fall down to double for mixed int128 and float
             DataType::Float64
         },
+        #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+        2 if possibilities.contains(&DataType::Date)
+            && possibilities.contains(&DataType::Datetime(TimeUnit::Microseconds, None)) =>
+        {
+            // dates without a time component are read as midnight
+            DataType::Datetime(TimeUnit::Microseconds, None)
+        },
+        // JSON values of differing shapes, e.g. objects with differing keys, are merged into
+        // their supertype. Conflicting shapes such as objects and arrays fall back to String.
+        _ if possibilities.iter().all(|dtype| dtype.is_nested()) => {
+            polars_core::utils::dtypes_to_supertype(possibilities)
+                .ok()
+                .filter(|dtype| dtype.is_nested())
+                .unwrap_or(DataType::String)
+        },
         // default to String for conflicting datatypes (e.g bool and int)
         _ => DataType::String,
     }
 }
 
+/// Infer the nested data type of a JSON object or array value.
+///
+/// Returns `None` if the value is not a valid JSON object or array.
+#[cfg(feature = "json")]
+fn infer_json_field_schema(string: &str) -> Option<DataType> {
+    if !matches!(string.trim_start().as_bytes().first(), Some(b'{' | b'[')) {
+        return None;
+    }
+
+    let dtype = polars_json::ndjson::infer_iter(std::iter::once(string)).ok()?;
+    let dtype = DataType::from_arrow_dtype(&dtype);
+    dtype.is_nested().then_some(dtype)
+}
+
+/// Infer the data type of a record with the user supplied date formats, see
+/// [`CsvParseOptions::with_date_formats`]. The first format that matches wins.
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+fn infer_with_date_formats(string: &str, date_formats: &[PlSmallStr]) -> Option<DataType> {
+    date_formats.iter().find_map(|fmt| {
+        #[cfg(feature = "dtype-datetime")]
+        if chrono::NaiveDateTime::parse_from_str(string, fmt).is_ok() {
+            return Some(DataType::Datetime(TimeUnit::Microseconds, None));
+        }
+        #[cfg(feature = "dtype-date")]
+        if chrono::NaiveDate::parse_from_str(string, fmt).is_ok() {
+            return Some(DataType::Date);
+        }
+        None
+    })
+}
+
+/// Options for [`infer_field_schema`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FieldInferenceOptions {
+    /// Infer date, datetime and time values.
+    pub try_parse_dates: bool,
+    /// Use a comma as the decimal separator of floats.
+    pub decimal_comma: bool,
+    /// Infer integers outside the `Int64` range as `Float64`.
+    pub large_ints_as_float: bool,
+    /// Infer floats without a fractional part, e.g. `1.0`, as `Int64`.
+    pub integral_floats_as_int: bool,
+}
+
 /// Infer the data type of a record
-pub fn infer_field_schema(string: &str, try_parse_dates: bool, decimal_comma: bool) -> DataType {
+pub fn infer_field_schema(string: &str, options: FieldInferenceOptions) -> DataType {
+    let dtype = infer_field_schema_impl(string, options.try_parse_dates, options.decimal_comma);
+    if options.large_ints_as_float && dtype.is_integer() && string.parse::<i64>().is_err() {
+        return DataType::Float64;
+    }
+    if options.integral_floats_as_int && dtype == DataType::Float64 && is_integral_float(string) {
+        return DataType::Int64;
+    }
+    dtype
+}
+
+fn infer_field_schema_impl(string: &str, try_parse_dates: bool, decimal_comma: bool) -> DataType {
     // when quoting is enabled in the reader, these quotes aren't escaped, we default to
     // String for them
     let bytes = string.as_bytes();
@@ -362,12 +903,20 @@ fn column_name(i: usize) -> PlSmallStr {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::csv::read::CsvReadOptions;
+    use crate::csv::read::streaming::read_until_start_and_infer_schema;
+    use crate::utils::compression::ByteSourceReader;
+
+    fn infer(csv: &[u8], options: &CsvReadOptions) -> PolarsResult<SchemaInferenceResult> {
+        let mut reader = ByteSourceReader::from_memory(Buffer::from(csv.to_vec()))?;
+        Ok(read_until_start_and_infer_schema(options, None, None, None, &mut reader)?.0)
+    }
 
     #[test]
     fn test_infer_field_schema_i64_overflow() {
         // Values within i64 range should infer as Int64.
         assert_eq!(
-            infer_field_schema("9223372036854775807", false, false),
+            infer_field_schema("9223372036854775807", Default::default()),
             DataType::Int64,
         );
 
@@ -375,9 +924,15 @@ mod tests {
         // otherwise as String.
         let large = "12345678901234567890";
         #[cfg(feature = "dtype-i128")]
-        assert_eq!(infer_field_schema(large, false, false), DataType::Int128,);
+        assert_eq!(
+            infer_field_schema(large, Default::default()),
+            DataType::Int128,
+        );
         #[cfg(not(feature = "dtype-i128"))]
-        assert_eq!(infer_field_schema(large, false, false), DataType::Int64,);
+        assert_eq!(
+            infer_field_schema(large, Default::default()),
+            DataType::Int64,
+        );
     }
 
     #[test]
@@ -388,4 +943,432 @@ mod tests {
         possibilities.insert(DataType::Int128);
         assert_eq!(finish_infer_field_schema(&possibilities), DataType::Int128);
     }
+
+    #[test]
+    #[cfg(all(
+        feature = "polars-time",
+        feature = "dtype-date",
+        feature = "dtype-datetime"
+    ))]
+    fn test_finish_infer_field_schema_date_and_datetime() {
+        let possibilities: PlIndexSet<DataType> = ["2021-01-01", "2021-01-02 12:30:00"]
+            .into_iter()
+            .map(|s| {
+                let options = FieldInferenceOptions {
+                    try_parse_dates: true,
+                    ..Default::default()
+                };
+                infer_field_schema(s, options)
+            })
+            .collect();
+        assert_eq!(
+            possibilities,
+            PlIndexSet::from_iter([
+                DataType::Date,
+                DataType::Datetime(TimeUnit::Microseconds, None)
+            ])
+        );
+        assert_eq!(
+            finish_infer_field_schema(&possibilities),
+            DataType::Datetime(TimeUnit::Microseconds, None)
+        );
+
+        // Timezone-aware datetimes can't represent a bare date, so these stay String.
+        let possibilities = PlIndexSet::from_iter([
+            DataType::Date,
+            DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+        ]);
+        assert_eq!(finish_infer_field_schema(&possibilities), DataType::String);
+    }
+
+    #[test]
+    fn test_finish_infer_field_schema_with_confidence() {
+        let type_counts = PlIndexMap::from_iter([(DataType::Int64, 999), (DataType::String, 1)]);
+        assert_eq!(
+            finish_infer_field_schema_with_confidence(&type_counts, None),
+            DataType::String
+        );
+        assert_eq!(
+            finish_infer_field_schema_with_confidence(&type_counts, Some(0.99)),
+            DataType::Int64
+        );
+        assert_eq!(
+            finish_infer_field_schema_with_confidence(&type_counts, Some(1.0)),
+            DataType::String
+        );
+    }
+
+    #[test]
+    fn test_unescape_field() {
+        let unescape = |s: &'static str, quote_char| {
+            unescape_field(s.as_bytes(), true, quote_char).into_owned()
+        };
+
+        assert_eq!(unescape(r#""2021-01-01""#, Some(b'"')), b"2021-01-01");
+        assert_eq!(unescape(r#""a""b""#, Some(b'"')), br#"a"b"#);
+        assert_eq!(
+            unescape(r#""""2021-01-01"""#, Some(b'"')),
+            br#""2021-01-01""#
+        );
+        assert_eq!(unescape("'it''s'", Some(b'\'')), b"it's");
+        // A backslash is not an escape character, it stays part of the value.
+        assert_eq!(unescape(r#""a\""b""#, Some(b'"')), br#"a\"b"#);
+        assert_eq!(unescape(r#""a""#, None), br#""a""#);
+    }
+
+    #[test]
+    fn test_infer_wide_file_with_conflicting_types() {
+        const WIDTH: usize = 1000;
+        // Column `i` gets the values of pattern `i % 4`, one per row.
+        let patterns: [&[&str]; 4] = [
+            &["1", "true", "a", "2.5", "3"],
+            &["1", "2.5", "", "3", "4"],
+            &["true", "false", "", "1", "x"],
+            &["1", "2", "3", "4", "5"],
+        ];
+        let expected = [
+            DataType::String,
+            DataType::Float64,
+            DataType::String,
+            DataType::Int64,
+        ];
+
+        let header = (0..WIDTH)
+            .map(|i| format!("c{i}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        let lines = (0..patterns[0].len())
+            .map(|row| {
+                let line = (0..WIDTH)
+                    .map(|i| patterns[i % 4][row])
+                    .collect::<Vec<_>>()
+                    .join(",");
+                Buffer::from(line.into_bytes())
+            })
+            .collect::<Vec<_>>();
+
+        let (schema, ..) = infer_file_schema_impl(
+            &Some(Buffer::from(header.into_bytes())),
+            &lines,
+            false,
+            &CsvParseOptions::default(),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(schema.len(), WIDTH);
+        for (i, dtype) in schema.iter_values().enumerate() {
+            assert_eq!(dtype, &expected[i % 4], "column {i}");
+        }
+    }
+
+    #[test]
+    fn test_schema_overwrite_out_of_range() {
+        let header = Some(Buffer::from(b"a,b".to_vec()));
+        let lines =
+            [b"1,100".to_vec(), b"2,300".to_vec(), b"3,\"-129\"".to_vec()].map(Buffer::from);
+        let schema_overwrite = Schema::from_iter([Field::new("b".into(), DataType::Int8)]);
+        let infer = |parse_options: &CsvParseOptions| {
+            infer_file_schema_impl(
+                &header,
+                &lines,
+                false,
+                parse_options,
+                None,
+                Some(&schema_overwrite),
+                None,
+            )
+        };
+
+        // Only warns by default.
+        let (schema, ..) = infer(&CsvParseOptions::default()).unwrap();
+        assert_eq!(schema.get("b"), Some(&DataType::Int8));
+
+        let err =
+            infer(&CsvParseOptions::default().with_strict_schema_overwrite(true)).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("sampled value 300 of column \"b\"")
+        );
+
+        let schema_overwrite = Schema::from_iter([Field::new("b".into(), DataType::Int16)]);
+        let (schema, ..) = infer_file_schema_impl(
+            &header,
+            &lines,
+            false,
+            &CsvParseOptions::default().with_strict_schema_overwrite(true),
+            None,
+            Some(&schema_overwrite),
+            None,
+        )
+        .unwrap();
+        assert_eq!(schema.get("b"), Some(&DataType::Int16));
+    }
+
+    #[test]
+    fn test_is_string_final() {
+        let types =
+            |dtypes: &[DataType]| PlIndexMap::from_iter(dtypes.iter().cloned().map(|d| (d, 1)));
+        assert!(is_string_final(&types(&[DataType::String])));
+        assert!(is_string_final(&types(&[
+            DataType::Boolean,
+            DataType::Int64
+        ])));
+        assert!(!is_string_final(&types(&[
+            DataType::Int64,
+            DataType::Float64
+        ])));
+        assert!(!is_string_final(&types(&[DataType::Boolean])));
+        let nested = DataType::List(Box::new(DataType::Int64));
+        assert!(!is_string_final(&types(&[
+            nested.clone(),
+            DataType::String.implode()
+        ])));
+        assert!(is_string_final(&types(&[nested, DataType::Int64])));
+    }
+
+    #[test]
+    fn test_guess_fixed_width_boundaries() {
+        assert!(!is_field_count_uneven(&[3, 3, 4]));
+        assert!(!is_field_count_uneven(&[1, 2]));
+        assert!(is_field_count_uneven(&[9, 11, 5]));
+
+        let lines: [&[u8]; 3] = [b"id  name   score", b"1   alice  3.5\r", b"22  bo     10"];
+        assert_eq!(
+            guess_fixed_width_boundaries(lines.into_iter()),
+            Some(vec![0, 4, 11])
+        );
+        let lines: [&[u8]; 2] = [b"a,b c", b"1 2,3"];
+        assert_eq!(guess_fixed_width_boundaries(lines.into_iter()), None);
+    }
+
+    #[test]
+    fn test_is_float_special_value() {
+        for s in [
+            "nan",
+            "NaN",
+            "-nan",
+            "inf",
+            "+inf",
+            "-INF",
+            "Infinity",
+            "-infinity",
+        ] {
+            assert!(is_float_special_value(s), "{s}");
+        }
+        for s in ["", "-", "na", "info", "1.5", "++inf", "in f"] {
+            assert!(!is_float_special_value(s), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_is_integral_float() {
+        assert!(is_integral_float("3.0"));
+        assert!(is_integral_float("-12.000"));
+        assert!(is_integral_float("7."));
+        assert!(!is_integral_float("3.5"));
+        assert!(!is_integral_float("3"));
+        assert!(!is_integral_float("1e3"));
+        assert!(!is_integral_float("99999999999999999999.0"));
+    }
+
+    #[test]
+    fn test_parse_types_row() {
+        let parse_options = CsvParseOptions::default();
+        assert_eq!(
+            parse_types_row(
+                b"integer,String, number ,\"bool\",geopoint\r",
+                &parse_options
+            ),
+            [
+                Some(DataType::Int64),
+                Some(DataType::String),
+                Some(DataType::Float64),
+                Some(DataType::Boolean),
+                None
+            ]
+        );
+        #[cfg(feature = "dtype-date")]
+        assert_eq!(
+            parse_types_row(b"date", &parse_options),
+            [Some(DataType::Date)]
+        );
+    }
+
+    #[test]
+    fn test_infer_schema_had_bom() -> PolarsResult<()> {
+        for has_header in [true, false] {
+            let options = CsvReadOptions::default().with_has_header(has_header);
+            let with_bom = infer(b"\xef\xbb\xbfa,b\n1,2\n", &options)?;
+            assert!(with_bom.had_bom);
+            let without_bom = infer(b"a,b\n1,2\n", &options)?;
+            assert!(!without_bom.had_bom);
+            assert_eq!(with_bom.schema, without_bom.schema);
+        }
+
+        // Only a leading byte order mark counts.
+        assert!(!infer(b"a,b\n\xef\xbb\xbf1,2\n", &CsvReadOptions::default())?.had_bom);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_schema_detected_encoding() -> PolarsResult<()> {
+        let options = CsvReadOptions::default();
+
+        let valid = infer("a,b\n1,héllo\n2,wörld\n".as_bytes(), &options)?;
+        assert_eq!(valid.encoding, DetectedEncoding::Utf8);
+        assert_eq!(valid.schema.len(), 2);
+        // A UTF-8 byte order mark is valid.
+        let bom = infer(b"\xef\xbb\xbfa,b\n1,2\n", &options)?;
+        assert_eq!(bom.encoding, DetectedEncoding::Utf8);
+        assert_eq!(bom.schema.get_at_index(0).unwrap().0.as_str(), "a");
+
+        let invalid = infer(b"a,b\n1,h\xe9llo\n2,x\n", &options)?;
+        assert_eq!(invalid.encoding, DetectedEncoding::InvalidUtf8);
+        assert_eq!(invalid.schema.get("b"), Some(&DataType::String));
+
+        assert_eq!(
+            detect_sample_encoding([&b"\xff\xfea\x00"[..], b"1\x00"].into_iter()),
+            DetectedEncoding::Utf16
+        );
+        assert_eq!(
+            detect_sample_encoding(std::iter::empty()),
+            DetectedEncoding::Utf8
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_schema_header_line_idx() -> PolarsResult<()> {
+        let header_line_idx = |csv: &[u8], options: CsvReadOptions| {
+            infer(csv, &options).map(|result| result.header_line_idx)
+        };
+        let with_comments = || {
+            CsvReadOptions::default().map_parse_options(|opts| opts.with_comment_prefix(Some("#")))
+        };
+
+        assert_eq!(
+            header_line_idx(b"a,b\n1,2\n", CsvReadOptions::default())?,
+            Some(0)
+        );
+        assert_eq!(
+            header_line_idx(b"# one\n# two\na,b\n1,2\n", with_comments())?,
+            Some(2)
+        );
+        assert_eq!(
+            header_line_idx(
+                b"# one\nx,y\n# two\na,b\n1,2\n",
+                with_comments().with_skip_rows(1)
+            )?,
+            Some(3)
+        );
+        assert_eq!(
+            header_line_idx(
+                b"junk\n\"x\ny\"\na,b\n1,2\n",
+                CsvReadOptions::default()
+                    .with_skip_lines(1)
+                    .with_skip_rows(1)
+            )?,
+            Some(3)
+        );
+        assert_eq!(
+            header_line_idx(b"1,2\n", CsvReadOptions::default().with_has_header(false))?,
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_schema_columns_needing_quotes() -> PolarsResult<()> {
+        let csv = b"a,b,c,d,e\n\"x,y\",\"plain\",\"1,5\",\"say \"\"hi\"\"\",\"line\nbreak\"\n";
+        let result = infer(csv, &CsvReadOptions::default())?;
+        assert_eq!(result.columns_needing_quotes, ["a", "c", "d", "e"]);
+
+        // Only `String` columns are reported.
+        let options =
+            CsvReadOptions::default().with_dtype_overwrite(Some(Arc::new(vec![DataType::Int64])));
+        let result = infer(b"a,b\n\"1,5\",x\n", &options)?;
+        assert!(result.columns_needing_quotes.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_schema_non_null_columns() -> PolarsResult<()> {
+        // `b` has a missing value, `c` one after it is inferred as a string, `d` a null value.
+        let csv = b"a,b,c,d,e\n1,,x,NA,p\n2,3,,z,q\n";
+
+        let options = CsvReadOptions::default()
+            .with_infer_nullability(true)
+            .map_parse_options(|parse_options| {
+                parse_options.with_null_values(Some(NullValues::AllColumnsSingle("NA".into())))
+            });
+        let result = infer(csv, &options)?;
+        assert_eq!(result.non_null_columns.unwrap(), ["a", "e"]);
+
+        // Only with `infer_nullability`.
+        let result = infer(csv, &CsvReadOptions::default())?;
+        assert!(result.non_null_columns.is_none());
+
+        // Fields missing at the end of a line are null.
+        let options = CsvReadOptions::default().with_infer_nullability(true);
+        let result = infer(b"a,b\n1,2\n3\n", &options)?;
+        assert_eq!(result.non_null_columns.unwrap(), ["a"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_schema_multiline_quoted_field() -> PolarsResult<()> {
+        // If the quoted line breaks split records, the second half of a record would be
+        // inferred as a row of its own, e.g. `second",1.5` as a `String` and an `Int64` column.
+        let csv = b"id,note,value\n1,\"first\nsecond\",1.5\n2,\"a,\r\nb\",2.5\n3,\"\n\n\",3.5\n";
+        let options = CsvReadOptions::default().with_infer_schema_length(Some(3));
+        let result = infer(csv, &options)?;
+        assert_eq!(
+            result.schema.iter_values().cloned().collect::<Vec<_>>(),
+            [DataType::Int64, DataType::String, DataType::Float64]
+        );
+        assert_eq!(result.columns_needing_quotes, ["note"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recommended_chunk_bytes() -> PolarsResult<()> {
+        let result = infer(b"a,b\n1,x\n22,yy\n333,zzzz\n", &CsvReadOptions::default())?;
+        assert_eq!((result.mean_line_len, result.max_line_len), (6, 9));
+
+        let gb = 1 << 30;
+        let chunk_bytes =
+            |n_threads, bytes_total| result.recommended_chunk_bytes(n_threads, bytes_total);
+        // More threads give smaller chunks, larger files larger chunks.
+        assert!(chunk_bytes(16, gb) < chunk_bytes(4, gb));
+        assert!(chunk_bytes(16, gb) < chunk_bytes(16, 4 * gb));
+        assert_eq!(chunk_bytes(16, gb), gb / 64);
+        // Chunks are bounded from both sides, and never exceed the file.
+        assert_eq!(chunk_bytes(1, 1 << 40), 1 << 26);
+        assert_eq!(chunk_bytes(64, 1 << 20), 1 << 16);
+        assert_eq!(chunk_bytes(4, 100), 100);
+        assert_eq!(chunk_bytes(0, 0), 1);
+
+        // Chunks hold many lines of the mean length, and a few of the longest line.
+        let wide_rows = SchemaInferenceResult {
+            mean_line_len: 1 << 10,
+            max_line_len: 1 << 12,
+            ..result.clone()
+        };
+        assert_eq!(wide_rows.recommended_chunk_bytes(64, 64 << 20), 1 << 20);
+        let long_lines = SchemaInferenceResult {
+            max_line_len: 1 << 20,
+            ..result
+        };
+        assert_eq!(long_lines.recommended_chunk_bytes(64, gb), 16 << 20);
+
+        Ok(())
+    }
 }
@@ -8,7 +8,9 @@ use polars_core::prelude::Schema;
 use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail, polars_ensure};
 
-use crate::csv::read::schema_inference::infer_file_schema_impl;
+use crate::csv::read::schema_inference::{
+    SchemaInferenceResult, detect_sample_encoding, infer_file_schema_impl, parse_types_row,
+};
 use crate::prelude::_csv_read_internal::{SplitLines, is_comment_line};
 use crate::prelude::{CsvParseOptions, CsvReadOptions};
 use crate::utils::compression::{ByteSourceReader, CompressedReader};
@@ -18,8 +20,9 @@ pub type InspectContentFn<'a> = Box<dyn FnMut(&[u8]) + 'a>;
 
 /// Reads bytes from `reader` until the CSV starting point is reached depending on the options.
 ///
-/// Returns the inferred schema and leftover bytes not yet consumed, which may be empty. The
-/// leftover bytes + `reader.read_next_slice` is guaranteed to start at first real content row.
+/// Returns the inferred schema, the encoding observed in the lines sampled for inference and the
+/// leftover bytes not yet consumed, which may be empty. The leftover bytes +
+/// `reader.read_next_slice` is guaranteed to start at first real content row.
 ///
 /// `inspect_first_content_row_fn` allows looking at the first content row, this is where parsing
 /// will start. Beware even if the function is provided it's *not* guaranteed that the returned
@@ -34,7 +37,7 @@ pub fn read_until_start_and_infer_schema_from_compressed_reader(
     projected_schema: Option<SchemaRef>,
     mut inspect_first_content_row_fn: Option<InspectContentFn<'_>>,
     reader: &mut CompressedReader,
-) -> PolarsResult<(Schema, Buffer<u8>)> {
+) -> PolarsResult<(SchemaInferenceResult, Buffer<u8>)> {
     // It's better to be above than below here.
     const ESTIMATED_BYTES_PER_ROW: usize = 200;
 
@@ -44,19 +47,15 @@ pub fn read_until_start_and_infer_schema_from_compressed_reader(
         SkipEmpty,
         SkipRowsBeforeHeader(usize),
         SkipHeader(bool),
+        TypesRow,
         SkipRowsAfterHeader(usize),
         ContentInspect,
         InferCollect,
         Done,
     }
 
-    polars_ensure!(
-        !(options.skip_lines != 0 && options.skip_rows != 0),
-        InvalidOperation: "only one of 'skip_rows'/'skip_lines' may be set"
-    );
-
     // We have to treat skip_lines differently since the lines it skips may not follow regular CSV
-    // quote escape rules.
+    // quote escape rules. It is applied first, `skip_rows` then skips parsed rows after that.
     let prev_leftover = skip_lines_naive_from_compressed_reader(
         options.parse_options.eol_char,
         options.skip_lines,
@@ -66,7 +65,7 @@ pub fn read_until_start_and_infer_schema_from_compressed_reader(
 
     let mut state = if options.has_header {
         State::SkipEmpty
-    } else if options.skip_lines != 0 {
+    } else if options.skip_lines != 0 && options.skip_rows == 0 {
         // skip_lines shouldn't skip extra comments before the header, so directly go to SkipHeader
         // state.
         State::SkipHeader(false)
@@ -78,11 +77,29 @@ pub fn read_until_start_and_infer_schema_from_compressed_reader(
     let infer_schema_length = if options.schema.is_some() {
         // Don't actually infer if the schema is set.
         Some(0)
+    } else if let Some(n_rows) = options.n_rows {
+        // Rows past `n_rows` aren't read, so they shouldn't affect the schema. Keep at least one
+        // row, zero means inferring every column as `String`.
+        let n_rows = n_rows.max(1);
+        Some(
+            options
+                .infer_schema_length
+                .map_or(n_rows, |isl| isl.min(n_rows)),
+        )
     } else {
         options.infer_schema_length
     };
 
+    // With `infer_schema_length == Some(0)` and a header, the header determines the columns and
+    // every column is `String`, so no content row is read for inference. Rows skipped by
+    // `skip_rows_after_header` are still consumed, the leftover starts at the first content row.
+    let header_only = infer_schema_length == Some(0) && options.has_header;
+
     let mut header_line = None;
+    // Physical line index of the current line, `skip_lines` counts physical lines as well.
+    let mut physical_line_idx = options.skip_lines;
+    let mut header_line_idx = None;
+    let mut types_line = None;
     let mut content_lines = Vec::with_capacity(infer_schema_length.unwrap_or_else(|| {
         reader
             .total_len_estimate()
@@ -103,14 +120,22 @@ pub fn read_until_start_and_infer_schema_from_compressed_reader(
         })
         .unwrap_or(usize::MAX);
 
+    let mut had_bom = false;
     let leftover = for_each_line_from_reader_from_compressed_reader(
         &options.parse_options,
         true,
+        &mut had_bom,
         prev_leftover,
         initial_read_size,
         reader,
         |mem_slice_line| {
             let line = &*mem_slice_line;
+            let line_idx = physical_line_idx;
+            if header_line.is_none() {
+                // Quoted fields may span multiple physical lines.
+                physical_line_idx +=
+                    1 + memchr::memchr_iter(options.parse_options.eol_char, line).count();
+            }
 
             let done = loop {
                 match &mut state {
@@ -134,14 +159,26 @@ pub fn read_until_start_and_infer_schema_from_compressed_reader(
                     },
                     State::SkipHeader(did_skip) => {
                         if !options.has_header || *did_skip {
-                            state = State::SkipRowsAfterHeader(options.skip_rows_after_header);
+                            state = if *did_skip && options.types_row {
+                                State::TypesRow
+                            } else {
+                                State::SkipRowsAfterHeader(options.skip_rows_after_header)
+                            };
                             continue;
                         }
 
                         header_line = Some(mem_slice_line.clone());
+                        header_line_idx = Some(line_idx);
                         *did_skip = true;
                         break LineUse::ConsumeDiscard;
                     },
+                    State::TypesRow => {
+                        if !is_comment_line(line, comment_prefix) {
+                            types_line = Some(mem_slice_line.clone());
+                            state = State::SkipRowsAfterHeader(options.skip_rows_after_header);
+                        }
+                        break LineUse::ConsumeDiscard;
+                    },
                     State::SkipRowsAfterHeader(remaining) => {
                         let is_comment = is_comment_line(line, comment_prefix);
 
@@ -161,6 +198,11 @@ pub fn read_until_start_and_infer_schema_from_compressed_reader(
                         state = State::InferCollect;
                     },
                     State::InferCollect => {
+                        if header_only {
+                            state = State::Done;
+                            continue;
+                        }
+
                         if !is_comment_line(line, comment_prefix) {
                             content_lines.push(mem_slice_line.clone());
                             if content_lines.len() >= infer_schema_length.unwrap_or(usize::MAX) {
@@ -185,6 +227,9 @@ pub fn read_until_start_and_infer_schema_from_compressed_reader(
 
     let inferred_schema = infer_schema(
         &header_line,
+        header_line_idx,
+        &types_line,
+        had_bom,
         &content_lines,
         infer_all_as_str,
         options,
@@ -196,8 +241,9 @@ pub fn read_until_start_and_infer_schema_from_compressed_reader(
 
 /// Reads bytes from `reader` until the CSV starting point is reached depending on the options.
 ///
-/// Returns the inferred schema and leftover bytes not yet consumed, which may be empty. The
-/// leftover bytes + `reader.read_next_slice` is guaranteed to start at first real content row.
+/// Returns the inferred schema, the encoding observed in the lines sampled for inference and the
+/// leftover bytes not yet consumed, which may be empty. The leftover bytes +
+/// `reader.read_next_slice` is guaranteed to start at first real content row.
 ///
 /// `inspect_first_content_row_fn` allows looking at the first content row, this is where parsing
 /// will start. Beware even if the function is provided it's *not* guaranteed that the returned
@@ -213,7 +259,7 @@ pub fn read_until_start_and_infer_schema(
     decompressed_file_size_hint: Option<usize>,
     mut inspect_first_content_row_fn: Option<InspectContentFn<'_>>,
     reader: &mut ByteSourceReader<ReaderSource>,
-) -> PolarsResult<(Schema, Buffer<u8>)> {
+) -> PolarsResult<(SchemaInferenceResult, Buffer<u8>)> {
     // It's better to be above than below here.
     const ESTIMATED_BYTES_PER_ROW: usize = 200;
 
@@ -223,19 +269,15 @@ pub fn read_until_start_and_infer_schema(
         SkipEmpty,
         SkipRowsBeforeHeader(usize),
         SkipHeader(bool),
+        TypesRow,
         SkipRowsAfterHeader(usize),
         ContentInspect,
         InferCollect,
         Done,
     }
 
-    polars_ensure!(
-        !(options.skip_lines != 0 && options.skip_rows != 0),
-        InvalidOperation: "only one of 'skip_rows'/'skip_lines' may be set"
-    );
-
     // We have to treat skip_lines differently since the lines it skips may not follow regular CSV
-    // quote escape rules.
+    // quote escape rules. It is applied first, `skip_rows` then skips parsed rows after that.
     let prev_leftover = skip_lines_naive(
         options.parse_options.eol_char,
         options.skip_lines,
@@ -246,7 +288,7 @@ pub fn read_until_start_and_infer_schema(
 
     let mut state = if options.has_header {
         State::SkipEmpty
-    } else if options.skip_lines != 0 {
+    } else if options.skip_lines != 0 && options.skip_rows == 0 {
         // skip_lines shouldn't skip extra comments before the header, so directly go to SkipHeader
         // state.
         State::SkipHeader(false)
@@ -258,11 +300,29 @@ pub fn read_until_start_and_infer_schema(
     let infer_schema_length = if options.schema.is_some() {
         // Don't actually infer if the schema is set.
         Some(0)
+    } else if let Some(n_rows) = options.n_rows {
+        // Rows past `n_rows` aren't read, so they shouldn't affect the schema. Keep at least one
+        // row, zero means inferring every column as `String`.
+        let n_rows = n_rows.max(1);
+        Some(
+            options
+                .infer_schema_length
+                .map_or(n_rows, |isl| isl.min(n_rows)),
+        )
     } else {
         options.infer_schema_length
     };
 
+    // With `infer_schema_length == Some(0)` and a header, the header determines the columns and
+    // every column is `String`, so no content row is read for inference. Rows skipped by
+    // `skip_rows_after_header` are still consumed, the leftover starts at the first content row.
+    let header_only = infer_schema_length == Some(0) && options.has_header;
+
     let mut header_line = None;
+    // Physical line index of the current line, `skip_lines` counts physical lines as well.
+    let mut physical_line_idx = options.skip_lines;
+    let mut header_line_idx = None;
+    let mut types_line = None;
     let mut content_lines = Vec::with_capacity(infer_schema_length.unwrap_or_else(|| {
         decompressed_file_size_hint
             .map(|size| size.saturating_div(ESTIMATED_BYTES_PER_ROW))
@@ -283,15 +343,23 @@ pub fn read_until_start_and_infer_schema(
         })
         .unwrap_or(usize::MAX);
 
+    let mut had_bom = false;
     let leftover = for_each_line_from_reader(
         &options.parse_options,
         true,
+        &mut had_bom,
         prev_leftover,
         initial_read_size,
         decompressed_file_size_hint,
         reader,
         |mem_slice_line| {
             let line = &*mem_slice_line;
+            let line_idx = physical_line_idx;
+            if header_line.is_none() {
+                // Quoted fields may span multiple physical lines.
+                physical_line_idx +=
+                    1 + memchr::memchr_iter(options.parse_options.eol_char, line).count();
+            }
 
             let done = loop {
                 match &mut state {
@@ -315,14 +383,26 @@ pub fn read_until_start_and_infer_schema(
                     },
                     State::SkipHeader(did_skip) => {
                         if !options.has_header || *did_skip {
-                            state = State::SkipRowsAfterHeader(options.skip_rows_after_header);
+                            state = if *did_skip && options.types_row {
+                                State::TypesRow
+                            } else {
+                                State::SkipRowsAfterHeader(options.skip_rows_after_header)
+                            };
                             continue;
                         }
 
                         header_line = Some(mem_slice_line.clone());
+                        header_line_idx = Some(line_idx);
                         *did_skip = true;
                         break LineUse::ConsumeDiscard;
                     },
+                    State::TypesRow => {
+                        if !is_comment_line(line, comment_prefix) {
+                            types_line = Some(mem_slice_line.clone());
+                            state = State::SkipRowsAfterHeader(options.skip_rows_after_header);
+                        }
+                        break LineUse::ConsumeDiscard;
+                    },
                     State::SkipRowsAfterHeader(remaining) => {
                         let is_comment = is_comment_line(line, comment_prefix);
 
@@ -342,6 +422,11 @@ pub fn read_until_start_and_infer_schema(
                         state = State::InferCollect;
                     },
                     State::InferCollect => {
+                        if header_only {
+                            state = State::Done;
+                            continue;
+                        }
+
                         if !is_comment_line(line, comment_prefix) {
                             content_lines.push(mem_slice_line.clone());
                             if content_lines.len() >= infer_schema_length.unwrap_or(usize::MAX) {
@@ -366,6 +451,9 @@ pub fn read_until_start_and_infer_schema(
 
     let inferred_schema = infer_schema(
         &header_line,
+        header_line_idx,
+        &types_line,
+        had_bom,
         &content_lines,
         infer_all_as_str,
         options,
@@ -388,6 +476,7 @@ enum LineUse {
 fn for_each_line_from_reader_from_compressed_reader(
     parse_options: &CsvParseOptions,
     is_file_start: bool,
+    had_bom: &mut bool,
     mut prev_leftover: Buffer<u8>,
     initial_read_size: usize,
     reader: &mut CompressedReader,
@@ -419,6 +508,7 @@ fn for_each_line_from_reader_from_compressed_reader(
             const UTF8_BOM_MARKER: Option<&[u8]> = Some(b"\xef\xbb\xbf");
             if slice.get(0..3) == UTF8_BOM_MARKER {
                 slice = slice.sliced(3..);
+                *had_bom = true;
             }
         }
 
@@ -519,6 +609,7 @@ fn for_each_line_from_reader_from_compressed_reader(
 fn for_each_line_from_reader(
     parse_options: &CsvParseOptions,
     is_file_start: bool,
+    had_bom: &mut bool,
     mut prev_leftover: Buffer<u8>,
     initial_read_size: usize,
     decompressed_file_size_hint: Option<usize>,
@@ -552,6 +643,7 @@ fn for_each_line_from_reader(
             const UTF8_BOM_MARKER: Option<&[u8]> = Some(b"\xef\xbb\xbf");
             if slice.get(0..3) == UTF8_BOM_MARKER {
                 slice = slice.sliced(3..);
+                *had_bom = true;
             }
         }
 
@@ -757,11 +849,14 @@ fn skip_lines_naive(
 
 fn infer_schema(
     header_line: &Option<Buffer<u8>>,
+    header_line_idx: Option<usize>,
+    types_line: &Option<Buffer<u8>>,
+    had_bom: bool,
     content_lines: &[Buffer<u8>],
     infer_all_as_str: bool,
     options: &CsvReadOptions,
     projected_schema: Option<SchemaRef>,
-) -> PolarsResult<Schema> {
+) -> PolarsResult<SchemaInferenceResult> {
     let has_no_inference_data = if options.has_header {
         header_line.is_none()
     } else {
@@ -771,9 +866,14 @@ fn infer_schema(
     if options.raise_if_empty && has_no_inference_data {
         polars_bail!(NoData: "empty CSV");
     }
+    polars_ensure!(
+        options.has_header || !options.types_row,
+        InvalidOperation: "a types row can only be read from a CSV file with a header"
+    );
 
-    let mut inferred_schema = if has_no_inference_data {
-        Schema::default()
+    let (mut inferred_schema, mut columns_needing_quotes, sampled_nulls) = if has_no_inference_data
+    {
+        (Schema::default(), vec![], vec![])
     } else {
         infer_file_schema_impl(
             header_line,
@@ -782,9 +882,27 @@ fn infer_schema(
             &options.parse_options,
             options.column_names_overwrite.as_deref(),
             options.schema_overwrite.as_deref(),
+            options.max_inferred_columns,
         )?
     };
 
+    if let Some(types_line) = types_line {
+        let declared = parse_types_row(types_line, &options.parse_options);
+        for (i, dtype) in declared.into_iter().enumerate() {
+            let Some(dtype) = dtype else { continue };
+            let Some((name, _)) = inferred_schema.get_at_index(i) else {
+                break;
+            };
+            if !options
+                .schema_overwrite
+                .as_ref()
+                .is_some_and(|schema| schema.contains(name))
+            {
+                inferred_schema.set_dtype_at_index(i, dtype);
+            }
+        }
+    }
+
     if let Some(schema) = &options.schema {
         // Note: User can provide schema with more columns, they will simply
         // be projected as NULL.
@@ -829,5 +947,37 @@ fn infer_schema(
         }
     }
 
-    Ok(inferred_schema)
+    columns_needing_quotes.retain(|name| inferred_schema.get(name) == Some(&DataType::String));
+    // Columns are matched by position, as a provided schema may rename them.
+    let non_null_columns = options.infer_nullability.then(|| {
+        inferred_schema
+            .iter_names()
+            .zip(&sampled_nulls)
+            .filter(|(_, has_nulls)| !**has_nulls)
+            .map(|(name, _)| name.clone())
+            .collect()
+    });
+
+    let encoding = detect_sample_encoding(
+        header_line
+            .iter()
+            .chain(content_lines)
+            .map(|line| &line[..]),
+    );
+
+    // Lines are split without their line break.
+    let line_lens = content_lines.iter().map(|line| line.len() + 1);
+    let mean_line_len = line_lens.clone().sum::<usize>() / content_lines.len().max(1);
+    let max_line_len = line_lens.max().unwrap_or(0);
+
+    Ok(SchemaInferenceResult {
+        schema: inferred_schema,
+        encoding,
+        header_line_idx,
+        had_bom,
+        columns_needing_quotes,
+        mean_line_len,
+        max_line_len,
+        non_null_columns,
+    })
 }
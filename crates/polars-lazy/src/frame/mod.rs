@@ -30,7 +30,10 @@ use polars_core::query_result::QueryResult;
 use polars_io::RowIndex;
 use polars_mem_engine::scan_predicate::functions::apply_scan_predicate_to_scan_ir;
 use polars_mem_engine::{Executor, create_multiple_physical_plans, create_physical_plan};
-use polars_ops::frame::{JoinBuildSide, JoinCoalesce, MaintainOrderJoin};
+use polars_ops::frame::{
+    DataFrameJoinOps, JoinBuildSide, JoinBuildTable, JoinCoalesce, MaintainOrderJoin, RightUnique,
+    StringKeyNormalization,
+};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
@@ -1388,6 +1391,13 @@ impl LazyFrame {
             coalesce,
             maintain_order,
             build_side,
+            string_key_normalization,
+            case_insensitive_keys,
+            indicator,
+            right_unique,
+            full_join_hash_seed,
+            drop_keys,
+            right_select,
         } = args;
 
         if slice.is_some() {
@@ -1404,7 +1414,14 @@ impl LazyFrame {
             .join_nulls(nulls_equal)
             .coalesce(coalesce)
             .maintain_order(maintain_order)
-            .build_side(build_side);
+            .build_side(build_side)
+            .string_key_normalization(string_key_normalization)
+            .case_insensitive_keys(case_insensitive_keys)
+            .indicator(indicator)
+            .right_unique(right_unique)
+            .full_join_hash_seed(full_join_hash_seed)
+            .drop_keys(drop_keys)
+            .right_select(right_select);
 
         if let Some(suffix) = suffix {
             builder = builder.suffix(suffix);
@@ -1423,6 +1440,43 @@ impl LazyFrame {
         JoinBuilder::new(self)
     }
 
+    /// Perform an inner or left join against a prebuilt [`JoinBuildTable`].
+    ///
+    /// See [`DataFrameJoinOps::join_with_build_table`]. The table is shared by every plan it
+    /// is used in, so the build side is only hashed once. The key data types are checked
+    /// against the table when the schema is resolved.
+    pub fn join_with_build_table<I, S>(
+        self,
+        table: Arc<JoinBuildTable>,
+        left_on: I,
+        how: JoinType,
+        suffix: Option<PlSmallStr>,
+    ) -> LazyFrame
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let left_on: Arc<[PlSmallStr]> = left_on.into_iter().map(Into::into).collect();
+
+        let schema_table = table.clone();
+        let schema_left_on = left_on.clone();
+        let schema_suffix = suffix.clone();
+        let schema = move |input_schema: &Schema| {
+            schema_table
+                .join_schema(input_schema, &schema_left_on, schema_suffix.clone())
+                .map(Arc::new)
+        };
+
+        // The join is row-wise in the probe side, so it can run per batch. Pushing predicates
+        // or projections past it could drop the key columns.
+        self.map(
+            move |df| df.join_with_build_table(&table, left_on.iter(), how.clone(), suffix.clone()),
+            AllowedOptimizations::STREAMING,
+            Some(Arc::new(schema)),
+            Some("JOIN WITH BUILD TABLE"),
+        )
+    }
+
     /// Gathers rows from this DataFrame based on the indices in idxs.
     ///
     /// idxs must only have a single column of indices.
@@ -2171,6 +2225,13 @@ pub struct JoinBuilder {
     coalesce: JoinCoalesce,
     maintain_order: MaintainOrderJoin,
     build_side: Option<JoinBuildSide>,
+    string_key_normalization: Option<StringKeyNormalization>,
+    case_insensitive_keys: bool,
+    indicator: Option<PlSmallStr>,
+    right_unique: Option<RightUnique>,
+    full_join_hash_seed: Option<u64>,
+    drop_keys: bool,
+    right_select: Option<Vec<PlSmallStr>>,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -2189,6 +2250,13 @@ impl JoinBuilder {
             coalesce: Default::default(),
             maintain_order: Default::default(),
             build_side: None,
+            string_key_normalization: None,
+            case_insensitive_keys: false,
+            indicator: None,
+            right_unique: None,
+            full_join_hash_seed: None,
+            drop_keys: false,
+            right_select: None,
         }
     }
 
@@ -2281,6 +2349,55 @@ impl JoinBuilder {
         self
     }
 
+    /// Normalize string join keys (e.g. trim whitespace) before matching them.
+    pub fn string_key_normalization(
+        mut self,
+        string_key_normalization: Option<StringKeyNormalization>,
+    ) -> Self {
+        self.string_key_normalization = string_key_normalization;
+        self
+    }
+
+    /// Match string join keys case-insensitively. The key columns in the output keep their
+    /// original values.
+    pub fn case_insensitive_keys(mut self, case_insensitive_keys: bool) -> Self {
+        self.case_insensitive_keys = case_insensitive_keys;
+        self
+    }
+
+    /// Add a column with the given name to the output of a full join, that indicates whether a
+    /// row matched on the left side only, the right side only or both sides.
+    pub fn indicator(mut self, indicator: Option<PlSmallStr>) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Keep a single row per join key of the right table before joining, or raise if a key
+    /// occurs more than once.
+    pub fn right_unique(mut self, right_unique: Option<RightUnique>) -> Self {
+        self.right_unique = right_unique;
+        self
+    }
+
+    /// Hash the keys of a full join with a fixed seed, so that the output order is reproducible.
+    /// Other join types ignore the seed.
+    pub fn full_join_hash_seed(mut self, full_join_hash_seed: Option<u64>) -> Self {
+        self.full_join_hash_seed = full_join_hash_seed;
+        self
+    }
+
+    /// Remove the join key columns of both tables from the output.
+    pub fn drop_keys(mut self, drop_keys: bool) -> Self {
+        self.drop_keys = drop_keys;
+        self
+    }
+
+    /// Only keep these columns of the right table in the output, besides its join key columns.
+    pub fn right_select(mut self, right_select: Option<Vec<PlSmallStr>>) -> Self {
+        self.right_select = right_select;
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let opt_state = self.lf.opt_state;
@@ -2295,6 +2412,13 @@ impl JoinBuilder {
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
             build_side: self.build_side,
+            string_key_normalization: self.string_key_normalization,
+            case_insensitive_keys: self.case_insensitive_keys,
+            indicator: self.indicator,
+            right_unique: self.right_unique,
+            full_join_hash_seed: self.full_join_hash_seed,
+            drop_keys: self.drop_keys,
+            right_select: self.right_select,
         };
 
         let lp = self
@@ -2387,6 +2511,13 @@ impl JoinBuilder {
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
             build_side: self.build_side,
+            string_key_normalization: self.string_key_normalization,
+            case_insensitive_keys: self.case_insensitive_keys,
+            indicator: self.indicator,
+            right_unique: self.right_unique,
+            full_join_hash_seed: self.full_join_hash_seed,
+            drop_keys: self.drop_keys,
+            right_select: self.right_select,
         };
         let options = JoinOptions {
             allow_parallel: self.allow_parallel,
@@ -56,9 +56,13 @@ impl LazyFrame {
                 row_index: None,
                 pre_slice: args.n_rows.map(|len| Slice::Positive { offset: 0, len }),
                 cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+                per_column_cast_policy: None,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
                 extra_columns_policy: ExtraColumnsPolicy::Raise,
                 include_file_paths: None,
+                virtual_columns: Vec::new(),
+                predicate_stats: None,
+                max_concurrent_scans: None,
                 deletion_files: None,
                 table_statistics: None,
                 row_count: None,
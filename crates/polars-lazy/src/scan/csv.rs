@@ -117,7 +117,8 @@ impl LazyCsvReader {
     }
 
     /// Skip the first `n` lines during parsing. The header will be parsed at line `n`.
-    /// We don't respect CSV escaping when skipping lines.
+    /// We don't respect CSV escaping when skipping lines. If `skip_rows` is also set, the
+    /// lines are skipped first.
     #[must_use]
     pub fn with_skip_lines(mut self, skip_lines: usize) -> Self {
         self.read_options.skip_lines = skip_lines;
@@ -141,6 +142,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// When [`with_schema_modify`](Self::with_schema_modify) re-applies the dtype overwrite, only
+    /// change the dtypes of columns in the modified schema, never add columns.
+    #[must_use]
+    pub fn with_dtype_overrides_only(mut self, dtype_overrides_only: bool) -> Self {
+        self.read_options.dtype_overrides_only = dtype_overrides_only;
+        self
+    }
+
     /// Overwrite dtypes by position.
     #[must_use]
     pub fn with_dtype_overwrite_by_position(mut self, dtypes: Option<Arc<Vec<DataType>>>) -> Self {
@@ -293,7 +302,7 @@ impl LazyCsvReader {
                 &mut reader,
             )?;
 
-            PolarsResult::Ok(inferred_schema)
+            PolarsResult::Ok(inferred_schema.schema)
         };
 
         let schema = match self.sources.clone() {
@@ -341,7 +350,11 @@ impl LazyCsvReader {
         // the dtypes set may be for the new names, so update again
         if let Some(overwrite_schema) = &self.read_options.schema_overwrite {
             for (name, dtype) in overwrite_schema.iter() {
-                schema.with_column(name.clone(), dtype.clone());
+                if self.read_options.dtype_overrides_only {
+                    schema.set_dtype(name, dtype.clone());
+                } else {
+                    schema.with_column(name.clone(), dtype.clone());
+                }
             }
         }
 
@@ -386,9 +399,13 @@ impl LazyFileListReader for LazyCsvReader {
                 row_index,
                 pre_slice,
                 cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+                per_column_cast_policy: None,
                 missing_columns_policy,
                 extra_columns_policy: ExtraColumnsPolicy::Raise,
                 include_file_paths: self.include_file_paths,
+                virtual_columns: Vec::new(),
+                predicate_stats: None,
+                max_concurrent_scans: None,
                 deletion_files: None,
                 table_statistics: None,
                 row_count: None,
@@ -139,9 +139,13 @@ impl LazyFileListReader for LazyJsonLineReader {
             row_index: self.row_index,
             pre_slice: self.n_rows.map(|len| Slice::Positive { offset: 0, len }),
             cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+            per_column_cast_policy: None,
             missing_columns_policy: MissingColumnsPolicy::Raise,
             extra_columns_policy: ExtraColumnsPolicy::Raise,
             include_file_paths: self.include_file_paths,
+            virtual_columns: Vec::new(),
+            predicate_stats: None,
+            max_concurrent_scans: None,
             deletion_files: None,
             table_statistics: None,
             row_count: None,
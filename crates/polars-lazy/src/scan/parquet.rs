@@ -90,6 +90,7 @@ impl LazyFileListReader for LazyParquetReader {
                 .n_rows
                 .map(|len| Slice::Positive { offset: 0, len }),
             cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+            per_column_cast_policy: None,
             missing_columns_policy: if self.args.allow_missing_columns {
                 MissingColumnsPolicy::Insert
             } else {
@@ -97,6 +98,9 @@ impl LazyFileListReader for LazyParquetReader {
             },
             extra_columns_policy: ExtraColumnsPolicy::Raise,
             include_file_paths: self.args.include_file_paths,
+            virtual_columns: Vec::new(),
+            predicate_stats: None,
+            max_concurrent_scans: None,
             deletion_files: None,
             table_statistics: None,
             row_count: None,
@@ -786,6 +786,34 @@ fn scan_anonymous_fn_count() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn scan_csv_dtype_overrides_only() -> PolarsResult<()> {
+    let overwrite = Arc::new(Schema::from_iter([
+        Field::new("sugars_g".into(), DataType::Float64),
+        Field::new("not_in_file".into(), DataType::Int64),
+    ]));
+    let schema = |dtype_overrides_only: bool| -> PolarsResult<SchemaRef> {
+        LazyCsvReader::new(PlRefPath::new(FOODS_CSV))
+            .with_dtype_overwrite(Some(overwrite.clone()))
+            .with_dtype_overrides_only(dtype_overrides_only)
+            .with_schema_modify(Ok)?
+            .finish()?
+            .collect_schema()
+    };
+
+    let out = schema(true)?;
+    assert_eq!(
+        out.iter_names().collect::<Vec<_>>(),
+        &["category", "calories", "fats_g", "sugars_g"]
+    );
+    assert_eq!(out.get("sugars_g"), Some(&DataType::Float64));
+    assert_eq!(out.get("calories"), Some(&DataType::Int64));
+
+    // By default the overwrite may add columns.
+    assert!(schema(false)?.contains("not_in_file"));
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
@@ -810,3 +838,187 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_scan_per_column_cast_policy() -> PolarsResult<()> {
+    use polars_buffer::Buffer;
+
+    let mut buffers = vec![];
+    for mut df in [
+        df!["a" => [1.5], "b" => [2.5]]?,
+        df!["a" => [1i64], "b" => [2i64]]?,
+    ] {
+        let mut buf = vec![];
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buffers.push(Buffer::from(buf));
+    }
+    let sources = ScanSources::Buffers(buffers.into());
+
+    let allow_float = CastColumnsPolicy {
+        integer_to_float_cast: true,
+        ..CastColumnsPolicy::ERROR_ON_MISMATCH
+    };
+    let args = UnifiedScanArgs {
+        per_column_cast_policy: Some(Arc::new(PerColumnCastPolicy(PlIndexMapHashable(
+            PlIndexMap::from_iter([("a".into(), allow_float)]),
+        )))),
+        ..Default::default()
+    };
+    let scan = |name: &str| -> PolarsResult<DataFrame> {
+        let lf: LazyFrame =
+            DslBuilder::scan_ipc(sources.clone(), Default::default(), args.clone())?
+                .build()
+                .into();
+        lf.select([col(name)]).collect()
+    };
+
+    // Only `a` may be cast from the integers of the second file to the floats of the first.
+    let out = scan("a")?;
+    assert_eq!(Vec::from(out.column("a")?.f64()?), [Some(1.5), Some(1.0)]);
+    assert!(scan("b").is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_scan_virtual_columns() -> PolarsResult<()> {
+    use polars_buffer::Buffer;
+
+    let mut buffers = vec![];
+    for mut df in [df!["a" => [1, 2]]?, df!["a" => [3]]?] {
+        let mut buf = vec![];
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buffers.push(Buffer::from(buf));
+    }
+    let sources = ScanSources::Buffers(buffers.into());
+
+    let scan = |virtual_columns: Vec<(PlSmallStr, Scalar)>| -> PolarsResult<LazyFrame> {
+        let args = UnifiedScanArgs {
+            virtual_columns,
+            ..Default::default()
+        };
+        Ok(
+            DslBuilder::scan_ipc(sources.clone(), Default::default(), args)?
+                .build()
+                .into(),
+        )
+    };
+
+    let lf = scan(vec![
+        (
+            "system".into(),
+            Scalar::from(PlSmallStr::from_static("crm")),
+        ),
+        ("batch".into(), Scalar::from(7i64)),
+    ])?;
+    let out = lf
+        .clone()
+        .filter(col("a").gt(lit(1)))
+        .select([col("system"), col("a")])
+        .collect()?;
+    let expected = df![
+        "system" => ["crm", "crm"],
+        "a" => [2, 3],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = lf.collect()?;
+    assert_eq!(out.get_column_names(), &["a", "system", "batch"]);
+    assert_eq!(Vec::from(out.column("batch")?.i64()?), [Some(7); 3]);
+
+    // Virtual columns may not collide with the columns of the file.
+    let lf = scan(vec![("a".into(), Scalar::from(1i64))])?;
+    assert!(lf.collect().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_scan_predicate_stats() -> PolarsResult<()> {
+    use polars_buffer::Buffer;
+    use polars_plan::dsl::predicate_stats::{FilePredicateStats, PredicateStatsCollector};
+
+    let mut buffers = vec![];
+    for mut df in [df!["a" => [1, 2, 3]]?, df!["a" => [4, 5]]?] {
+        let mut buf = vec![];
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buffers.push(Buffer::from(buf));
+    }
+
+    let predicate_stats = Arc::new(PredicateStatsCollector::default());
+    let args = UnifiedScanArgs {
+        predicate_stats: Some(predicate_stats.clone()),
+        ..Default::default()
+    };
+    let lf: LazyFrame = DslBuilder::scan_ipc(
+        ScanSources::Buffers(buffers.into()),
+        Default::default(),
+        args,
+    )?
+    .build()
+    .into();
+    let out = lf.filter(col("a").lt_eq(lit(4))).collect()?;
+    assert_eq!(out.height(), 4);
+
+    assert_eq!(
+        predicate_stats.report(),
+        [
+            FilePredicateStats {
+                scan_source_idx: 0,
+                rows_before: 3,
+                rows_after: 3,
+            },
+            FilePredicateStats {
+                scan_source_idx: 1,
+                rows_before: 2,
+                rows_after: 1,
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_scan_head_stops_starting_files() -> PolarsResult<()> {
+    use std::num::NonZeroUsize;
+
+    use polars_buffer::Buffer;
+    use polars_plan::dsl::predicate_stats::PredicateStatsCollector;
+
+    // Every file has a single row that passes the predicate.
+    let n_files = 20;
+    let mut buffers = vec![];
+    for i in 0..n_files {
+        let mut df = df!["a" => (0..10).map(|x| i * 10 + x).collect::<Vec<i32>>()]?;
+        let mut buf = vec![];
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buffers.push(Buffer::from(buf));
+    }
+
+    let predicate_stats = Arc::new(PredicateStatsCollector::default());
+    let args = UnifiedScanArgs {
+        predicate_stats: Some(predicate_stats.clone()),
+        max_concurrent_scans: NonZeroUsize::new(1),
+        ..Default::default()
+    };
+    let lf: LazyFrame = DslBuilder::scan_ipc(
+        ScanSources::Buffers(buffers.into()),
+        Default::default(),
+        args,
+    )?
+    .build()
+    .into();
+    let out = lf
+        .filter((col("a") % lit(10)).eq(lit(0)))
+        .limit(2)
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), [Some(0), Some(10)]);
+
+    // Rows are counted after the predicate, so only the first few files are started.
+    let report = predicate_stats.report();
+    assert!(report.len() < n_files as usize / 2, "{report:?}");
+    assert!(report.iter().all(|file| file.rows_after <= 1));
+    Ok(())
+}
@@ -109,6 +109,195 @@ fn test_lazy_logical_plan_schema() {
     assert!(lp.compute_schema().unwrap().get("sepal_width").is_some());
 }
 
+#[test]
+fn test_join_coalesce_overridden_in_plan() -> PolarsResult<()> {
+    let left = df!("a" => [1, 2])?.lazy();
+    let right = df!("a" => [2, 3])?.lazy();
+
+    let plan = left
+        .join(
+            right,
+            [col("a") + lit(1)],
+            [col("a")],
+            JoinArgs {
+                how: JoinType::Left,
+                coalesce: JoinCoalesce::CoalesceColumns,
+                ..Default::default()
+            },
+        )
+        .to_alp()?;
+
+    let options = plan
+        .lp_arena
+        .iter(plan.lp_top)
+        .find_map(|(_, ir)| match ir {
+            IR::Join { options, .. } => Some(options.clone()),
+            _ => None,
+        })
+        .unwrap();
+    assert!(options.coalesce_overridden);
+    assert_eq!(options.args.coalesce, JoinCoalesce::KeepColumns);
+    assert!(plan.describe().contains("KEY COALESCING DISABLED"));
+
+    Ok(())
+}
+
+#[test]
+fn test_join_build_side_from_row_estimates() -> PolarsResult<()> {
+    let small = df!("a" => [1, 2])?.lazy();
+    let medium = df!("a" => [1, 2, 3])?.lazy();
+    let large = df!("a" => (0..10).collect::<Vec<i32>>())?.lazy();
+
+    let build_side = |left: LazyFrame, right: LazyFrame, args: JoinArgs| -> PolarsResult<_> {
+        let plan = left.join(right, [col("a")], [col("a")], args).to_alp()?;
+        Ok(plan
+            .lp_arena
+            .iter(plan.lp_top)
+            .find_map(|(_, ir)| match ir {
+                IR::Join { options, .. } => Some(options.args.build_side.clone()),
+                _ => None,
+            })
+            .unwrap())
+    };
+    let inner = || JoinArgs::new(JoinType::Inner);
+
+    assert_eq!(
+        build_side(small.clone(), large.clone(), inner())?,
+        Some(JoinBuildSide::PreferLeft)
+    );
+    assert_eq!(
+        build_side(large.clone(), small.clone(), inner())?,
+        Some(JoinBuildSide::PreferRight)
+    );
+    // A slice caps the estimate.
+    assert_eq!(
+        build_side(large.clone().slice(0, 1), large.clone(), inner())?,
+        Some(JoinBuildSide::PreferLeft)
+    );
+    // Close estimates leave the build side to the engine.
+    assert_eq!(build_side(small.clone(), medium, inner())?, None);
+    assert_eq!(build_side(small.clone(), small.clone(), inner())?, None);
+    // A filter has no estimate.
+    assert_eq!(
+        build_side(
+            large.clone(),
+            large.clone().filter(col("a").lt(lit(1))),
+            inner()
+        )?,
+        None
+    );
+    // Without an estimate the build side is left to the engine.
+    assert_eq!(
+        build_side(
+            large.clone().group_by([col("a")]).agg([len()]),
+            large.clone(),
+            inner()
+        )?,
+        None
+    );
+    // A build side set by the user is kept.
+    let mut args = inner();
+    args.build_side = Some(JoinBuildSide::ForceRight);
+    assert_eq!(
+        build_side(small, large, args)?,
+        Some(JoinBuildSide::ForceRight)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_join_key_casts_are_not_multiplied() -> PolarsResult<()> {
+    let left = df!("a" => [1i64, 2, 3], "b" => [1i64, 2, 3])?.lazy();
+    let right = df!("a" => [1i32, 2], "b" => [1i32, 2])?.lazy().cache();
+
+    let mut q = left;
+    for _ in 0..3 {
+        let keys = [col("a"), col("b")];
+        q = q.join(right.clone(), &keys, &keys, JoinArgs::new(JoinType::Inner));
+    }
+    let plan = q.to_alp()?;
+
+    let mut casts = PlHashSet::new();
+    let mut n_keys = 0;
+    for (_, ir) in plan.lp_arena.iter(plan.lp_top) {
+        let IR::Join {
+            left_on, right_on, ..
+        } = ir
+        else {
+            continue;
+        };
+        // Every join casts each of its keys exactly once, to the `Int64` supertype.
+        for e in left_on.iter().chain(right_on) {
+            let key_casts: Vec<_> = plan
+                .expr_arena
+                .iter(e.node())
+                .filter(|(_, ae)| matches!(ae, AExpr::Cast { .. }))
+                .map(|(node, _)| node)
+                .collect();
+            assert_eq!(key_casts.len(), 1);
+            casts.extend(key_casts);
+            n_keys += 1;
+        }
+    }
+    assert_eq!(n_keys, 3 * 2 * 2);
+    // No cast node is shared between joins.
+    assert_eq!(casts.len(), n_keys);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "iejoin", feature = "cross_join"))]
+fn test_join_where_equality_keys() -> PolarsResult<()> {
+    let left = df!(
+        "a" => [Some(1), Some(2), Some(2), None],
+        "x" => [1, 5, 10, 3]
+    )?
+    .lazy();
+    let right = df!(
+        "b" => [Some(2), Some(1), Some(2), None],
+        "x" => [4, 2, 8, 1]
+    )?
+    .lazy();
+
+    let predicates = vec![col("b").eq(col("a")), col("x").lt(col("x_right"))];
+    let q = left
+        .clone()
+        .join_builder()
+        .with(right.clone())
+        .join_where(predicates.clone());
+
+    // The equality is used as a hash join key, only the inequality remains as a filter.
+    let plan = q.clone().to_alp()?;
+    let (how, left_on) = plan
+        .lp_arena
+        .iter(plan.lp_top)
+        .find_map(|(_, ir)| match ir {
+            IR::Join {
+                options, left_on, ..
+            } => Some((options.args.how.clone(), left_on.clone())),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(how, JoinType::Inner);
+    assert_eq!(left_on.len(), 1);
+    assert_eq!(left_on[0].output_name().as_str(), "a");
+
+    let sort = |lf: LazyFrame| {
+        lf.sort(["a", "x", "b", "x_right"], Default::default())
+            .collect()
+    };
+    let out = sort(q.with_predicate_pushdown(false))?;
+    let expected = sort(
+        left.cross_join(right, None)
+            .filter(predicates.into_iter().reduce(|l, r| l.and(r)).unwrap()),
+    )?;
+    assert_eq!(out.height(), 2);
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
+
 #[test]
 fn test_lazy_logical_plan_join() {
     let left = df!("days" => &[0, 1, 2, 3, 4],
@@ -199,6 +199,42 @@ fn test_filter_nulls_created_by_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_filter_on_full_join_with_indicator() -> PolarsResult<()> {
+    let a = df![
+        "key" => [1, 2],
+        "x" => ["a", "b"],
+    ]?
+    .lazy();
+    let b = df![
+        "key" => [2, 3],
+        "y" => ["c", "d"],
+    ]?
+    .lazy();
+
+    // Without the indicator this filter rewrites the full join into a right join.
+    let out = a
+        .join_builder()
+        .with(b)
+        .left_on([col("key")])
+        .right_on([col("key")])
+        .how(JoinType::Full)
+        .coalesce(JoinCoalesce::CoalesceColumns)
+        .indicator(Some("_merge".into()))
+        .finish()
+        .filter(col("y").is_not_null())
+        .select([col("key"), col("_merge").cast(DataType::String)])
+        .sort(["key"], Default::default())
+        .collect()?;
+    let expected = df![
+        "key" => [2, 3],
+        "_merge" => ["both", "right_only"],
+    ]?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
 #[test]
 fn test_filter_null_creation_by_cast() -> PolarsResult<()> {
     let df = df![
@@ -1221,6 +1221,53 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[cfg(feature = "dtype-struct")]
+#[test]
+fn test_join_on_struct_field() -> PolarsResult<()> {
+    let left = df![
+        "id" => [1, 2, 3],
+        "a" => ["x", "y", "z"]
+    ]?
+    .lazy()
+    .select([as_struct(vec![col("id"), col("a")]).alias("l")]);
+    let right = df![
+        "id" => [3, 1, 4],
+        "b" => [30, 10, 40]
+    ]?
+    .lazy()
+    .select([as_struct(vec![col("id"), col("b")]).alias("r")]);
+
+    let out = left
+        .clone()
+        .join(
+            right.clone(),
+            [col("l").struct_().field_by_name("id")],
+            [col("r").struct_().field_by_name("id")],
+            JoinType::Inner.into(),
+        )
+        .sort_by_exprs([col("l").struct_().field_by_name("id")], Default::default())
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["l", "r"]);
+    let b = out.column("r")?.struct_()?.field_by_name("b")?;
+    assert_eq!(Vec::from(b.i32()?), &[Some(10), Some(30)]);
+
+    // Mixing a nested key with a plain column key.
+    let out = left
+        .join(
+            right.select([col("r").struct_().field_by_name("id")]),
+            [col("l").struct_().field_by_name("id")],
+            [col("id")],
+            JoinType::Left.into(),
+        )
+        .collect()?;
+    assert_eq!(out.shape(), (3, 2));
+    assert_eq!(
+        Vec::from(out.column("id")?.i32()?),
+        &[Some(1), None, Some(3)]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
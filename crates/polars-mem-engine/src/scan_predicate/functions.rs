@@ -472,9 +472,13 @@ where
         row_index,
         pre_slice,
         cast_columns_policy: _,
+        per_column_cast_policy: _,
         missing_columns_policy: _,
         extra_columns_policy: _,
         include_file_paths: _,
+        virtual_columns: _,
+        predicate_stats: _,
+        max_concurrent_scans: _,
         deletion_files,
         table_statistics,
         row_count,
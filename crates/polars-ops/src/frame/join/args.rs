@@ -15,12 +15,20 @@ pub type ChunkJoinOptIds = Vec<NullableIdxSize>;
 #[cfg(not(feature = "chunked_ids"))]
 pub type ChunkJoinIds = Vec<IdxSize>;
 
+use either::Either;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
 
+use crate::series::SeriesMethods;
+
 /// Parameters for which side to use as the build side in a join. Currently only
 /// respected by the streaming engine.
+///
+/// If not set, the query planner prefers the side with the lower row count estimate for equi
+/// joins where both estimates are available. The in-memory engine ignores this preference: it
+/// knows the actual row counts of both sides when it builds the hash table, and already builds
+/// it on the shorter side.
 #[derive(Clone, PartialEq, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -48,12 +56,117 @@ pub struct JoinArgs {
     pub coalesce: JoinCoalesce,
     pub maintain_order: MaintainOrderJoin,
     pub build_side: Option<JoinBuildSide>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub string_key_normalization: Option<StringKeyNormalization>,
+    /// Compare string join keys case-insensitively, under full case folding. Unlike
+    /// [`StringKeyNormalization::Lowercase`] this also matches e.g. "ß" with "SS". The key columns
+    /// in the output keep their original values.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub case_insensitive_keys: bool,
+    /// Name of a column to add to the output of a full join, that indicates whether a row matched
+    /// on the left side only, the right side only or both sides.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub indicator: Option<PlSmallStr>,
+    /// Keep a single row per join key of the right side before joining.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub right_unique: Option<RightUnique>,
+    /// Seed for hashing the join keys of a full join. By default every join uses a random seed.
+    /// Other join types ignore it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub full_join_hash_seed: Option<u64>,
+    /// Remove the join key columns of both sides from the output.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub drop_keys: bool,
+    /// Only keep these columns of the right side, besides its join key columns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub right_select: Option<Vec<PlSmallStr>>,
 }
 
 impl JoinArgs {
     pub fn should_coalesce(&self) -> bool {
         self.coalesce.coalesce(&self.how)
     }
+
+    /// How string join keys are matched, `None` if they are compared as is.
+    pub(super) fn string_key_matching(&self) -> Option<StringKeyMatching> {
+        use StringKeyNormalization as N;
+        if self.string_key_normalization.is_none() && !self.case_insensitive_keys {
+            return None;
+        }
+        Some(StringKeyMatching {
+            trim: matches!(
+                self.string_key_normalization,
+                Some(N::Trim | N::TrimLowercase)
+            ),
+            lowercase: matches!(
+                self.string_key_normalization,
+                Some(N::Lowercase | N::TrimLowercase)
+            ),
+            case_fold: self.case_insensitive_keys,
+        })
+    }
+}
+
+/// Which row to keep of right side rows with the same join key, see [`JoinArgs::right_unique`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum RightUnique {
+    FirstMatch,
+    LastMatch,
+    /// Raise if a join key occurs more than once on the right side.
+    Error,
+}
+
+/// Normalization applied to string join keys before they are hashed and compared. The key columns
+/// in the output keep their original values.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum StringKeyNormalization {
+    /// Remove leading and trailing whitespace.
+    Trim,
+    /// Lowercase every character, see [`JoinArgs::case_insensitive_keys`] for case folding.
+    Lowercase,
+    TrimLowercase,
+}
+
+/// The combination of [`JoinArgs::string_key_normalization`] and
+/// [`JoinArgs::case_insensitive_keys`]. String keys match if their normalized forms are equal.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct StringKeyMatching {
+    trim: bool,
+    lowercase: bool,
+    case_fold: bool,
+}
+
+impl StringKeyMatching {
+    /// The characters of the normalized form of `s`.
+    pub(super) fn chars(self, s: &str) -> impl Iterator<Item = char> + '_ {
+        let s = if self.trim { s.trim() } else { s };
+        s.chars().flat_map(move |c| {
+            if self.case_fold {
+                // Full case folding, a character may fold to multiple characters: "ß" -> "ss".
+                Either::Left(Either::Left(c.to_uppercase().flat_map(char::to_lowercase)))
+            } else if self.lowercase {
+                Either::Left(Either::Right(c.to_lowercase()))
+            } else {
+                Either::Right(std::iter::once(c))
+            }
+        })
+    }
+
+    /// Materialize the normalized keys. Only used for keys that are row encoded, which copies
+    /// them anyway; single keys are normalized while they are hashed and compared.
+    pub(super) fn normalize(self, ca: &StringChunked) -> StringChunked {
+        if self.lowercase || self.case_fold {
+            ca.apply_values(|s| Cow::Owned(self.chars(s).collect()))
+        } else {
+            ca.apply_values(|s| Cow::Borrowed(s.trim()))
+        }
+    }
 }
 
 #[derive(Clone, PartialEq, Hash, Default, IntoStaticStr)]
@@ -152,6 +265,13 @@ impl JoinArgs {
             coalesce: Default::default(),
             maintain_order: Default::default(),
             build_side: None,
+            string_key_normalization: None,
+            case_insensitive_keys: false,
+            indicator: None,
+            right_unique: None,
+            full_join_hash_seed: None,
+            drop_keys: false,
+            right_select: None,
         }
     }
 
@@ -170,12 +290,117 @@ impl JoinArgs {
         self
     }
 
+    /// Normalize string join keys before matching them. Joining on keys of other types is an error.
+    pub fn with_string_key_normalization(
+        mut self,
+        string_key_normalization: Option<StringKeyNormalization>,
+    ) -> Self {
+        self.string_key_normalization = string_key_normalization;
+        self
+    }
+
+    /// Match string join keys case-insensitively, while the output keeps the original values of
+    /// both sides. Joining on keys of other types is an error.
+    pub fn with_case_insensitive_keys(mut self, case_insensitive_keys: bool) -> Self {
+        self.case_insensitive_keys = case_insensitive_keys;
+        self
+    }
+
+    /// Add an indicator column with the given name to the output of a full join. See
+    /// [`join_indicator_dtype`] for its values.
+    pub fn with_indicator(mut self, indicator: Option<PlSmallStr>) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Deduplicate the right side by its join keys before joining, so that every left row matches
+    /// at most one right row. This is the same as calling `unique` on the right keys first.
+    pub fn with_right_unique(mut self, right_unique: Option<RightUnique>) -> Self {
+        self.right_unique = right_unique;
+        self
+    }
+
+    /// Hash the keys of a full join with a fixed seed.
+    ///
+    /// Without [`MaintainOrderJoin`] the rows of the hashed side without a match are emitted in
+    /// the order of the hash table, so a fixed seed makes the output order reproducible across
+    /// runs. Only full joins use the seed, other join types ignore it.
+    pub fn with_full_join_hash_seed(mut self, full_join_hash_seed: Option<u64>) -> Self {
+        self.full_join_hash_seed = full_join_hash_seed;
+        self
+    }
+
+    /// Remove the join key columns of both sides from the output, e.g. for joins that only filter
+    /// or enrich the left side. This is the same as dropping the keys after the join.
+    pub fn with_drop_keys(mut self, drop_keys: bool) -> Self {
+        self.drop_keys = drop_keys;
+        self
+    }
+
+    /// Only bring these columns of the right side into the output, besides its join key columns.
+    /// This is the same as selecting them before the join, but doesn't require listing the keys.
+    pub fn with_right_select(mut self, right_select: Option<Vec<PlSmallStr>>) -> Self {
+        self.right_select = right_select;
+        self
+    }
+
+    /// The columns to keep of the right side with schema `schema_right`, or `None` to keep all of
+    /// them.
+    ///
+    /// These are the columns of [`Self::right_select`] together with the columns used by
+    /// `right_keys` and the `by` columns of an asof join, in the order of the right side.
+    pub fn right_select_columns<'a>(
+        &self,
+        right_keys: impl IntoIterator<Item = &'a PlSmallStr>,
+        schema_right: &Schema,
+    ) -> PolarsResult<Option<Vec<PlSmallStr>>> {
+        let Some(right_select) = &self.right_select else {
+            return Ok(None);
+        };
+        for name in right_select {
+            polars_ensure!(
+                schema_right.contains(name),
+                ColumnNotFound: "`right_select` column {:?} not found in the right frame", name
+            );
+        }
+
+        let mut keep: PlHashSet<&str> = right_select.iter().map(|name| name.as_str()).collect();
+        keep.extend(right_keys.into_iter().map(|name| name.as_str()));
+        #[cfg(feature = "asof_join")]
+        if let JoinType::AsOf(options) = &self.how {
+            keep.extend(options.right_by.iter().flatten().map(|name| name.as_str()));
+        }
+        Ok(Some(
+            schema_right
+                .iter_names()
+                .filter(|name| keep.contains(name.as_str()))
+                .cloned()
+                .collect(),
+        ))
+    }
+
     pub fn suffix(&self) -> &PlSmallStr {
         const DEFAULT: &PlSmallStr = &PlSmallStr::from_static("_right");
         self.suffix.as_ref().unwrap_or(DEFAULT)
     }
 }
 
+/// The values of the join indicator column, in the order of its categories.
+pub const JOIN_INDICATOR_VALUES: [&str; 3] = ["left_only", "right_only", "both"];
+
+/// The data type of the join indicator column, an `Enum` of [`JOIN_INDICATOR_VALUES`] (or
+/// `String` if categorical types are not enabled).
+pub fn join_indicator_dtype() -> DataType {
+    #[cfg(feature = "dtype-categorical")]
+    {
+        DataType::from_frozen_categories(FrozenCategories::new(JOIN_INDICATOR_VALUES).unwrap())
+    }
+    #[cfg(not(feature = "dtype-categorical"))]
+    {
+        DataType::String
+    }
+}
+
 impl From<JoinType> for JoinArgs {
     fn from(value: JoinType) -> Self {
         JoinArgs::new(value)
@@ -356,14 +581,29 @@ pub enum JoinValidation {
     OneToMany,
     /// Check if join keys are unique in both left and right datasets
     OneToOne,
+    /// Check if the (leading) join keys are sorted in both left and right datasets.
+    ///
+    /// By default only the sorted flag is inspected. If `verify_values` is set, keys that are not
+    /// flagged as sorted are checked by scanning their values.
+    RequireSortedKeys {
+        #[cfg_attr(feature = "serde", serde(default))]
+        verify_values: bool,
+    },
 }
 
 impl JoinValidation {
     pub fn needs_checks(&self) -> bool {
-        !matches!(self, JoinValidation::ManyToMany)
+        !matches!(
+            self,
+            JoinValidation::ManyToMany | JoinValidation::RequireSortedKeys { .. }
+        )
+    }
+
+    pub fn requires_sorted_keys(&self) -> bool {
+        matches!(self, JoinValidation::RequireSortedKeys { .. })
     }
 
-    fn swap(self, swap: bool) -> Self {
+    pub(super) fn swap(self, swap: bool) -> Self {
         use JoinValidation::*;
         if swap {
             match self {
@@ -371,6 +611,7 @@ impl JoinValidation {
                 ManyToOne => OneToMany,
                 OneToMany => ManyToOne,
                 OneToOne => OneToOne,
+                RequireSortedKeys { verify_values } => RequireSortedKeys { verify_values },
             }
         } else {
             self
@@ -378,6 +619,11 @@ impl JoinValidation {
     }
 
     pub fn is_valid_join(&self, join_type: &JoinType) -> PolarsResult<()> {
+        if self.requires_sorted_keys() {
+            polars_ensure!(join_type.is_equi() || join_type.is_semi_anti(),
+                          ComputeError: "{self} validation on a {join_type} join is not supported");
+            return Ok(());
+        }
         if !self.needs_checks() {
             return Ok(());
         }
@@ -407,7 +653,7 @@ impl JoinValidation {
         let valid = match self.swap(should_swap) {
             // Only check the `build` side.
             // The other side use `validate_build` to check
-            ManyToMany | ManyToOne => true,
+            ManyToMany | ManyToOne | RequireSortedKeys { .. } => true,
             OneToMany | OneToOne => {
                 if !nulls_equal && probe.null_count() > 0 {
                     probe.n_unique()? - 1 == probe.len() - probe.null_count()
@@ -432,12 +678,31 @@ impl JoinValidation {
         let valid = match self.swap(swapped) {
             // Only check the `build` side.
             // The other side use `validate_prone` to check
-            ManyToMany | OneToMany => true,
+            ManyToMany | OneToMany | RequireSortedKeys { .. } => true,
             ManyToOne | OneToOne => build_size == expected_size,
         };
         polars_ensure!(valid, ComputeError: "join keys did not fulfill {} validation", self);
         Ok(())
     }
+
+    /// Check the sortedness of a join key if this validation requires it.
+    pub(super) fn validate_sorted(&self, s: &Series, side: &str) -> PolarsResult<()> {
+        let JoinValidation::RequireSortedKeys { verify_values } = *self else {
+            return Ok(());
+        };
+        let valid = if verify_values {
+            s.is_sorted_any(None, None)?
+        } else {
+            s.len() <= 1 || !matches!(s.is_sorted_flag(), IsSorted::Not)
+        };
+        polars_ensure!(
+            valid,
+            ComputeError: "join key `{}` on the {} side did not fulfill {} validation{}",
+            s.name(), side, self,
+            if verify_values { "" } else { "; the key is not flagged as sorted" }
+        );
+        Ok(())
+    }
 }
 
 impl Display for JoinValidation {
@@ -447,6 +712,7 @@ impl Display for JoinValidation {
             JoinValidation::ManyToOne => "m:1",
             JoinValidation::OneToMany => "1:m",
             JoinValidation::OneToOne => "1:1",
+            JoinValidation::RequireSortedKeys { .. } => "sorted",
         };
         write!(f, "{s}")
     }
@@ -132,6 +132,7 @@ pub(crate) fn join_asof_numeric<T: PolarsNumericType>(
             AsofStrategy::Forward => join_asof_forward::<T, _>(left, right, filter, allow_eq),
             AsofStrategy::Backward => join_asof_backward::<T, _>(left, right, filter, allow_eq),
             AsofStrategy::Nearest => join_asof_nearest::<T, _>(left, right, filter, allow_eq),
+            AsofStrategy::PerRow(_) => unreachable!(),
         }
     } else {
         let filter = |_l: T::Native, _r: T::Native| true;
@@ -139,6 +140,7 @@ pub(crate) fn join_asof_numeric<T: PolarsNumericType>(
             AsofStrategy::Forward => join_asof_forward::<T, _>(left, right, filter, allow_eq),
             AsofStrategy::Backward => join_asof_backward::<T, _>(left, right, filter, allow_eq),
             AsofStrategy::Nearest => join_asof_nearest::<T, _>(left, right, filter, allow_eq),
+            AsofStrategy::PerRow(_) => unreachable!(),
         }
     };
     Ok(out)
@@ -173,6 +175,7 @@ where
             "AsOf strategy \"nearest\" is not supported for {} data type",
             T::get_static_dtype()
         ),
+        AsofStrategy::PerRow(_) => unreachable!(),
     })
 }
 
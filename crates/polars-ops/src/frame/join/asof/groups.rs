@@ -181,7 +181,7 @@ where
     let left_val_arr = left_asof.downcast_as_array();
     let right_val_arr = right_asof.downcast_as_array();
 
-    let (prep_by_left, prep_by_right, _, _) = prepare_binary::<B>(by_left, by_right, false);
+    let (prep_by_left, prep_by_right, _) = prepare_binary::<B>(by_left, by_right, false, None);
     let offsets = compute_len_offsets(prep_by_left.iter().map(|s| s.len()));
     let hash_tbls = build_tables(prep_by_right, false);
     let n_tables = hash_tbls.len();
@@ -354,6 +354,7 @@ where
             left_asof, right_asof, left_by, right_by, filter, allow_eq,
         ),
         AsofStrategy::Nearest => unimplemented!(),
+        AsofStrategy::PerRow(_) => unreachable!(),
     }
 }
 
@@ -383,6 +384,7 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
                 left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
+            AsofStrategy::PerRow(_) => unreachable!(),
         }
     } else {
         let filter = |_a: T::Physical<'_>, _b: T::Physical<'_>| true;
@@ -396,6 +398,7 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
                 left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
+            AsofStrategy::PerRow(_) => unreachable!(),
         }
     }
 }
@@ -410,6 +413,10 @@ fn dispatch_join_type(
     tolerance: Option<AnyValue<'static>>,
     allow_eq: bool,
 ) -> PolarsResult<IdxArr> {
+    polars_ensure!(
+        !matches!(strategy, AsofStrategy::PerRow(_)),
+        InvalidOperation: "per-row asof strategy must be resolved against the left DataFrame"
+    );
     match left_asof.dtype() {
         DataType::Int64 => {
             let ca = left_asof.i64().unwrap();
@@ -567,15 +574,23 @@ pub trait AsofJoinBy: IntoDf {
             *r = r.to_physical_repr();
         }
 
-        let right_join_tuples = dispatch_join_type(
-            &left_asof,
-            &right_asof,
-            &mut left_by,
-            &mut right_by,
-            strategy,
-            tolerance,
-            allow_eq,
-        )?;
+        let mut join = |strategy: AsofStrategy| -> PolarsResult<IdxCa> {
+            let idx = dispatch_join_type(
+                &left_asof,
+                &right_asof,
+                &mut left_by,
+                &mut right_by,
+                strategy,
+                tolerance.clone(),
+                allow_eq,
+            )?;
+            Ok(IdxCa::with_chunk(PlSmallStr::EMPTY, idx))
+        };
+        let right_join_tuples = if let AsofStrategy::PerRow(direction) = &strategy {
+            asof_join_per_row(asof_direction_column(self_df, direction)?, join)?
+        } else {
+            join(strategy)?
+        };
 
         let mut drop_these = right_by.get_column_names();
         if coalesce && left_asof_name == right_asof_name {
@@ -593,9 +608,7 @@ pub trait AsofJoinBy: IntoDf {
         let left = self_df.clone();
 
         // SAFETY: join tuples are in bounds.
-        let right_df = unsafe {
-            proj_other_df.take_unchecked(&IdxCa::with_chunk(PlSmallStr::EMPTY, right_join_tuples))
-        };
+        let right_df = unsafe { proj_other_df.take_unchecked(&right_join_tuples) };
 
         _finish_join(left, right_df, suffix)
     }
@@ -886,4 +899,79 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_asof_per_row() -> PolarsResult<()> {
+        let a = df![
+            "a" => [1, 5, 5, 9],
+            "g" => ["x", "x", "x", "x"],
+            "backward" => [Some(true), Some(true), Some(false), None],
+            "n" => [1, 2, 3, 4],
+        ]?;
+        let b = df![
+            "a" => [0, 4, 6, 10],
+            "g" => ["x", "x", "x", "x"],
+            "right_vals" => [10, 40, 60, 100],
+        ]?;
+        let strategy = AsofStrategy::PerRow("backward".into());
+        let expected = &[Some(10), Some(40), Some(60), None];
+
+        let out = a._join_asof(
+            &b,
+            a.column("a")?.as_materialized_series(),
+            b.column("a")?.as_materialized_series(),
+            strategy.clone(),
+            None,
+            None,
+            None,
+            true,
+            true,
+            true,
+        )?;
+        assert_eq!(Vec::from(out.column("right_vals")?.i32()?), expected);
+
+        let out = a.join_asof_by(&b, "a", "a", ["g"], ["g"], strategy, None, true, true)?;
+        assert_eq!(Vec::from(out.column("right_vals")?.i32()?), expected);
+
+        // A single direction gives the same result as the matching fixed strategy.
+        let mut a = a;
+        a.with_column(Column::new("backward".into(), [true; 4]))?;
+        let per_row = a.join_asof_by(
+            &b,
+            "a",
+            "a",
+            ["g"],
+            ["g"],
+            AsofStrategy::PerRow("backward".into()),
+            None,
+            true,
+            true,
+        )?;
+        let backward = a.join_asof_by(
+            &b,
+            "a",
+            "a",
+            ["g"],
+            ["g"],
+            AsofStrategy::Backward,
+            None,
+            true,
+            true,
+        )?;
+        assert!(per_row.equals_missing(&backward));
+
+        let out = a.join_asof_by(
+            &b,
+            "a",
+            "a",
+            ["g"],
+            ["g"],
+            AsofStrategy::PerRow("n".into()),
+            None,
+            true,
+            true,
+        );
+        assert!(out.is_err());
+        Ok(())
+    }
 }
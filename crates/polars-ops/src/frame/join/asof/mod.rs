@@ -256,7 +256,11 @@ pub fn _check_asof_columns(
     Ok(())
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+/// The direction in which an asof join searches for a match.
+///
+/// This is not `Copy`, as [`AsofStrategy::PerRow`] holds a column name. Code that copied a
+/// strategy out of a reference needs to `clone` it instead.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub enum AsofStrategy {
@@ -267,6 +271,62 @@ pub enum AsofStrategy {
     Forward,
     /// selects the right in the right DataFrame whose 'on' key is nearest to the left's key.
     Nearest,
+    /// selects per row with the given boolean column of the left DataFrame: `true` searches
+    /// backward, `false` searches forward and `null` does not match.
+    PerRow(PlSmallStr),
+}
+
+/// Get the control column of [`AsofStrategy::PerRow`] from the left DataFrame.
+pub(crate) fn asof_direction_column<'a>(
+    df: &'a DataFrame,
+    name: &str,
+) -> PolarsResult<&'a BooleanChunked> {
+    let control = df.column(name)?;
+    let direction = control.bool().map_err(|_| {
+        polars_err!(
+            SchemaMismatch: "asof join direction column '{}' must be of type Boolean, got {}",
+            name, control.dtype()
+        )
+    })?;
+    polars_ensure!(
+        direction.len() == df.height(),
+        ShapeMismatch: "asof join direction column '{}' has length {}, expected {}",
+        name, direction.len(), df.height()
+    );
+    Ok(direction)
+}
+
+/// Pick the backward match where `direction` is true and the forward match where it is false.
+///
+/// The asof join only runs in the directions that occur in `direction`.
+pub(crate) fn asof_join_per_row<F>(direction: &BooleanChunked, mut join: F) -> PolarsResult<IdxCa>
+where
+    F: FnMut(AsofStrategy) -> PolarsResult<IdxCa>,
+{
+    let backward = (direction.num_trues() > 0)
+        .then(|| join(AsofStrategy::Backward))
+        .transpose()?;
+    let forward = (direction.num_falses() > 0)
+        .then(|| join(AsofStrategy::Forward))
+        .transpose()?;
+
+    match (backward, forward) {
+        (Some(idx), None) | (None, Some(idx)) if direction.null_count() == 0 => Ok(idx),
+        (backward, forward) => {
+            let mut backward = backward.as_ref().map(|ca| ca.iter());
+            let mut forward = forward.as_ref().map(|ca| ca.iter());
+            let take_idx = direction.iter().map(|direction| {
+                let backward = backward.as_mut().and_then(|it| it.next()).flatten();
+                let forward = forward.as_mut().and_then(|it| it.next()).flatten();
+                match direction {
+                    Some(true) => backward,
+                    Some(false) => forward,
+                    None => None,
+                }
+            });
+            Ok(IdxCa::from_iter_options(PlSmallStr::EMPTY, take_idx))
+        },
+    }
 }
 
 pub trait AsofJoin: IntoDf {
@@ -297,8 +357,14 @@ pub trait AsofJoin: IntoDf {
         let left_key = left_key.to_physical_repr();
         let right_key = right_key.to_physical_repr();
 
-        let mut take_idx =
-            _join_asof_dispatch(&left_key, &right_key, strategy, tolerance, allow_eq)?;
+        let mut take_idx = if let AsofStrategy::PerRow(direction) = &strategy {
+            let direction = asof_direction_column(self_df, direction)?;
+            asof_join_per_row(direction, |strategy| {
+                _join_asof_dispatch(&left_key, &right_key, strategy, tolerance.clone(), allow_eq)
+            })?
+        } else {
+            _join_asof_dispatch(&left_key, &right_key, strategy, tolerance, allow_eq)?
+        };
 
         try_raise_polars_abort();
 
@@ -329,6 +395,10 @@ pub fn _join_asof_dispatch(
     tolerance: Option<AnyValue<'static>>,
     allow_eq: bool,
 ) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        !matches!(strategy, AsofStrategy::PerRow(_)),
+        InvalidOperation: "per-row asof strategy must be resolved against the left DataFrame"
+    );
     let take_idx = match left_key.dtype() {
         DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16 => {
             let left_key = left_key.cast(&DataType::Int32).unwrap();
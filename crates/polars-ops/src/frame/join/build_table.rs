@@ -0,0 +1,156 @@
+use polars_core::prelude::*;
+use polars_utils::idx_vec::IdxVec;
+
+use super::general::get_suffix;
+use super::{_join_suffix_name, prepare_keys_multiple};
+
+/// The build side of a hash join, which can be probed by many joins.
+///
+/// Building the hash table dominates the cost of joining many small frames against one larger
+/// frame, e.g. repeated lookups in a dimension table. A [`JoinBuildTable`] is built once and
+/// reused by [`DataFrameJoinOps::join_with_build_table`](super::DataFrameJoinOps::join_with_build_table).
+#[derive(Clone)]
+pub struct JoinBuildTable {
+    /// The non-key columns of the build frame.
+    payload: DataFrame,
+    key_schema: Schema,
+    nulls_equal: bool,
+    table: PlHashMap<Vec<u8>, IdxVec>,
+}
+
+impl JoinBuildTable {
+    /// Hash the `on` columns of `df`.
+    pub fn new(
+        df: &DataFrame,
+        on: impl IntoIterator<Item = impl AsRef<str>>,
+        nulls_equal: bool,
+    ) -> PolarsResult<Self> {
+        let keys = df
+            .select_to_vec(on)?
+            .into_iter()
+            .map(Column::take_materialized_series)
+            .collect::<Vec<_>>();
+        polars_ensure!(!keys.is_empty(), InvalidOperation: "expected join keys");
+
+        let key_schema = keys
+            .iter()
+            .map(|s| Field::new(s.name().clone(), s.dtype().clone()))
+            .collect::<Schema>();
+        let payload = df.drop_many(key_schema.iter_names().cloned());
+
+        let mut table = PlHashMap::<Vec<u8>, IdxVec>::new();
+        let encoded = prepare_keys_multiple(&keys, nulls_equal)?;
+        // Keys containing a null are encoded as null unless nulls are equal.
+        for (idx, key) in encoded.iter().enumerate() {
+            if let Some(key) = key {
+                table.entry(key.to_vec()).or_default().push(idx as IdxSize);
+            }
+        }
+
+        Ok(Self {
+            payload,
+            key_schema,
+            nulls_equal,
+            table,
+        })
+    }
+
+    /// The names and data types of the key columns.
+    pub fn key_schema(&self) -> &Schema {
+        &self.key_schema
+    }
+
+    pub fn nulls_equal(&self) -> bool {
+        self.nulls_equal
+    }
+
+    /// Number of rows in the build frame.
+    pub fn height(&self) -> usize {
+        self.payload.height()
+    }
+
+    /// The output schema of joining a frame with schema `left_schema` on `left_on` against this
+    /// table.
+    pub fn join_schema(
+        &self,
+        left_schema: &Schema,
+        left_on: &[PlSmallStr],
+        suffix: Option<PlSmallStr>,
+    ) -> PolarsResult<Schema> {
+        polars_ensure!(
+            left_on.len() == self.key_schema.len(),
+            InvalidOperation: "the number of join keys ({}) does not match the number of keys of the build table ({})",
+            left_on.len(), self.key_schema.len()
+        );
+        for (name, (build_name, build_dtype)) in left_on.iter().zip(self.key_schema.iter()) {
+            let dtype = left_schema.try_get(name)?;
+            polars_ensure!(
+                dtype == build_dtype,
+                SchemaMismatch: "datatypes of join keys don't match - `{}`: {} on left does not match `{}`: {} in the build table",
+                name, dtype, build_name, build_dtype
+            );
+        }
+
+        let suffix = get_suffix(suffix);
+        let mut schema = left_schema.clone();
+        for (name, dtype) in self.payload.schema().iter() {
+            let name = if left_schema.contains(name) {
+                _join_suffix_name(name.as_str(), suffix.as_str())
+            } else {
+                name.clone()
+            };
+            schema.with_column(name, dtype.clone());
+        }
+        Ok(schema)
+    }
+
+    /// Probe the table with the given keys, returning the matching (probe, build) row indices.
+    ///
+    /// For a left join probe rows without a match are kept with a null build index.
+    pub(super) fn probe(&self, keys: &[Series], how: &JoinType) -> PolarsResult<(IdxCa, IdxCa)> {
+        polars_ensure!(
+            keys.len() == self.key_schema.len(),
+            InvalidOperation: "the number of join keys ({}) does not match the number of keys of the build table ({})",
+            keys.len(), self.key_schema.len()
+        );
+        for (s, (name, dtype)) in keys.iter().zip(self.key_schema.iter()) {
+            polars_ensure!(
+                s.dtype() == dtype,
+                SchemaMismatch: "datatypes of join keys don't match - `{}`: {} on left does not match `{}`: {} in the build table",
+                s.name(), s.dtype(), name, dtype
+            );
+        }
+        let keep_unmatched = match how {
+            JoinType::Inner => false,
+            JoinType::Left => true,
+            how => polars_bail!(InvalidOperation: "{how} join is not supported with a build table"),
+        };
+
+        let encoded = prepare_keys_multiple(keys, self.nulls_equal)?;
+        let mut probe_idx = Vec::with_capacity(encoded.len());
+        let mut build_idx = Vec::with_capacity(encoded.len());
+        for (idx, key) in encoded.iter().enumerate() {
+            let idx = idx as IdxSize;
+            match key.and_then(|key| self.table.get(key)) {
+                Some(matches) => {
+                    probe_idx.extend(std::iter::repeat_n(idx, matches.len()));
+                    build_idx.extend(matches.iter().map(|&i| Some(i)));
+                },
+                None if keep_unmatched => {
+                    probe_idx.push(idx);
+                    build_idx.push(None);
+                },
+                None => {},
+            }
+        }
+
+        Ok((
+            IdxCa::from_vec(PlSmallStr::EMPTY, probe_idx),
+            IdxCa::from_iter_options(PlSmallStr::EMPTY, build_idx.into_iter()),
+        ))
+    }
+
+    pub(super) fn payload(&self) -> &DataFrame {
+        &self.payload
+    }
+}
@@ -72,13 +72,22 @@ pub fn materialize_left_join_from_series(
         s_right = s_right.rechunk();
     }
 
-    let (left_idx, right_idx) = sort_or_hash_left(
-        &s_left,
-        &s_right,
-        verbose,
-        args.validation,
-        args.nulls_equal,
-    )?;
+    let (left_idx, right_idx) = match args.string_key_matching() {
+        Some(matching) => hash_join_left_normalized(
+            &s_left,
+            &s_right,
+            matching,
+            args.validation,
+            args.nulls_equal,
+        )?,
+        None => sort_or_hash_left(
+            &s_left,
+            &s_right,
+            verbose,
+            args.validation,
+            args.nulls_equal,
+        )?,
+    };
 
     let right = if let Some(drop_names) = drop_names {
         right.drop_many(drop_names)
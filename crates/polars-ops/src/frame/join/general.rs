@@ -7,7 +7,65 @@ pub fn _join_suffix_name(name: &str, suffix: &str) -> PlSmallStr {
     format_pl_smallstr!("{name}{suffix}")
 }
 
-fn get_suffix(suffix: Option<PlSmallStr>) -> PlSmallStr {
+/// Names of the join key columns in the output of a join.
+///
+/// `left_on` and `right_on` hold the names of the key columns in the left and right input, or
+/// `None` for keys that aren't columns of the input. Those don't end up in the output.
+pub fn _join_key_output_names(
+    left_on: &[Option<PlSmallStr>],
+    right_on: &[Option<PlSmallStr>],
+    schema_left: &Schema,
+    args: &JoinArgs,
+) -> Vec<PlSmallStr> {
+    let should_coalesce = args.should_coalesce();
+    let suffix = args.suffix();
+    let right_name = |name: &PlSmallStr| {
+        if schema_left.contains(name) {
+            _join_suffix_name(name, suffix)
+        } else {
+            name.clone()
+        }
+    };
+
+    #[cfg(feature = "asof_join")]
+    let is_asof = matches!(args.how, JoinType::AsOf(_));
+    #[cfg(not(feature = "asof_join"))]
+    let is_asof = false;
+
+    let mut names = Vec::with_capacity(left_on.len() + right_on.len());
+    match &args.how {
+        JoinType::Cross => {},
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi | JoinType::Anti => names.extend(left_on.iter().flatten().cloned()),
+        // The left keys are coalesced into the right keys.
+        JoinType::Right if should_coalesce => {
+            for name in right_on.iter().flatten() {
+                if left_on.iter().flatten().any(|l| l == name) {
+                    names.push(name.clone())
+                } else {
+                    names.push(right_name(name))
+                }
+            }
+        },
+        _ => {
+            names.extend(left_on.iter().flatten().cloned());
+            for (l, r) in left_on.iter().zip(right_on) {
+                let Some(r) = r else {
+                    continue;
+                };
+                // Asof joins keep the right key if it is named differently, as its values differ
+                // from the left key.
+                let is_coalesced = should_coalesce && !(is_asof && l.as_ref() != Some(r));
+                if !is_coalesced {
+                    names.push(right_name(r))
+                }
+            }
+        },
+    }
+    names
+}
+
+pub(super) fn get_suffix(suffix: Option<PlSmallStr>) -> PlSmallStr {
     suffix.unwrap_or_else(|| PlSmallStr::from_static("_right"))
 }
 
@@ -48,6 +106,10 @@ pub fn _finish_join(
     Ok(df_left)
 }
 
+/// Coalesce the key columns of a full join into the left key columns.
+///
+/// A key is only null on one side if the row has no match on that side, so taking the first
+/// non-null value of the two keys always gives the key of the side the row exists on.
 pub fn _coalesce_full_join(
     mut df: DataFrame,
     keys_left: &[PlSmallStr],
@@ -1,4 +1,5 @@
 #![allow(unsafe_op_in_unsafe_fn)]
+mod normalized_keys;
 pub(super) mod single_keys;
 mod single_keys_dispatch;
 mod single_keys_inner;
@@ -8,6 +9,7 @@ mod single_keys_outer;
 mod single_keys_semi_anti;
 pub(super) mod sort_merge;
 use arrow::array::ArrayRef;
+pub(super) use normalized_keys::*;
 use polars_core::runtime::RAYON;
 use polars_core::utils::_set_partition_size;
 use polars_utils::index::ChunkId;
@@ -129,16 +131,20 @@ pub trait JoinDispatch: IntoDf {
         &self,
         s_left: &Series,
         s_right: &Series,
-        slice: Option<(i64, usize)>,
         anti: bool,
-        nulls_equal: bool,
+        args: JoinArgs,
     ) -> PolarsResult<DataFrame> {
         let ca_self = self.to_df();
 
-        let idx = s_left.hash_join_semi_anti(s_right, anti, nulls_equal)?;
+        let idx = match args.string_key_matching() {
+            Some(matching) => {
+                hash_join_semi_anti_normalized(s_left, s_right, matching, anti, args.nulls_equal)?
+            },
+            None => s_left.hash_join_semi_anti(s_right, anti, args.nulls_equal)?,
+        };
         // SAFETY:
         // indices are in bounds
-        Ok(unsafe { ca_self._finish_anti_semi_join(&idx, slice) })
+        Ok(unsafe { ca_self._finish_anti_semi_join(&idx, args.slice) })
     }
     fn _full_join_from_series(
         &self,
@@ -150,8 +156,22 @@ pub trait JoinDispatch: IntoDf {
         let df_self = self.to_df();
 
         // Get the indexes of the joined relations
-        let (mut join_idx_l, mut join_idx_r) =
-            s_left.hash_join_outer(s_right, args.validation, args.nulls_equal)?;
+        let (mut join_idx_l, mut join_idx_r) = match args.string_key_matching() {
+            Some(matching) => hash_join_outer_normalized(
+                s_left,
+                s_right,
+                matching,
+                args.validation,
+                args.nulls_equal,
+                args.full_join_hash_seed,
+            )?,
+            None => s_left.hash_join_outer(
+                s_right,
+                args.validation,
+                args.nulls_equal,
+                args.full_join_hash_seed,
+            )?,
+        };
 
         try_raise_polars_abort();
         if let Some((offset, len)) = args.slice {
@@ -162,7 +182,7 @@ pub trait JoinDispatch: IntoDf {
         let idx_ca_l = IdxCa::with_chunk("a".into(), join_idx_l);
         let idx_ca_r = IdxCa::with_chunk("b".into(), join_idx_r);
 
-        let (df_left, df_right) = if args.maintain_order != MaintainOrderJoin::None {
+        let (df_left, df_right, indicator) = if args.maintain_order != MaintainOrderJoin::None {
             let mut df = unsafe {
                 DataFrame::new_unchecked_infer_height(vec![
                     idx_ca_l.into_series().into(),
@@ -187,34 +207,68 @@ pub trait JoinDispatch: IntoDf {
 
             let join_tuples_left = df.column("a").unwrap().idx().unwrap();
             let join_tuples_right = df.column("b").unwrap().idx().unwrap();
-            RAYON.join(
+            let indicator = args
+                .indicator
+                .clone()
+                .map(|name| join_indicator(name, join_tuples_left, join_tuples_right))
+                .transpose()?;
+            let (df_left, df_right) = RAYON.join(
                 || unsafe { df_self.take_unchecked(join_tuples_left) },
                 || unsafe { other.take_unchecked(join_tuples_right) },
-            )
+            );
+            (df_left, df_right, indicator)
         } else {
-            RAYON.join(
+            let indicator = args
+                .indicator
+                .clone()
+                .map(|name| join_indicator(name, &idx_ca_l, &idx_ca_r))
+                .transpose()?;
+            let (df_left, df_right) = RAYON.join(
                 || unsafe { df_self.take_unchecked(&idx_ca_l) },
                 || unsafe { other.take_unchecked(&idx_ca_r) },
-            )
+            );
+            (df_left, df_right, indicator)
         };
 
         let coalesce = args.coalesce.coalesce(&JoinType::Full);
-        if coalesce {
+        let mut out = if coalesce {
             let tmp_right_name = unique_column_name();
             let mut df_right = df_right;
             df_right.rename(s_right.name().as_str(), tmp_right_name.clone())?;
             let out = _finish_join(df_left, df_right, args.suffix.clone())?;
-            Ok(_coalesce_full_join(
+            _coalesce_full_join(
                 out,
                 &[s_left.name().clone()],
                 &[tmp_right_name],
                 args.suffix,
                 df_self,
-            ))
+            )
         } else {
-            _finish_join(df_left, df_right, args.suffix.clone())
+            _finish_join(df_left, df_right, args.suffix.clone())?
+        };
+
+        if let Some(indicator) = indicator {
+            out.hstack_mut(&[indicator])?;
         }
+        Ok(out)
     }
 }
 
+/// Whether each row of a full join matched on the left side only, the right side only or both,
+/// given the gathered row indices of both sides.
+fn join_indicator(name: PlSmallStr, idx_left: &IdxCa, idx_right: &IdxCa) -> PolarsResult<Column> {
+    let [left_only, right_only, both] = JOIN_INDICATOR_VALUES;
+    let values = idx_left
+        .iter()
+        .zip(idx_right.iter())
+        .map(|(l, r)| match (l, r) {
+            (Some(_), Some(_)) => both,
+            (Some(_), None) => left_only,
+            _ => right_only,
+        });
+    StringChunked::from_iter_values(name, values)
+        .into_column()
+        .cast(&join_indicator_dtype())
+}
+
 impl JoinDispatch for DataFrame {}
@@ -0,0 +1,271 @@
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use arrow::array::PrimitiveArray;
+use polars_core::utils::_split_offsets;
+use polars_utils::aliases::{PlSeedableRandomStateQuality, SeedableFromU64SeedExt};
+use polars_utils::hashing::DirtyHash;
+use polars_utils::nulls::IsNull;
+use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
+
+use super::*;
+
+/// A string join key with a precomputed hash of its normalized form. Keys are compared by their
+/// normalized characters, so the normalized strings are never materialized.
+#[derive(Copy, Clone, Debug)]
+pub(super) struct NormalizedStrHash<'a> {
+    payload: Option<&'a str>,
+    hash: u64,
+    matching: StringKeyMatching,
+}
+
+impl PartialEq for NormalizedStrHash<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+            && match (self.payload, other.payload) {
+                (Some(l), Some(r)) => l == r || self.matching.chars(l).eq(self.matching.chars(r)),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl Eq for NormalizedStrHash<'_> {}
+
+impl Hash for NormalizedStrHash<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash)
+    }
+}
+
+impl TotalHash for NormalizedStrHash<'_> {
+    #[inline(always)]
+    fn tot_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.hash(state)
+    }
+}
+
+impl TotalEq for NormalizedStrHash<'_> {
+    #[inline(always)]
+    fn tot_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<'a> ToTotalOrd for NormalizedStrHash<'a> {
+    type TotalOrdItem = NormalizedStrHash<'a>;
+    type SourceItem = NormalizedStrHash<'a>;
+
+    #[inline]
+    fn to_total_ord(&self) -> Self::TotalOrdItem {
+        *self
+    }
+
+    #[inline]
+    fn peel_total_ord(ord_item: Self::TotalOrdItem) -> Self::SourceItem {
+        ord_item
+    }
+}
+
+impl DirtyHash for NormalizedStrHash<'_> {
+    fn dirty_hash(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl<'a> IsNull for NormalizedStrHash<'a> {
+    const HAS_NULLS: bool = true;
+    type Inner = NormalizedStrHash<'a>;
+
+    #[inline(always)]
+    fn is_null(&self) -> bool {
+        self.payload.is_none()
+    }
+
+    fn unwrap_inner(self) -> Self::Inner {
+        assert!(self.payload.is_some());
+        self
+    }
+}
+
+fn str_keys(s: &Series) -> PolarsResult<&StringChunked> {
+    polars_ensure!(
+        s.dtype() == &DataType::String,
+        InvalidOperation: "case-insensitive or normalized join keys must be of type String, got `{}`: {}",
+        s.name(), s.dtype()
+    );
+    s.str()
+}
+
+fn normalized_hashes<'a>(
+    ca: &'a StringChunked,
+    matching: StringKeyMatching,
+    hb: &PlSeedableRandomStateQuality,
+) -> Vec<Vec<NormalizedStrHash<'a>>> {
+    let null_h = hb.hash_one(0xde259df92c607d49_u64);
+    let n_partitions = _set_partition_size();
+    let partitions = ca
+        .downcast_iter()
+        .flat_map(|arr| {
+            _split_offsets(arr.len(), n_partitions)
+                .into_iter()
+                .map(move |(offset, len)| (arr, offset, len))
+        })
+        .collect::<Vec<_>>();
+    if partitions.is_empty() {
+        return vec![vec![]];
+    }
+
+    RAYON.install(|| {
+        partitions
+            .into_par_iter()
+            .map(|(arr, offset, len)| {
+                (offset..offset + len)
+                    .map(|i| {
+                        let payload = arr.get(i);
+                        let hash = payload.map_or(null_h, |s| {
+                            let mut state = hb.build_hasher();
+                            matching.chars(s).for_each(|c| state.write_u32(c as u32));
+                            state.finish()
+                        });
+                        NormalizedStrHash {
+                            payload,
+                            hash,
+                            matching,
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    })
+}
+
+/// Same as `prepare_binary`, but hashes the normalized form of the string keys.
+fn prepare_normalized<'a>(
+    lhs: &'a Series,
+    rhs: &'a Series,
+    build_shortest_table: bool,
+    matching: StringKeyMatching,
+    hash_seed: Option<u64>,
+) -> PolarsResult<(
+    Vec<Vec<NormalizedStrHash<'a>>>,
+    Vec<Vec<NormalizedStrHash<'a>>>,
+    bool,
+)> {
+    let (lhs, rhs) = (str_keys(lhs)?, str_keys(rhs)?);
+    let (a, b, swapped) = if build_shortest_table {
+        det_hash_prone_order!(lhs, rhs)
+    } else {
+        (lhs, rhs, false)
+    };
+    let hb = hash_seed
+        .map(PlSeedableRandomStateQuality::seed_from_u64)
+        .unwrap_or_default();
+    let (a, b) = RAYON.join(
+        || normalized_hashes(a, matching, &hb),
+        || normalized_hashes(b, matching, &hb),
+    );
+    Ok((a, b, swapped))
+}
+
+/// `JoinValidation::validate_probe` for normalized keys, the raw keys may be unique while their
+/// normalized forms are not.
+fn validate_probe(
+    validate: JoinValidation,
+    probe: &[Vec<NormalizedStrHash<'_>>],
+    swapped: bool,
+    nulls_equal: bool,
+) -> PolarsResult<()> {
+    use JoinValidation::*;
+    if matches!(validate.swap(swapped), OneToMany | OneToOne) {
+        let mut seen = PlHashSet::with_capacity(probe.iter().map(Vec::len).sum());
+        let valid = probe
+            .iter()
+            .flatten()
+            .filter(|k| nulls_equal || !k.is_null())
+            .all(|k| seen.insert(*k));
+        polars_ensure!(valid, ComputeError: "join keys did not fulfill {} validation", validate);
+    }
+    Ok(())
+}
+
+pub(crate) fn hash_join_left_normalized(
+    s_left: &Series,
+    s_right: &Series,
+    matching: StringKeyMatching,
+    validate: JoinValidation,
+    nulls_equal: bool,
+) -> PolarsResult<LeftJoinIds> {
+    let (lhs, rhs, _) = prepare_normalized(s_left, s_right, false, matching, None)?;
+    validate_probe(validate, &lhs, false, nulls_equal)?;
+    let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
+    let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
+    hash_join_tuples_left(
+        lhs,
+        rhs,
+        None,
+        None,
+        validate,
+        nulls_equal,
+        s_right.null_count(),
+    )
+}
+
+#[cfg(feature = "semi_anti_join")]
+pub(crate) fn hash_join_semi_anti_normalized(
+    s_left: &Series,
+    s_right: &Series,
+    matching: StringKeyMatching,
+    anti: bool,
+    nulls_equal: bool,
+) -> PolarsResult<Vec<IdxSize>> {
+    let (lhs, rhs, _) = prepare_normalized(s_left, s_right, false, matching, None)?;
+    let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
+    let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
+    Ok(if anti {
+        hash_join_tuples_left_anti(lhs, rhs, nulls_equal)
+    } else {
+        hash_join_tuples_left_semi(lhs, rhs, nulls_equal)
+    })
+}
+
+/// Returns the join tuples and whether or not the lhs tuples are sorted.
+pub(crate) fn hash_join_inner_normalized(
+    s_left: &Series,
+    s_right: &Series,
+    matching: StringKeyMatching,
+    validate: JoinValidation,
+    nulls_equal: bool,
+) -> PolarsResult<(InnerJoinIds, bool)> {
+    let (lhs, rhs, swapped) = prepare_normalized(s_left, s_right, true, matching, None)?;
+    validate_probe(validate, &lhs, swapped, nulls_equal)?;
+    let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
+    let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
+    let build_null_count = if swapped {
+        s_left.null_count()
+    } else {
+        s_right.null_count()
+    };
+    Ok((
+        hash_join_tuples_inner(lhs, rhs, swapped, validate, nulls_equal, build_null_count)?,
+        !swapped,
+    ))
+}
+
+pub(crate) fn hash_join_outer_normalized(
+    s_left: &Series,
+    s_right: &Series,
+    matching: StringKeyMatching,
+    validate: JoinValidation,
+    nulls_equal: bool,
+    hash_seed: Option<u64>,
+) -> PolarsResult<(PrimitiveArray<IdxSize>, PrimitiveArray<IdxSize>)> {
+    let (lhs, rhs, swapped) = prepare_normalized(s_left, s_right, true, matching, hash_seed)?;
+    validate_probe(validate, &lhs, swapped, nulls_equal)?;
+    let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
+    let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
+    hash_join_tuples_outer(lhs, rhs, swapped, validate, nulls_equal, hash_seed)
+}
@@ -1,9 +1,11 @@
+use std::borrow::Cow;
+
 use arrow::array::PrimitiveArray;
 use polars_core::chunked_array::ops::row_encode::encode_rows_unordered;
 use polars_core::series::BitRepr;
 use polars_core::utils::split;
 use polars_core::with_match_physical_float_polars_type;
-use polars_utils::aliases::PlRandomState;
+use polars_utils::aliases::{PlSeedableRandomStateQuality, SeedableFromU64SeedExt};
 use polars_utils::hashing::DirtyHash;
 use polars_utils::nulls::IsNull;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
@@ -11,6 +13,18 @@ use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 use super::*;
 use crate::series::SeriesSealed;
 
+/// Row encode a `List` or `Array` join key. Keys are equal if they have the same elements in the
+/// same order, null elements are equal to each other. A null key is only equal to another null key
+/// if `nulls_equal` is set.
+fn encode_list_key(key: Cow<'_, Series>, nulls_equal: bool) -> PolarsResult<Series> {
+    let key = Column::from(key.into_owned());
+    let mut encoded = encode_rows_unordered(std::slice::from_ref(&key))?;
+    if !nulls_equal {
+        encoded.set_validity(key.rechunk_validity());
+    }
+    Ok(encoded.into_series())
+}
+
 pub trait SeriesJoin: SeriesSealed + Sized {
     #[doc(hidden)]
     fn hash_join_left(
@@ -33,7 +47,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = rhs.cast(&T::Binary).unwrap();
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                let (lhs, rhs, _, _) = prepare_binary::<BinaryType>(lhs, rhs, false);
+                let (lhs, rhs, _) = prepare_binary::<BinaryType>(lhs, rhs, false, None);
                 let lhs = lhs.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
                 let build_null_count = other.null_count();
@@ -50,7 +64,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
             T::BinaryOffset => {
                 let lhs = lhs.binary_offset().unwrap();
                 let rhs = rhs.binary_offset().unwrap();
-                let (lhs, rhs, _, _) = prepare_binary::<BinaryOffsetType>(lhs, rhs, false);
+                let (lhs, rhs, _) = prepare_binary::<BinaryOffsetType>(lhs, rhs, false, None);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -66,14 +80,14 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 )
             },
             T::List(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &encode_list_key(lhs, nulls_equal)?;
+                let rhs = &encode_list_key(rhs, nulls_equal)?;
                 lhs.hash_join_left(rhs, validate, nulls_equal)
             },
             #[cfg(feature = "dtype-array")]
             T::Array(_, _) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &encode_list_key(lhs, nulls_equal)?;
+                let rhs = &encode_list_key(rhs, nulls_equal)?;
                 lhs.hash_join_left(rhs, validate, nulls_equal)
             },
             #[cfg(feature = "dtype-struct")]
@@ -145,7 +159,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = rhs.cast(&T::Binary).unwrap();
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                let (lhs, rhs, _, _) = prepare_binary::<BinaryType>(lhs, rhs, false);
+                let (lhs, rhs, _) = prepare_binary::<BinaryType>(lhs, rhs, false, None);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -158,7 +172,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
             T::BinaryOffset => {
                 let lhs = lhs.binary_offset().unwrap();
                 let rhs = rhs.binary_offset().unwrap();
-                let (lhs, rhs, _, _) = prepare_binary::<BinaryOffsetType>(lhs, rhs, false);
+                let (lhs, rhs, _) = prepare_binary::<BinaryOffsetType>(lhs, rhs, false, None);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -169,14 +183,14 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 }
             },
             T::List(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &encode_list_key(lhs, nulls_equal)?;
+                let rhs = &encode_list_key(rhs, nulls_equal)?;
                 lhs.hash_join_semi_anti(rhs, anti, nulls_equal)?
             },
             #[cfg(feature = "dtype-array")]
             T::Array(_, _) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &encode_list_key(lhs, nulls_equal)?;
+                let rhs = &encode_list_key(rhs, nulls_equal)?;
                 lhs.hash_join_semi_anti(rhs, anti, nulls_equal)?
             },
             #[cfg(feature = "dtype-struct")]
@@ -249,7 +263,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = rhs.cast(&T::Binary).unwrap();
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryType>(lhs, rhs, true);
+                let (lhs, rhs, swapped) = prepare_binary::<BinaryType>(lhs, rhs, true, None);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -273,7 +287,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
             T::BinaryOffset => {
                 let lhs = lhs.binary_offset().unwrap();
                 let rhs = rhs.binary_offset()?;
-                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryOffsetType>(lhs, rhs, true);
+                let (lhs, rhs, swapped) = prepare_binary::<BinaryOffsetType>(lhs, rhs, true, None);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -295,14 +309,14 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 ))
             },
             T::List(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &encode_list_key(lhs, nulls_equal)?;
+                let rhs = &encode_list_key(rhs, nulls_equal)?;
                 lhs.hash_join_inner(rhs, validate, nulls_equal)
             },
             #[cfg(feature = "dtype-array")]
             T::Array(_, _) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &encode_list_key(lhs, nulls_equal)?;
+                let rhs = &encode_list_key(rhs, nulls_equal)?;
                 lhs.hash_join_inner(rhs, validate, nulls_equal)
             },
             #[cfg(feature = "dtype-struct")]
@@ -359,6 +373,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         other: &Series,
         validate: JoinValidation,
         nulls_equal: bool,
+        hash_seed: Option<u64>,
     ) -> PolarsResult<(PrimitiveArray<IdxSize>, PrimitiveArray<IdxSize>)> {
         let s_self = self.as_series();
         let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
@@ -374,43 +389,44 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = rhs.cast(&T::Binary).unwrap();
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryType>(lhs, rhs, true);
+                let (lhs, rhs, swapped) = prepare_binary::<BinaryType>(lhs, rhs, true, hash_seed);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
-                hash_join_tuples_outer(lhs, rhs, swapped, validate, nulls_equal)
+                hash_join_tuples_outer(lhs, rhs, swapped, validate, nulls_equal, hash_seed)
             },
             T::BinaryOffset => {
                 let lhs = lhs.binary_offset().unwrap();
                 let rhs = rhs.binary_offset()?;
-                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryOffsetType>(lhs, rhs, true);
+                let (lhs, rhs, swapped) =
+                    prepare_binary::<BinaryOffsetType>(lhs, rhs, true, hash_seed);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
-                hash_join_tuples_outer(lhs, rhs, swapped, validate, nulls_equal)
+                hash_join_tuples_outer(lhs, rhs, swapped, validate, nulls_equal, hash_seed)
             },
             T::List(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
-                lhs.hash_join_outer(rhs, validate, nulls_equal)
+                let lhs = &encode_list_key(lhs, nulls_equal)?;
+                let rhs = &encode_list_key(rhs, nulls_equal)?;
+                lhs.hash_join_outer(rhs, validate, nulls_equal, hash_seed)
             },
             #[cfg(feature = "dtype-array")]
             T::Array(_, _) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
-                lhs.hash_join_outer(rhs, validate, nulls_equal)
+                let lhs = &encode_list_key(lhs, nulls_equal)?;
+                let rhs = &encode_list_key(rhs, nulls_equal)?;
+                lhs.hash_join_outer(rhs, validate, nulls_equal, hash_seed)
             },
             #[cfg(feature = "dtype-struct")]
             T::Struct(_) => {
                 let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
-                lhs.hash_join_outer(rhs, validate, nulls_equal)
+                lhs.hash_join_outer(rhs, validate, nulls_equal, hash_seed)
             },
             x if x.is_float() => {
                 with_match_physical_float_polars_type!(lhs.dtype(), |$T| {
                     let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
                     let rhs: &ChunkedArray<$T> = rhs.as_ref().as_ref().as_ref();
-                    hash_join_outer(lhs, rhs, validate, nulls_equal)
+                    hash_join_outer(lhs, rhs, validate, nulls_equal, hash_seed)
                 })
             },
             _ => {
@@ -420,19 +436,21 @@ pub trait SeriesJoin: SeriesSealed + Sized {
 
                 use BitRepr as B;
                 match (lhs, rhs) {
-                    (B::U8(lhs), B::U8(rhs)) => hash_join_outer(&lhs, &rhs, validate, nulls_equal),
+                    (B::U8(lhs), B::U8(rhs)) => {
+                        hash_join_outer(&lhs, &rhs, validate, nulls_equal, hash_seed)
+                    },
                     (B::U16(lhs), B::U16(rhs)) => {
-                        hash_join_outer(&lhs, &rhs, validate, nulls_equal)
+                        hash_join_outer(&lhs, &rhs, validate, nulls_equal, hash_seed)
                     },
                     (B::U32(lhs), B::U32(rhs)) => {
-                        hash_join_outer(&lhs, &rhs, validate, nulls_equal)
+                        hash_join_outer(&lhs, &rhs, validate, nulls_equal, hash_seed)
                     },
                     (B::U64(lhs), B::U64(rhs)) => {
-                        hash_join_outer(&lhs, &rhs, validate, nulls_equal)
+                        hash_join_outer(&lhs, &rhs, validate, nulls_equal, hash_seed)
                     },
                     #[cfg(feature = "dtype-u128")]
                     (B::U128(lhs), B::U128(rhs)) => {
-                        hash_join_outer(&lhs, &rhs, validate, nulls_equal)
+                        hash_join_outer(&lhs, &rhs, validate, nulls_equal, hash_seed)
                     },
                     _ => {
                         polars_bail!(
@@ -643,6 +661,7 @@ fn hash_join_outer<T>(
     other: &ChunkedArray<T>,
     validate: JoinValidation,
     nulls_equal: bool,
+    hash_seed: Option<u64>,
 ) -> PolarsResult<(PrimitiveArray<IdxSize>, PrimitiveArray<IdxSize>)>
 where
     T: PolarsNumericType,
@@ -665,7 +684,7 @@ where
                 .iter()
                 .flat_map(|ca| ca.downcast_iter().map(|arr| arr.values().as_slice()))
                 .collect::<Vec<_>>();
-            hash_join_tuples_outer(iters_a, iters_b, swapped, validate, nulls_equal)
+            hash_join_tuples_outer(iters_a, iters_b, swapped, validate, nulls_equal, hash_seed)
         },
         _ => {
             let iters_a = splitted_a
@@ -676,7 +695,7 @@ where
                 .iter()
                 .flat_map(|ca| ca.downcast_iter().map(|arr| arr.iter()))
                 .collect::<Vec<_>>();
-            hash_join_tuples_outer(iters_a, iters_b, swapped, validate, nulls_equal)
+            hash_join_tuples_outer(iters_a, iters_b, swapped, validate, nulls_equal, hash_seed)
         },
     }
 }
@@ -687,12 +706,8 @@ pub(crate) fn prepare_binary<'a, T>(
     // In inner join and outer join, the shortest relation will be used to create a hash table.
     // In left join, always use the right side to create.
     build_shortest_table: bool,
-) -> (
-    Vec<Vec<BytesHash<'a>>>,
-    Vec<Vec<BytesHash<'a>>>,
-    bool,
-    PlRandomState,
-)
+    hash_seed: Option<u64>,
+) -> (Vec<Vec<BytesHash<'a>>>, Vec<Vec<BytesHash<'a>>>, bool)
 where
     T: PolarsDataType,
     for<'b> <T::Array as StaticArray>::ValueT<'b>: AsRef<[u8]>,
@@ -702,11 +717,13 @@ where
     } else {
         (ca, other, false)
     };
-    let hb = PlRandomState::default();
+    let hb = hash_seed
+        .map(PlSeedableRandomStateQuality::seed_from_u64)
+        .unwrap_or_default();
     let bh_a = a.to_bytes_hashes(true, hb.clone());
-    let bh_b = b.to_bytes_hashes(true, hb.clone());
+    let bh_b = b.to_bytes_hashes(true, hb);
 
-    (bh_a, bh_b, swapped, hb)
+    (bh_a, bh_b, swapped)
 }
 
 #[cfg(feature = "semi_anti_join")]
@@ -2,6 +2,7 @@ use std::hash::BuildHasher;
 
 use arrow::array::{MutablePrimitiveArray, PrimitiveArray};
 use arrow::legacy::utils::CustomIterTools;
+use polars_utils::aliases::{PlSeedableRandomStateQuality, SeedableFromU64SeedExt};
 use polars_utils::hashing::hash_to_partition;
 use polars_utils::idx_vec::IdxVec;
 use polars_utils::nulls::IsNull;
@@ -10,17 +11,18 @@ use polars_utils::unitvec;
 
 use super::*;
 
+type OuterHashMap<K, V> = hashbrown::HashMap<K, V, PlSeedableRandomStateQuality>;
+
 pub(crate) fn create_hash_and_keys_threaded_vectorized<I, T>(
     iters: Vec<I>,
-    build_hasher: Option<PlRandomState>,
-) -> (Vec<Vec<(u64, T)>>, PlRandomState)
+    build_hasher: PlSeedableRandomStateQuality,
+) -> (Vec<Vec<(u64, T)>>, PlSeedableRandomStateQuality)
 where
     I: IntoIterator<Item = T> + Send,
     I::IntoIter: TrustedLen,
     T: TotalHash + TotalEq + Send + ToTotalOrd,
     <T as ToTotalOrd>::TotalOrdItem: Hash + Eq,
 {
-    let build_hasher = build_hasher.unwrap_or_default();
     let hashes = RAYON.install(|| {
         iters
             .into_par_iter()
@@ -38,14 +40,19 @@ where
 
 pub(crate) fn prepare_hashed_relation_threaded<T, I>(
     iters: Vec<I>,
-) -> Vec<PlHashMap<<T as ToTotalOrd>::TotalOrdItem, (bool, IdxVec)>>
+    hash_seed: Option<u64>,
+) -> Vec<OuterHashMap<<T as ToTotalOrd>::TotalOrdItem, (bool, IdxVec)>>
 where
     I: Iterator<Item = T> + Send + TrustedLen,
     T: Send + Sync + TotalHash + TotalEq + ToTotalOrd,
     <T as ToTotalOrd>::TotalOrdItem: Send + Sync + Hash + Eq,
 {
     let n_partitions = _set_partition_size();
-    let (hashes_and_keys, build_hasher) = create_hash_and_keys_threaded_vectorized(iters, None);
+    let build_hasher = hash_seed
+        .map(PlSeedableRandomStateQuality::seed_from_u64)
+        .unwrap_or_default();
+    let (hashes_and_keys, build_hasher) =
+        create_hash_and_keys_threaded_vectorized(iters, build_hasher);
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
@@ -55,8 +62,8 @@ where
             .into_par_iter()
             .map(|partition_no| {
                 let hashes_and_keys = &hashes_and_keys;
-                let mut hash_tbl: PlHashMap<T::TotalOrdItem, (bool, IdxVec)> =
-                    PlHashMap::with_hasher(build_hasher.clone());
+                let mut hash_tbl: OuterHashMap<T::TotalOrdItem, (bool, IdxVec)> =
+                    OuterHashMap::with_hasher(build_hasher.clone());
 
                 let mut offset = 0;
                 for hashes_and_keys in hashes_and_keys {
@@ -100,7 +107,7 @@ where
 #[allow(clippy::too_many_arguments)]
 fn probe_outer<T, F, G, H>(
     probe_hashes: &[Vec<(u64, T)>],
-    hash_tbls: &mut [PlHashMap<<T as ToTotalOrd>::TotalOrdItem, (bool, IdxVec)>],
+    hash_tbls: &mut [OuterHashMap<<T as ToTotalOrd>::TotalOrdItem, (bool, IdxVec)>],
     results: &mut (
         MutablePrimitiveArray<IdxSize>,
         MutablePrimitiveArray<IdxSize>,
@@ -184,6 +191,7 @@ pub(super) fn hash_join_tuples_outer<T, I, J>(
     swapped: bool,
     validate: JoinValidation,
     nulls_equal: bool,
+    hash_seed: Option<u64>,
 ) -> PolarsResult<(PrimitiveArray<IdxSize>, PrimitiveArray<IdxSize>)>
 where
     I: IntoIterator<Item = T>,
@@ -219,18 +227,17 @@ where
     // prepare hash table
     let mut hash_tbls = if validate.needs_checks() {
         let expected_size = build.iter().map(|i| i.size_hint().0).sum();
-        let hash_tbls = prepare_hashed_relation_threaded(build);
+        let hash_tbls = prepare_hashed_relation_threaded(build, hash_seed);
         let build_size = hash_tbls.iter().map(|m| m.len()).sum();
         validate.validate_build(build_size, expected_size, swapped)?;
         hash_tbls
     } else {
-        prepare_hashed_relation_threaded(build)
+        prepare_hashed_relation_threaded(build, hash_seed)
     };
     let random_state = hash_tbls[0].hasher();
 
     // we pre hash the probing values
-    let (probe_hashes, _) =
-        create_hash_and_keys_threaded_vectorized(probe, Some(random_state.clone()));
+    let (probe_hashes, _) = create_hash_and_keys_threaded_vectorized(probe, random_state.clone());
 
     let n_tables = hash_tbls.len();
     try_raise_polars_abort();
@@ -1,6 +1,7 @@
 mod args;
 #[cfg(feature = "asof_join")]
 mod asof;
+mod build_table;
 mod cross_join;
 mod dispatch_left_right;
 mod general;
@@ -21,12 +22,13 @@ use arrow::trusted_len::TrustedLen;
 pub use asof::{
     _check_asof_columns, _join_asof_dispatch, AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy,
 };
+pub use build_table::JoinBuildTable;
 pub use cross_join::CrossJoin;
 #[cfg(feature = "chunked_ids")]
 use either::Either;
 #[cfg(feature = "chunked_ids")]
 use general::create_chunked_index_mapping;
-pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
+pub use general::{_coalesce_full_join, _finish_join, _join_key_output_names, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
 #[cfg(feature = "iejoin")]
@@ -51,6 +53,41 @@ use rayon::prelude::*;
 use self::cross_join::fused_cross_filter;
 use super::IntoDf;
 
+/// Indices of the rows to keep so that every combination of `keys` occurs once, in their
+/// original order. Returns `None` if the keys are already unique.
+fn right_unique_idx(keys: &[Series], right_unique: RightUnique) -> PolarsResult<Option<IdxCa>> {
+    let height = keys[0].len();
+    let keys = keys.iter().cloned().map(Column::from).collect();
+    let gb = DataFrame::empty().group_by_with_series(keys, true, true)?;
+    let groups = gb.get_groups();
+    if groups.len() == height {
+        return Ok(None);
+    }
+
+    let idx: Vec<IdxSize> = match right_unique {
+        RightUnique::FirstMatch => groups.iter().map(|g| g.first()).collect(),
+        RightUnique::LastMatch => {
+            let mut idx = groups
+                .iter()
+                .map(|g| match g {
+                    GroupsIndicator::Idx((_first, idx)) => idx[idx.len() - 1],
+                    GroupsIndicator::Slice([first, len]) => first + len - 1,
+                })
+                .collect::<Vec<_>>();
+            idx.sort_unstable();
+            idx
+        },
+        RightUnique::Error => {
+            let g = groups.iter().find(|g| g.len() > 1).unwrap();
+            polars_bail!(
+                ComputeError: "join keys on the right side are not unique: the key at row {} occurs {} times",
+                g.first(), g.len()
+            );
+        },
+    };
+    Ok(Some(IdxCa::from_vec(PlSmallStr::EMPTY, idx)))
+}
+
 pub trait DataFrameJoinOps: IntoDf {
     /// Generic join method. Can be used to join on multiple columns.
     ///
@@ -134,6 +171,59 @@ pub trait DataFrameJoinOps: IntoDf {
     ) -> PolarsResult<DataFrame> {
         let left_df = self.to_df();
 
+        polars_ensure!(
+            args.indicator.is_none() || args.how == JoinType::Full,
+            InvalidOperation: "an indicator column is only supported for full joins, got a {} join",
+            args.how
+        );
+        polars_ensure!(
+            args.right_unique.is_none() || args.how.is_equi(),
+            InvalidOperation: "`right_unique` is only supported for equi joins, got a {} join",
+            args.how
+        );
+
+        if args.drop_keys {
+            args.drop_keys = false;
+            let key_names = |keys: &[Series], df: &DataFrame| {
+                keys.iter()
+                    .map(|s| df.schema().contains(s.name()).then(|| s.name().clone()))
+                    .collect::<Vec<_>>()
+            };
+            let names = _join_key_output_names(
+                &key_names(&selected_left, left_df),
+                &key_names(&selected_right, other),
+                left_df.schema(),
+                &args,
+            );
+            let out = self._join_impl(
+                other,
+                selected_left,
+                selected_right,
+                args,
+                options,
+                _check_rechunk,
+                _verbose,
+            )?;
+            return Ok(out.drop_many(names));
+        }
+
+        let right_keys = selected_right
+            .iter()
+            .map(|s| s.name())
+            .filter(|name| other.schema().contains(name));
+        if let Some(columns) = args.right_select_columns(right_keys, other.schema())? {
+            args.right_select = None;
+            return self._join_impl(
+                &other.select(columns)?,
+                selected_left,
+                selected_right,
+                args,
+                options,
+                _check_rechunk,
+                _verbose,
+            );
+        }
+
         #[cfg(feature = "cross_join")]
         if let JoinType::Cross = args.how {
             if let Some(JoinTypeOptions::Cross(cross_options)) = &options {
@@ -227,6 +317,64 @@ pub trait DataFrameJoinOps: IntoDf {
             );
         };
 
+        // Only the leading key is checked: frames sorted by multiple keys are generally not
+        // sorted by the trailing keys on their own.
+        if let (Some(l), Some(r)) = (selected_left.first(), selected_right.first()) {
+            args.validation.validate_sorted(l, "left")?;
+            args.validation.validate_sorted(r, "right")?;
+        }
+
+        if let Some(matching) = args.string_key_matching() {
+            if let Some(s) = selected_left
+                .iter()
+                .chain(&selected_right)
+                .find(|s| s.dtype() != &DataType::String)
+            {
+                polars_bail!(
+                    InvalidOperation: "case-insensitive or normalized join keys must be of type String, got `{}`: {}",
+                    s.name(), s.dtype()
+                );
+            }
+            // Single keys of hash joins are normalized while they are hashed and compared, other
+            // keys are normalized up front.
+            let normalize_while_hashing =
+                selected_left.len() == 1 && (args.how.is_equi() || args.how.is_semi_anti());
+            if !normalize_while_hashing {
+                for s in selected_left.iter_mut().chain(selected_right.iter_mut()) {
+                    *s = matching.normalize(s.str()?).into_series();
+                }
+                args.string_key_normalization = None;
+                args.case_insensitive_keys = false;
+            }
+        }
+
+        // Deduplicate on the final keys, i.e. after they are cast to a common type and normalized.
+        let deduplicated_other;
+        let other = match args.right_unique {
+            Some(right_unique) if !selected_right.is_empty() => {
+                let normalized_right;
+                let keys = match args.string_key_matching() {
+                    Some(matching) => {
+                        normalized_right =
+                            [matching.normalize(selected_right[0].str()?).into_series()];
+                        normalized_right.as_slice()
+                    },
+                    None => selected_right.as_slice(),
+                };
+                match right_unique_idx(keys, right_unique)? {
+                    Some(idx) => {
+                        for s in selected_right.iter_mut() {
+                            *s = unsafe { s.take_unchecked(&idx) };
+                        }
+                        deduplicated_other = unsafe { other.take_unchecked(&idx) };
+                        &deduplicated_other
+                    },
+                    None => other,
+                }
+            },
+            _ => other,
+        };
+
         #[cfg(feature = "iejoin")]
         if let JoinType::IEJoin = args.how {
             let Some(JoinTypeOptions::IEJoin(options)) = options else {
@@ -280,21 +428,9 @@ pub trait DataFrameJoinOps: IntoDf {
                 ),
                 JoinType::Full => left_df._full_join_from_series(other, s_left, s_right, args),
                 #[cfg(feature = "semi_anti_join")]
-                JoinType::Anti => left_df._semi_anti_join_from_series(
-                    s_left,
-                    s_right,
-                    args.slice,
-                    true,
-                    args.nulls_equal,
-                ),
+                JoinType::Anti => left_df._semi_anti_join_from_series(s_left, s_right, true, args),
                 #[cfg(feature = "semi_anti_join")]
-                JoinType::Semi => left_df._semi_anti_join_from_series(
-                    s_left,
-                    s_right,
-                    args.slice,
-                    false,
-                    args.nulls_equal,
-                ),
+                JoinType::Semi => left_df._semi_anti_join_from_series(s_left, s_right, false, args),
                 #[cfg(feature = "asof_join")]
                 JoinType::AsOf(options) => match (options.left_by, options.right_by) {
                     (Some(left_by), Some(right_by)) => left_df._join_asof_by(
@@ -540,6 +676,47 @@ pub trait DataFrameJoinOps: IntoDf {
             None,
         )
     }
+
+    /// Perform an inner or left join against a prebuilt [`JoinBuildTable`].
+    ///
+    /// The build table is the right side of the join, so it can be reused for many joins
+    /// without rehashing. The key columns of the build side are coalesced into the keys of
+    /// `self`, the remaining output matches [`DataFrameJoinOps::join`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn join_dfs(dimension: &DataFrame, facts: &[DataFrame]) -> PolarsResult<Vec<DataFrame>> {
+    ///     let table = JoinBuildTable::new(dimension, ["id"], false)?;
+    ///     facts
+    ///         .iter()
+    ///         .map(|df| df.join_with_build_table(&table, ["dim_id"], JoinType::Left, None))
+    ///         .collect()
+    /// }
+    /// ```
+    fn join_with_build_table(
+        &self,
+        table: &JoinBuildTable,
+        left_on: impl IntoIterator<Item = impl AsRef<str>>,
+        how: JoinType,
+        suffix: Option<PlSmallStr>,
+    ) -> PolarsResult<DataFrame> {
+        let df_left = self.to_df();
+        let keys = df_left
+            .select_to_vec(left_on)?
+            .into_iter()
+            .map(Column::take_materialized_series)
+            .collect::<Vec<_>>();
+
+        let (left_idx, right_idx) = table.probe(&keys, &how)?;
+        let (df_left, df_right) = RAYON.join(
+            || df_left.take(&left_idx),
+            || table.payload().take(&right_idx),
+        );
+        _finish_join(df_left?, df_right?, suffix)
+    }
 }
 
 trait DataFrameJoinOpsPrivate: IntoDf {
@@ -553,8 +730,18 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         drop_names: Option<Vec<PlSmallStr>>,
     ) -> PolarsResult<DataFrame> {
         let left_df = self.to_df();
-        let ((join_tuples_left, join_tuples_right), sorted) =
-            _sort_or_hash_inner(s_left, s_right, verbose, args.validation, args.nulls_equal)?;
+        let ((join_tuples_left, join_tuples_right), sorted) = match args.string_key_matching() {
+            Some(matching) => hash_join_inner_normalized(
+                s_left,
+                s_right,
+                matching,
+                args.validation,
+                args.nulls_equal,
+            )?,
+            None => {
+                _sort_or_hash_inner(s_left, s_right, verbose, args.validation, args.nulls_equal)?
+            },
+        };
 
         let mut join_tuples_left = &*join_tuples_left;
         let mut join_tuples_right = &*join_tuples_right;
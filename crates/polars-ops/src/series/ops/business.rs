@@ -2,6 +2,7 @@ use arrow::array::PrimitiveArray;
 use arrow::bitmap::Bitmap;
 #[cfg(feature = "dtype-date")]
 use chrono::DateTime;
+use polars_core::prelude::arity::broadcast_binary_elementwise_values;
 use polars_core::prelude::*;
 #[cfg(feature = "dtype-date")]
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
@@ -103,6 +104,51 @@ pub fn business_day_count(
     Ok(out.with_name(output_name).into_series())
 }
 
+pub trait DateBusinessDayCount {
+    /// Count the number of business days between `self` and `end` per row, excluding `end`.
+    /// The count is negative if `end` lies before `self`.
+    ///
+    /// `holidays` are the number of days since the UNIX epoch, in any order. A date of length 1
+    /// is broadcast and a null on either side yields null.
+    fn business_day_count(
+        &self,
+        end: &DateChunked,
+        holidays: &[i32],
+        week_mask: [bool; 7],
+    ) -> PolarsResult<Int32Chunked>;
+}
+
+impl DateBusinessDayCount for DateChunked {
+    fn business_day_count(
+        &self,
+        end: &DateChunked,
+        holidays: &[i32],
+        week_mask: [bool; 7],
+    ) -> PolarsResult<Int32Chunked> {
+        polars_ensure!(
+            week_mask.iter().any(|&x| x),
+            ComputeError: "`week_mask` must have at least one business day"
+        );
+        binary_output_height!(self, end, op = "business_day_count")?;
+
+        let mut holidays = holidays.to_vec();
+        normalize_holidays(&mut holidays, &week_mask);
+        let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
+
+        let out: Int32Chunked =
+            broadcast_binary_elementwise_values(self.physical(), end.physical(), |start, end| {
+                business_day_count_impl(
+                    start,
+                    end,
+                    &week_mask,
+                    n_business_days_in_week_mask,
+                    &holidays,
+                )
+            });
+        Ok(out.with_name(self.name().clone()))
+    }
+}
+
 /// Ported from:
 /// https://github.com/numpy/numpy/blob/e59c074842e3f73483afa5ddef031e856b9fd313/numpy/_core/src/multiarray/datetime_busday.c#L355-L433
 fn business_day_count_impl(
@@ -588,3 +634,63 @@ impl<'a> HolidayListsGetter<'a> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_date_business_day_count() -> PolarsResult<()> {
+        // 2024-01-01 is a Monday.
+        let monday = 19723;
+        let dates = |days: &[Option<i32>]| {
+            Int32Chunked::from_slice_options("date".into(), days).into_date()
+        };
+        let start = dates(&[
+            Some(monday),
+            Some(monday + 4),
+            Some(monday + 5),
+            Some(monday + 7),
+            None,
+            Some(monday),
+        ]);
+        let end = dates(&[
+            Some(monday + 7),
+            Some(monday + 7),
+            Some(monday + 6),
+            Some(monday),
+            Some(monday),
+            None,
+        ]);
+        let week_mask = [true, true, true, true, true, false, false];
+
+        let out = start.business_day_count(&end, &[], week_mask)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(5), Some(1), Some(0), Some(-5), None, None]
+        );
+
+        // Unsorted holidays, the one on a Saturday is not a business day anyway.
+        let holidays = [monday + 5, monday + 1, monday + 1];
+        let out = start.business_day_count(&end, &holidays, week_mask)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(4), Some(1), Some(0), Some(-4), None, None]
+        );
+
+        // A single end date is broadcast.
+        let out = start.business_day_count(&dates(&[Some(monday + 14)]), &[], week_mask)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(10), Some(6), Some(5), Some(5), None, Some(10)]
+        );
+
+        assert!(start.business_day_count(&end, &[], [false; 7]).is_err());
+        assert!(
+            start
+                .business_day_count(&dates(&[Some(monday), Some(monday)]), &[], week_mask)
+                .is_err()
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use std::hash::Hash;
+use std::num::NonZeroUsize;
 use std::sync::Mutex;
 
 use deletion::DeletionFilesList;
@@ -14,7 +15,9 @@ use polars_io::parquet::metadata::FileMetadataRef;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
 use polars_io::{HiveOptions, RowIndex};
+use polars_utils::aliases::PlIndexMapHashable;
 use polars_utils::slice_enum::Slice;
+use predicate_stats::PredicateStatsCollector;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
@@ -23,6 +26,7 @@ use super::*;
 use crate::dsl::default_values::DefaultFieldValues;
 pub mod default_values;
 pub mod deletion;
+pub mod predicate_stats;
 #[cfg(feature = "python")]
 pub mod python_delta_dv_provider;
 #[cfg(feature = "python")]
@@ -324,6 +328,13 @@ impl Default for CastColumnsPolicy {
     }
 }
 
+/// Cast policies for individual columns. Columns that are not listed use the scan-wide
+/// [`CastColumnsPolicy`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct PerColumnCastPolicy(pub PlIndexMapHashable<PlSmallStr, CastColumnsPolicy>);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -394,9 +405,22 @@ pub struct UnifiedScanArgs {
     pub pre_slice: Option<Slice>,
 
     pub cast_columns_policy: CastColumnsPolicy,
+    /// Overrides `cast_columns_policy` for individual columns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub per_column_cast_policy: Option<Arc<PerColumnCastPolicy>>,
     pub missing_columns_policy: MissingColumnsPolicy,
     pub extra_columns_policy: ExtraColumnsPolicy,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Constant columns added to the output of every file, e.g. for lineage.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub virtual_columns: Vec<(PlSmallStr, Scalar)>,
+    /// Opt-in per-file row counts before and after the scan predicate. Not serialized.
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+    pub predicate_stats: Option<Arc<PredicateStatsCollector>>,
+    /// Maximum number of files the streaming engine reads at the same time. Defaults to the
+    /// number of pipelines.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_concurrent_scans: Option<NonZeroUsize>,
 
     pub deletion_files: Option<DeletionFilesList>,
     pub table_statistics: Option<TableStatistics>,
@@ -440,9 +464,13 @@ impl Default for UnifiedScanArgs {
             row_index: None,
             pre_slice: None,
             cast_columns_policy: CastColumnsPolicy::default(),
+            per_column_cast_policy: None,
             missing_columns_policy: MissingColumnsPolicy::default(),
             extra_columns_policy: ExtraColumnsPolicy::default(),
             include_file_paths: None,
+            virtual_columns: Vec::new(),
+            predicate_stats: None,
+            max_concurrent_scans: None,
             deletion_files: None,
             table_statistics: None,
             row_count: None,
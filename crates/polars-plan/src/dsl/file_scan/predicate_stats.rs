@@ -0,0 +1,118 @@
+//! Opt-in accounting of how many rows each file contributed before and after the scan predicate.
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+use polars_utils::relaxed_cell::RelaxedCell;
+
+/// Row counts of a single file before and after the predicate was applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FilePredicateStats {
+    pub scan_source_idx: usize,
+    pub rows_before: u64,
+    pub rows_after: u64,
+}
+
+/// Collects [`FilePredicateStats`] for the files of a multi-file scan.
+///
+/// This is a side-channel: the caller keeps a clone of the `Arc` that is passed in the
+/// [`UnifiedScanArgs`](super::UnifiedScanArgs) and reads the [`report`](Self::report) once the
+/// query has completed.
+#[derive(Debug, Default)]
+pub struct PredicateStatsCollector {
+    files: Mutex<Vec<Arc<FilePredicateCounter>>>,
+}
+
+/// Collectors are compared by address, so that scans reporting to different collectors are never
+/// deduplicated.
+impl PartialEq for PredicateStatsCollector {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for PredicateStatsCollector {}
+
+impl Hash for PredicateStatsCollector {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self as *const Self as usize).hash(state)
+    }
+}
+
+impl PredicateStatsCollector {
+    /// Register a file that was started by the scan, returning the counter its rows are added to.
+    pub fn register_file(&self, scan_source_idx: usize) -> Arc<FilePredicateCounter> {
+        let counter = Arc::new(FilePredicateCounter {
+            scan_source_idx,
+            rows_before: RelaxedCell::new_u64(0),
+            rows_after: RelaxedCell::new_u64(0),
+        });
+        self.files.lock().unwrap().push(counter.clone());
+        counter
+    }
+
+    /// Per-file row counts, ordered by file index.
+    ///
+    /// Files that were never started (e.g. skipped by statistics or past the end of a slice) are
+    /// not included.
+    pub fn report(&self) -> Vec<FilePredicateStats> {
+        let mut out = self
+            .files
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|c| FilePredicateStats {
+                scan_source_idx: c.scan_source_idx,
+                rows_before: c.rows_before.load(),
+                rows_after: c.rows_after.load(),
+            })
+            .collect::<Vec<_>>();
+        out.sort_unstable_by_key(|x| x.scan_source_idx);
+        out
+    }
+}
+
+/// Counter for a single file, updated concurrently by the post-apply pipelines.
+#[derive(Debug)]
+pub struct FilePredicateCounter {
+    scan_source_idx: usize,
+    rows_before: RelaxedCell<u64>,
+    rows_after: RelaxedCell<u64>,
+}
+
+impl FilePredicateCounter {
+    pub fn add(&self, rows_before: usize, rows_after: usize) {
+        self.rows_before.fetch_add(rows_before as u64);
+        self.rows_after.fetch_add(rows_after as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predicate_stats_report() {
+        let collector = PredicateStatsCollector::default();
+        let second = collector.register_file(1);
+        let first = collector.register_file(0);
+        first.add(10, 4);
+        second.add(5, 5);
+        first.add(3, 0);
+
+        assert_eq!(
+            collector.report(),
+            [
+                FilePredicateStats {
+                    scan_source_idx: 0,
+                    rows_before: 13,
+                    rows_after: 4,
+                },
+                FilePredicateStats {
+                    scan_source_idx: 1,
+                    rows_before: 5,
+                    rows_after: 5,
+                },
+            ]
+        );
+    }
+}
@@ -125,6 +125,10 @@ pub struct JoinOptionsIR {
     pub force_parallel: bool,
     pub args: JoinArgs,
     pub options: Option<JoinTypeOptionsIR>,
+    /// Key coalescing was turned off during IR conversion because not all join keys are column
+    /// references. `args.coalesce` holds the final decision.
+    #[cfg_attr(feature = "ir_serde", serde(default))]
+    pub coalesce_overridden: bool,
 }
 
 impl From<JoinOptions> for JoinOptionsIR {
@@ -134,6 +138,7 @@ impl From<JoinOptions> for JoinOptionsIR {
             force_parallel: opts.force_parallel,
             args: opts.args,
             options: Default::default(),
+            coalesce_overridden: false,
         }
     }
 }
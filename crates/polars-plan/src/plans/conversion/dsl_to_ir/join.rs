@@ -24,6 +24,96 @@ fn check_join_keys(keys: &[Expr]) -> PolarsResult<()> {
     Ok(())
 }
 
+/// Whether a join key has to be materialized as a temporary column before the join.
+///
+/// This is the case for scalars, which have to be expanded, and for struct field access,
+/// which lets the backends join on nested fields as if they were regular columns.
+fn should_materialize_key(e: &ExprIR, expr_arena: &Arena<AExpr>) -> bool {
+    if e.is_scalar(expr_arena) {
+        return true;
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    if let AExpr::Function {
+        input,
+        function: IRFunctionExpr::StructExpr(IRStructFunction::FieldByName(_)),
+        ..
+    } = expr_arena.get(e.node())
+    {
+        return input.len() == 1 && expr_arena.get(input[0].node()).is_col();
+    }
+
+    false
+}
+
+/// Lossless numeric supertype of two join keys. `List` and `Array` keys are compared element-wise,
+/// so their inner types are upcast.
+fn join_key_upcast_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
+    match (l, r) {
+        (DataType::List(l), DataType::List(r)) => {
+            join_key_upcast_supertype(l, r).map(|dtype| DataType::List(Box::new(dtype)))
+        },
+        #[cfg(feature = "dtype-array")]
+        (DataType::Array(l, l_width), DataType::Array(r, r_width)) if l_width == r_width => {
+            join_key_upcast_supertype(l, r).map(|dtype| DataType::Array(Box::new(dtype), *l_width))
+        },
+        _ => get_numeric_upcast_supertype_lossless(l, r),
+    }
+}
+
+/// A rough estimate of the number of rows produced by `node`, derived from the row counts of
+/// the sources it reads from. Returns `None` if there is no such estimate.
+fn estimate_row_count(mut node: Node, lp_arena: &Arena<IR>) -> Option<usize> {
+    let mut limit = usize::MAX;
+    loop {
+        match lp_arena.get(node) {
+            IR::DataFrameScan { df, .. } => return Some(df.height().min(limit)),
+            IR::Scan { file_info, .. } => {
+                let estimate = file_info.row_estimation.1;
+                return (estimate != usize::MAX).then(|| estimate.min(limit));
+            },
+            IR::Slice { input, len, .. } => {
+                limit = limit.min(*len as usize);
+                node = *input;
+            },
+            IR::Sort { input, slice, .. } => {
+                if let Some((_, len, _)) = slice {
+                    limit = limit.min(*len);
+                }
+                node = *input;
+            },
+            IR::SimpleProjection { input, .. }
+            | IR::HStack { input, .. }
+            | IR::Cache { input, .. } => node = *input,
+            _ => return None,
+        }
+    }
+}
+
+/// How many times smaller the row count estimate of one side of a join must be than the other
+/// to prefer it as the build side.
+const BUILD_SIDE_ESTIMATE_RATIO: usize = 4;
+
+/// Prefer the side with the clearly lower row count estimate as the build side of a hash join.
+///
+/// This is a hint for the streaming engine, the in-memory engine picks the shorter side from the
+/// actual row counts. If the estimates are close the streaming engine decides at runtime.
+fn preferred_build_side(
+    input_left: Node,
+    input_right: Node,
+    lp_arena: &Arena<IR>,
+) -> Option<JoinBuildSide> {
+    let left = estimate_row_count(input_left, lp_arena)?;
+    let right = estimate_row_count(input_right, lp_arena)?;
+    if left.saturating_mul(BUILD_SIDE_ESTIMATE_RATIO) <= right {
+        Some(JoinBuildSide::PreferLeft)
+    } else if right.saturating_mul(BUILD_SIDE_ESTIMATE_RATIO) <= left {
+        Some(JoinBuildSide::PreferRight)
+    } else {
+        None
+    }
+}
+
 /// Returns: left: join_node, right: last_node (often both the same)
 pub fn resolve_join(
     input_left: Either<Arc<DslPlan>, Node>,
@@ -34,6 +124,18 @@ pub fn resolve_join(
     mut options: JoinOptionsIR,
     ctxt: &mut DslConversionContext,
 ) -> PolarsResult<(Node, Node)> {
+    // Dropping the keys is resolved here with a projection after the join, so the join itself
+    // doesn't have to know about it.
+    let drop_keys = std::mem::take(&mut options.args.drop_keys);
+    polars_ensure!(
+        !drop_keys || (predicates.is_empty() && !options.args.how.is_cross()),
+        InvalidOperation: "dropping the join keys requires an equality join on key columns"
+    );
+    polars_ensure!(
+        options.args.right_select.is_none() || predicates.is_empty(),
+        InvalidOperation: "`right_select` is not supported for joins with predicates"
+    );
+
     if !predicates.is_empty() {
         feature_gated!("iejoin", {
             debug_assert!(left_on.is_empty() && right_on.is_empty());
@@ -76,6 +178,7 @@ pub fn resolve_join(
                     "coalescing join requested but not all join keys are column references, turning off key coalescing"
                 );
             }
+            options.coalesce_overridden = options.args.coalesce.coalesce(&options.args.how);
             options.args.coalesce = JoinCoalesce::KeepColumns;
         }
 
@@ -94,6 +197,14 @@ pub fn resolve_join(
                     polars_bail!(InvalidOperation: "expected both 'by_left' and 'by_right' to be set in 'asof_join'")
                 },
             }
+            if let AsofStrategy::PerRow(direction) = &options.strategy {
+                let dtype = schema_left.try_get(direction)?;
+                polars_ensure!(
+                    dtype == &DataType::Boolean,
+                    SchemaMismatch: "asof join direction column '{}' must be of type Boolean, got {}",
+                    direction, dtype
+                );
+            }
         }
 
         polars_ensure!(
@@ -160,31 +271,51 @@ pub fn resolve_join(
         .optimize_exprs(ctxt.expr_arena, ctxt.lp_arena, input_right, true)
         .map_err(|e| e.context("'join' failed".into()))?;
 
+    // Selecting right columns is resolved here with a projection of the right input, so the join
+    // itself doesn't have to know about it.
+    let right_keys = right_on
+        .iter()
+        .flat_map(|e| aexpr_to_leaf_names_iter(e.node(), ctxt.expr_arena))
+        .cloned()
+        .collect_vec();
+    if let Some(columns) = options
+        .args
+        .right_select_columns(&right_keys, &schema_right)?
+    {
+        options.args.right_select = None;
+        let ir = IRBuilder::new(input_right, ctxt.expr_arena, ctxt.lp_arena)
+            .project_simple(columns)
+            .map(|b| b.build())?;
+        input_right = ctxt.lp_arena.add(ir);
+    }
+
     // Re-evaluate because of mutable borrows earlier.
     let schema_left = ctxt.lp_arena.get(input_left).schema(ctxt.lp_arena);
     let schema_right = ctxt.lp_arena.get(input_right).schema(ctxt.lp_arena);
 
-    // # Resolve scalars
+    // # Resolve scalars and struct fields
     //
     // Scalars need to be expanded. We translate them to temporary columns added with
     // `with_columns` and remove them later with `project`
-    // This way the backends don't have to expand the literals in the join implementation
+    // This way the backends don't have to expand the literals in the join implementation.
+    // Struct field access keys are materialized the same way, so joining on a nested
+    // field doesn't require a `with_columns` from the user.
 
-    let has_scalars = left_on
+    let has_materialized_keys = left_on
         .iter()
         .chain(right_on.iter())
-        .any(|e| e.is_scalar(ctxt.expr_arena));
+        .any(|e| should_materialize_key(e, ctxt.expr_arena));
 
-    let (schema_left, schema_right) = if has_scalars {
+    let (schema_left, schema_right) = if has_materialized_keys {
         let mut as_with_columns_l = vec![];
         let mut as_with_columns_r = vec![];
         for (i, e) in left_on.iter().enumerate() {
-            if e.is_scalar(ctxt.expr_arena) {
+            if should_materialize_key(e, ctxt.expr_arena) {
                 as_with_columns_l.push((i, e.clone()));
             }
         }
         for (i, e) in right_on.iter().enumerate() {
-            if e.is_scalar(ctxt.expr_arena) {
+            if should_materialize_key(e, ctxt.expr_arena) {
                 as_with_columns_r.push((i, e.clone()));
             }
         }
@@ -249,6 +380,25 @@ pub fn resolve_join(
         (schema_left, schema_right)
     };
 
+    // Only keys that are input columns end up in the output, expression keys are evaluated on the
+    // fly. Materialized keys are temporary columns that are removed regardless.
+    let drop_key_names: PlHashSet<PlSmallStr> = if drop_keys {
+        let key_name = |e: &ExprIR| match ctxt.expr_arena.get(e.node()) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        };
+        _join_key_output_names(
+            &left_on.iter().map(key_name).collect_vec(),
+            &right_on.iter().map(key_name).collect_vec(),
+            &schema_left,
+            &options.args,
+        )
+        .into_iter()
+        .collect()
+    } else {
+        Default::default()
+    };
+
     // Not a closure to avoid borrow issues because we mutate expr_arena as well.
     macro_rules! get_dtype {
         ($expr:expr, $schema:expr) => {
@@ -269,7 +419,7 @@ pub fn resolve_join(
         let ltype = get_dtype!(lnode, &schema_left)?;
         let rtype = get_dtype!(rnode, &schema_right)?;
 
-        if let Some(dtype) = get_numeric_upcast_supertype_lossless(&ltype, &rtype) {
+        if let Some(dtype) = join_key_upcast_supertype(&ltype, &rtype) {
             // We use overflowing cast to allow better optimization as we are casting to a known
             // lossless supertype.
             //
@@ -406,6 +556,11 @@ pub fn resolve_join(
         };
     }
 
+    // Never override a build side set by the user.
+    if options.args.build_side.is_none() && options.args.how.is_equi() {
+        options.args.build_side = preferred_build_side(input_left, input_right, ctxt.lp_arena);
+    }
+
     let ir = IR::Join {
         input_left,
         input_right,
@@ -416,11 +571,11 @@ pub fn resolve_join(
     };
     let join_node = ctxt.lp_arena.add(ir);
 
-    if has_scalars {
+    if has_materialized_keys || !drop_key_names.is_empty() {
         let names = join_schema
             .iter_names()
             .filter_map(|n| {
-                if n.starts_with(POLARS_TMP_PREFIX) {
+                if n.starts_with(POLARS_TMP_PREFIX) || drop_key_names.contains(n) {
                     None
                 } else {
                     Some(n.clone())
@@ -475,13 +630,33 @@ fn resolve_join_where(
         .schema(ctxt.lp_arena)
         .into_owned();
 
-    options.args.how = JoinType::Cross;
+    let schema_right = ctxt
+        .lp_arena
+        .get(input_right)
+        .schema(ctxt.lp_arena)
+        .into_owned();
+
+    // Equalities between a left and a right column become the keys of a hash join, the
+    // remaining predicates filter its output instead of the full cross product.
+    let (left_on, right_on, predicates) = split_equi_join_keys(
+        predicates,
+        &schema_left,
+        &schema_right,
+        options.args.suffix(),
+    );
+    if left_on.is_empty() {
+        options.args.how = JoinType::Cross;
+    } else {
+        options.args.how = JoinType::Inner;
+        options.args.coalesce = JoinCoalesce::KeepColumns;
+        options.args.nulls_equal = false;
+    }
 
     let (mut last_node, join_node) = resolve_join(
         Either::Right(input_left),
         Either::Right(input_right),
-        vec![],
-        vec![],
+        left_on,
+        right_on,
         vec![],
         options,
         ctxt,
@@ -537,6 +712,73 @@ fn resolve_join_where(
     Ok((last_node, join_node))
 }
 
+/// Split the `join_where` predicates into equalities between a left and a right column of the same
+/// dtype, returned as `(left_on, right_on)` join keys, and the remaining predicates.
+///
+/// Right columns are referred to by their name in the joined schema, so they may carry `suffix`.
+#[cfg(feature = "iejoin")]
+fn split_equi_join_keys(
+    predicates: Vec<Expr>,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    suffix: &str,
+) -> (Vec<Expr>, Vec<Expr>, Vec<Expr>) {
+    let right_name = |name: &PlSmallStr| -> Option<PlSmallStr> {
+        if schema_left.contains(name) {
+            None
+        } else if schema_right.contains(name) {
+            Some(name.clone())
+        } else {
+            name.strip_suffix(suffix)
+                .filter(|base| schema_left.contains(base) && schema_right.contains(base))
+                .map(PlSmallStr::from)
+        }
+    };
+    // Float equality differs between filters and hash joins for e.g. -0.0 and 0.0.
+    let is_hashable = |dtype: &DataType| {
+        !(dtype.is_float() || dtype.is_nested() || dtype.is_object() || dtype.is_null())
+    };
+
+    let mut keys = PlIndexSet::new();
+    let mut residual = Vec::with_capacity(predicates.len());
+    for e in predicates {
+        let key = match &e {
+            Expr::BinaryExpr {
+                left,
+                op: Operator::Eq,
+                right,
+            } => match (left.as_ref(), right.as_ref()) {
+                (Expr::Column(a), Expr::Column(b)) => {
+                    let key = |l: &PlSmallStr, r: &PlSmallStr| {
+                        right_name(r)
+                            .filter(|_| schema_left.contains(l))
+                            .map(|r| (l.clone(), r))
+                    };
+                    key(a, b).or_else(|| key(b, a))
+                },
+                _ => None,
+            },
+            _ => None,
+        };
+        match key {
+            Some((l, r))
+                if schema_left.get(&l) == schema_right.get(&r)
+                    && schema_left.get(&l).is_some_and(is_hashable)
+                    && !keys.contains(&(l.clone(), r.clone())) =>
+            {
+                keys.insert((l, r));
+            },
+            _ => residual.push(e),
+        }
+    }
+
+    let (left_on, right_on) = keys
+        .into_iter()
+        .map(|(l, r)| (Expr::Column(l), Expr::Column(r)))
+        .unzip();
+    (left_on, right_on, residual)
+}
+
 /// Locate nodes that are operands in a binary comparison involving both tables, and ensure that
 /// these nodes are losslessly upcast to a safe dtype.
 fn ensure_lossless_binary_comparisons(
@@ -575,16 +817,9 @@ fn build_upcast_node_list(
     to_replace: &mut Vec<(Node, DataType)>,
 ) -> PolarsResult<ExprOrigin> {
     let expr_origin = match expr_arena.get(*node) {
-        AExpr::Column(name) => {
-            if schema_left.contains(name) {
-                ExprOrigin::Left
-            } else if schema_merged.contains(name) {
-                ExprOrigin::Right
-            } else {
-                return Err(schema_merged.column_not_found_err(name));
-            }
+        AExpr::Column(_) | AExpr::Literal(..) => {
+            classify_expr_origin(*node, schema_left, schema_merged, expr_arena)?
         },
-        AExpr::Literal(..) => ExprOrigin::None,
         AExpr::Cast { expr: node, .. } => {
             build_upcast_node_list(node, schema_left, schema_merged, expr_arena, to_replace)?
         },
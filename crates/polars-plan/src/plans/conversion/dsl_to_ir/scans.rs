@@ -152,6 +152,21 @@ pub(super) async fn dsl_to_ir(
             schema.insert_at_index(schema.len(), file_path_col.clone(), DataType::String)?;
         }
 
+        if !unified_scan_args.virtual_columns.is_empty() {
+            let schema: &mut Schema = Arc::make_mut(&mut file_info.schema);
+
+            for (name, value) in unified_scan_args.virtual_columns.iter() {
+                if schema.contains(name) {
+                    polars_bail!(
+                        Duplicate: r#"virtual column "{}" conflicts with another column in the scan"#,
+                        name
+                    );
+                }
+
+                schema.insert_at_index(schema.len(), name.clone(), value.dtype().clone())?;
+            }
+        }
+
         unified_scan_args.projection = if let Some(file_schema) = file_info.reader_schema.as_ref() {
             maybe_init_projection_excluding_hive(
                 file_schema,
@@ -745,7 +760,7 @@ pub async fn csv_file_info(
                     csv_options.has_header,
                     csv_options.skip_lines,
                     csv_options.skip_rows,
-                    csv_options.skip_rows_after_header,
+                    csv_options.skip_rows_after_header + usize::from(csv_options.types_row),
                     csv_options.raise_if_empty,
                 )?;
 
@@ -780,7 +795,7 @@ pub async fn csv_file_info(
         let compression = reader.compression();
 
         let mut first_row_len = 0;
-        let (schema, _) = read_until_start_and_infer_schema(
+        let (inferred, _) = read_until_start_and_infer_schema(
             csv_options,
             None,
             decompressed_slice_size_hint,
@@ -800,7 +815,7 @@ pub async fn csv_file_info(
         let estimated_rows =
             (decompressed_file_size_hint as f64 / first_row_len as f64).round() as usize;
 
-        Ok((schema, estimated_rows))
+        Ok((inferred.schema, estimated_rows))
     };
 
     let merge_func =
@@ -68,7 +68,7 @@ pub fn coerce_temporal_dt(
         expr.set_node(expr_arena.add(AExpr::Cast {
             expr: expr.node(),
             dtype: to_type.clone(),
-            options: CastOptions::Strict,
+            options: super::coercion_cast_options(from_type, to_type),
         }));
         expr.set_dtype(to_type.clone());
     }
@@ -212,7 +212,7 @@ impl OptimizationRule for TypeCoercionRule {
                     expr_arena.add(AExpr::Cast {
                         expr: truthy_node,
                         dtype: st.clone(),
-                        options: CastOptions::Strict,
+                        options: coercion_cast_options(&type_true, &st),
                     })
                 } else {
                     truthy_node
@@ -221,8 +221,8 @@ impl OptimizationRule for TypeCoercionRule {
                 let new_node_falsy = if type_false != st {
                     expr_arena.add(AExpr::Cast {
                         expr: falsy_node,
+                        options: coercion_cast_options(&type_false, &st),
                         dtype: st,
-                        options: CastOptions::Strict,
                     })
                 } else {
                     falsy_node
@@ -1544,6 +1544,18 @@ fn try_inline_literal_cast(
     Ok(Some(lv))
 }
 
+/// Options for casting `from` to the supertype `to`.
+///
+/// The supertype of datetimes has the coarsest time unit. Coercing to it truncates, which a strict
+/// cast would reject.
+pub(super) fn coercion_cast_options(from: &DataType, to: &DataType) -> CastOptions {
+    if from.is_datetime() && to.is_datetime() {
+        CastOptions::NonStrict
+    } else {
+        CastOptions::Strict
+    }
+}
+
 fn cast_expr_ir(
     e: &mut ExprIR,
     from_dtype: &DataType,
@@ -1568,7 +1580,7 @@ fn cast_expr_ir(
     e.set_node(expr_arena.add(AExpr::Cast {
         expr: e.node(),
         dtype: to_dtype.clone(),
-        options: CastOptions::Strict,
+        options: coercion_cast_options(from_dtype, to_dtype),
     }));
     e.set_dtype(to_dtype.clone());
 
@@ -60,7 +60,8 @@ fn count_all_rows_csv(
                 options.has_header,
                 options.skip_lines,
                 options.skip_rows,
-                options.skip_rows_after_header,
+                // The types row is skipped like a row after the header.
+                options.skip_rows_after_header + usize::from(options.types_row),
                 options.raise_if_empty,
             ),
             _ => {
@@ -74,7 +75,7 @@ fn count_all_rows_csv(
                     options.has_header,
                     options.skip_lines,
                     options.skip_rows,
-                    options.skip_rows_after_header,
+                    options.skip_rows_after_header + usize::from(options.types_row),
                     options.raise_if_empty,
                 )
             },
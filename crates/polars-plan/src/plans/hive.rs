@@ -2,7 +2,9 @@ use std::borrow::Cow;
 use std::path::{Component, Path};
 
 use polars_core::prelude::*;
-use polars_io::prelude::schema_inference::{finish_infer_field_schema, infer_field_schema};
+use polars_io::prelude::schema_inference::{
+    FieldInferenceOptions, finish_infer_field_schema, infer_field_schema,
+};
 use polars_utils::pl_path::PlRefPath;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -148,7 +150,11 @@ pub fn hive_partitions_from_paths(
                         continue;
                     }
 
-                    entry.insert(infer_field_schema(value.as_ref(), try_parse_dates, false));
+                    let options = FieldInferenceOptions {
+                        try_parse_dates,
+                        ..Default::default()
+                    };
+                    entry.insert(infer_field_schema(value.as_ref(), options));
                 }
             }
 
@@ -167,6 +173,9 @@ pub fn hive_partitions_from_paths(
         None,
         polars_io::prelude::CsvEncoding::Utf8,
         false,
+        &[],
+        |_| false,
+        false,
     )?;
 
     for path in paths {
@@ -237,6 +237,13 @@ impl<'a> IRDisplay<'a> {
                 } else {
                     let how = &options.args.how;
                     write!(f, "{:indent$}{how} JOIN:", "")?;
+                    if options.coalesce_overridden {
+                        write!(
+                            f,
+                            "\n{:indent$}KEY COALESCING DISABLED: non-column join keys",
+                            ""
+                        )?;
+                    }
                     write!(f, "\n{:indent$}LEFT PLAN ON: {left_on}", "")?;
                     self.with_root(*input_left)
                         ._format(f, sub_indent, seen_caches)?;
@@ -1035,6 +1042,13 @@ pub fn write_ir_non_recursive(
             } else {
                 let how = &options.args.how;
                 write!(f, "{:indent$}{how} JOIN", "")?;
+                if options.coalesce_overridden {
+                    write!(
+                        f,
+                        "\n{:indent$}KEY COALESCING DISABLED: non-column join keys",
+                        ""
+                    )?;
+                }
                 write!(f, "\n{:indent$}LEFT PLAN ON: {left_on}", "")?;
                 write!(f, "\n{:indent$}RIGHT PLAN ON: {right_on}", "")?;
             }
@@ -330,9 +330,13 @@ fn expand_python_dataset(
                 row_index: _row_index @ None,
                 pre_slice: _pre_slice @ None,
                 cast_columns_policy,
+                per_column_cast_policy,
                 missing_columns_policy,
                 extra_columns_policy,
                 include_file_paths: _include_file_paths @ None,
+                virtual_columns: _,
+                predicate_stats: _,
+                max_concurrent_scans: _,
                 deletion_files,
                 table_statistics,
                 row_count,
@@ -348,6 +352,7 @@ fn expand_python_dataset(
             unified_scan_args.rechunk = *rechunk;
             unified_scan_args.cache = *cache;
             unified_scan_args.cast_columns_policy = cast_columns_policy.clone();
+            unified_scan_args.per_column_cast_policy = per_column_cast_policy.clone();
             unified_scan_args.missing_columns_policy = *missing_columns_policy;
             unified_scan_args.extra_columns_policy = *extra_columns_policy;
             unified_scan_args.column_mapping = column_mapping.clone();
@@ -9,9 +9,9 @@ use crate::plans::visitor::{AexprNode, RewriteRecursion, RewritingVisitor, TreeW
 use crate::plans::{ExprIR, OutputName};
 
 /// Join origin of an expression
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
 #[repr(u8)]
-pub(crate) enum ExprOrigin {
+pub enum ExprOrigin {
     // Note: BitOr is implemented on this struct that relies on this exact u8
     // repr layout (i.e. treated as a bitfield).
     //
@@ -22,7 +22,6 @@ pub(crate) enum ExprOrigin {
     /// Utilizes columns from the right side of the join
     Right = 0b01,
     /// Utilizes columns from both sides of the join
-    #[expect(unused)]
     Both = 0b11,
 }
 
@@ -102,6 +101,29 @@ impl ExprOrigin {
     }
 }
 
+/// Classify which side of a join the columns referenced by the expression at `node` come from.
+///
+/// Columns in `schema_left` are attributed to the left side, other columns in the joined
+/// `schema_merged` to the right side. Expressions without column references have origin
+/// [`ExprOrigin::None`]. Errors with ColumnNotFound if a column is in neither schema.
+pub fn classify_expr_origin(
+    node: Node,
+    schema_left: &Schema,
+    schema_merged: &Schema,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<ExprOrigin> {
+    aexpr_to_leaf_names_iter(node, expr_arena).try_fold(ExprOrigin::None, |acc, name| {
+        let origin = if schema_left.contains(name) {
+            ExprOrigin::Left
+        } else if schema_merged.contains(name) {
+            ExprOrigin::Right
+        } else {
+            return Err(schema_merged.column_not_found_err(name));
+        };
+        Ok(acc | origin)
+    })
+}
+
 impl std::ops::BitOr for ExprOrigin {
     type Output = ExprOrigin;
 
@@ -181,3 +203,42 @@ pub(super) fn remove_suffix<'a>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use polars_core::prelude::DataType;
+
+    use super::*;
+    use crate::dsl::Expr;
+    use crate::dsl::functions::{col, lit};
+    use crate::plans::{ExprToIRContext, to_expr_ir};
+
+    #[test]
+    fn test_classify_expr_origin() -> PolarsResult<()> {
+        let schema_left = Schema::from_iter([("a".into(), DataType::Int32)]);
+        let schema_merged = Schema::from_iter([
+            ("a".into(), DataType::Int32),
+            ("b".into(), DataType::Int64),
+            ("a_right".into(), DataType::Int32),
+        ]);
+        let classify = |expr: Expr| {
+            let mut arena = Arena::new();
+            let mut ctx = ExprToIRContext::new(&mut arena, &schema_merged);
+            let node = to_expr_ir(expr, &mut ctx)?.node();
+            classify_expr_origin(node, &schema_left, &schema_merged, &arena)
+        };
+
+        assert_eq!(classify(col("a"))?, ExprOrigin::Left);
+        assert_eq!(classify(col("b"))?, ExprOrigin::Right);
+        assert_eq!(classify(col("a_right"))?, ExprOrigin::Right);
+        assert_eq!(classify(lit(1))?, ExprOrigin::None);
+        assert_eq!(classify(col("a").gt(lit(1)))?, ExprOrigin::Left);
+        assert_eq!(classify(col("b") * lit(2))?, ExprOrigin::Right);
+        assert_eq!(classify(col("a").lt(col("b")))?, ExprOrigin::Both);
+        assert_eq!(
+            classify((col("a") + lit(1)).lt_eq(col("a_right").cast(DataType::Int64)))?,
+            ExprOrigin::Both
+        );
+        Ok(())
+    }
+}
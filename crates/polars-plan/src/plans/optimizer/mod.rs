@@ -18,7 +18,7 @@ mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
 mod join_utils;
-pub(crate) use join_utils::ExprOrigin;
+pub use join_utils::{ExprOrigin, classify_expr_origin};
 mod expand_datasets;
 #[cfg(feature = "python")]
 pub use expand_datasets::{ExpandedPythonScan, PyScanResolveThreadPool};
@@ -23,7 +23,13 @@ pub(super) fn process_join(
     mut acc_predicates: PlIndexMap<PlSmallStr, ExprIR>,
     streaming: bool,
 ) -> PolarsResult<IR> {
-    if options.args.slice.is_some() {
+    // The indicator column depends on the join type and on unmatched rows of both inputs, so the
+    // join must not be rewritten or filtered before it. Filtering the right side before it is
+    // deduplicated would change which of its rows is kept.
+    if options.args.slice.is_some()
+        || options.args.indicator.is_some()
+        || options.args.right_unique.is_some()
+    {
         let ir = rewrite_hive(
             IR::Join {
                 input_left,
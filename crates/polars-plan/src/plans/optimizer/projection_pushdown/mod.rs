@@ -8,6 +8,8 @@ use polars_core::frame::DataFrame;
 use polars_core::prelude::{Column, DataType, ScratchIndexMap, ScratchIndexSet};
 use polars_core::schema::Schema;
 use polars_io::RowIndex;
+#[cfg(feature = "asof_join")]
+use polars_ops::frame::AsofStrategy;
 use polars_ops::frame::{JoinCoalesce, JoinType};
 #[allow(clippy::disallowed_types)]
 use polars_utils::aliases::PlHashMap;
@@ -940,6 +942,8 @@ impl ProjectionPushdownVisitor<'_, '_> {
                 for output_name in output_schema_arc
                     .iter_names()
                     .filter(|name| is_projected_in_output(name))
+                    // The indicator column is created by the join itself.
+                    .filter(|name| options.args.indicator.as_ref() != Some(*name))
                     .chain(pred_used_names_iter.into_iter().flatten())
                 {
                     match ExprOrigin::get_column_origin(
@@ -998,6 +1002,10 @@ impl ProjectionPushdownVisitor<'_, '_> {
                             project_right.insert(name.clone());
                         }
                     }
+
+                    if let AsofStrategy::PerRow(direction) = &asof_options.strategy {
+                        project_left.insert(direction.clone());
+                    }
                 }
 
                 // Turn on coalesce if non-coalesced keys are not included in projection. Reduces materialization.
@@ -2103,6 +2111,13 @@ fn set_scan_projection(scan_ir: &mut IR, projection_schema: Arc<Schema>) {
         Arc::make_mut(&mut file_info.schema).shift_remove(&name);
     }
 
+    for (name, _) in unified_scan_args
+        .virtual_columns
+        .extract_if(.., |(name, _)| !projection_schema.contains(name))
+    {
+        Arc::make_mut(&mut file_info.schema).shift_remove(&name);
+    }
+
     unified_scan_args.projection = Some(
         projection_schema
             .iter_names()
@@ -2119,6 +2134,14 @@ fn set_scan_projection(scan_ir: &mut IR, projection_schema: Arc<Schema>) {
                     return false;
                 }
 
+                if unified_scan_args
+                    .virtual_columns
+                    .iter()
+                    .any(|(virtual_name, _)| virtual_name == name)
+                {
+                    return false;
+                }
+
                 true
             })
             .cloned()
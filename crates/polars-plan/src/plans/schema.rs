@@ -245,6 +245,15 @@ pub(crate) fn det_join_schema(
                 })?;
             }
 
+            if let Some(indicator) = &options.args.indicator {
+                polars_ensure!(
+                    matches!(how, JoinType::Full),
+                    InvalidOperation: "an indicator column is only supported for full joins, got a {} join",
+                    how
+                );
+                new_schema.try_insert(indicator.clone(), join_indicator_dtype())?;
+            }
+
             Ok(Arc::new(new_schema))
         },
     }
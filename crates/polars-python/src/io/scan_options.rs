@@ -130,9 +130,13 @@ impl PyScanOptions<'_> {
             row_index,
             pre_slice: pre_slice.map(Slice::from),
             cast_columns_policy: cast_options.0,
+            per_column_cast_policy: None,
             missing_columns_policy: missing_columns.0,
             extra_columns_policy: extra_columns.0,
             include_file_paths: include_file_paths.map(|x| x.0),
+            virtual_columns: Vec::new(),
+            predicate_stats: None,
+            max_concurrent_scans: None,
             deletion_files,
             table_statistics: table_statistics.map(|x| x.0),
             row_count,
@@ -577,6 +577,11 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<Py<PyAny>> {
                                     AsofStrategy::Backward => "backward",
                                     AsofStrategy::Forward => "forward",
                                     AsofStrategy::Nearest => "nearest",
+                                    AsofStrategy::PerRow(_) => {
+                                        return Err(PyNotImplementedError::new_err(
+                                            "per-row asof strategy",
+                                        ));
+                                    },
                                 };
                                 let left_by = asof_options.left_by.as_ref().map(|cols| {
                                     cols.iter().map(|c| c.as_str()).collect::<Vec<_>>()
@@ -1605,6 +1605,13 @@ impl SQLContext {
                                 coalesce: Default::default(),
                                 maintain_order: MaintainOrderJoin::Left,
                                 build_side: None,
+                                string_key_normalization: None,
+                                case_insensitive_keys: false,
+                                indicator: None,
+                                right_unique: None,
+                                full_join_hash_seed: None,
+                                drop_keys: false,
+                                right_select: None,
                             },
                         );
                 }
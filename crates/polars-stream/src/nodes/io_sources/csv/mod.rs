@@ -305,7 +305,7 @@ impl FileReader for CsvFileReader {
                 )
                 .map(|(inferred_schema, base_leftover)| {
                     (
-                        inferred_schema,
+                        inferred_schema.schema,
                         base_leftover,
                         reader,
                         options,
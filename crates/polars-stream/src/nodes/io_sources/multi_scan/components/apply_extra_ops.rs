@@ -8,7 +8,8 @@ use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
-use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy, ScanSource};
+use polars_plan::dsl::predicate_stats::FilePredicateCounter;
+use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy, PerColumnCastPolicy, ScanSource};
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::row_counter::RowCounter;
 use polars_utils::slice_enum::Slice;
@@ -31,6 +32,7 @@ pub enum ApplyExtraOps {
         final_output_schema: SchemaRef,
         projection: Projection,
         cast_columns_policy: CastColumnsPolicy,
+        per_column_cast_policy: Option<Arc<PerColumnCastPolicy>>,
         missing_columns_policy: MissingColumnsPolicy,
         extra_ops: ExtraOperations,
         /// This here so that we can get the include file path name if needed.
@@ -39,6 +41,8 @@ pub enum ApplyExtraOps {
         hive_parts: Option<Arc<HivePartitionsDf>>,
         /// E.g. Iceberg deletion files.
         external_filter_mask: Option<ExternalFilterMask>,
+        /// Set if predicate stats were requested.
+        predicate_counter: Option<Arc<FilePredicateCounter>>,
     },
 
     /// Note: These fields are ordered according to the order in which they are applied.
@@ -48,9 +52,10 @@ pub enum ApplyExtraOps {
         external_filter_mask: Option<ExternalFilterMask>,
         /// `(_, insertion_position)`
         row_index: Option<(RowIndex, usize)>,
-        /// This will have include_file_paths, hive columns, missing columns.
+        /// This will have include_file_paths, virtual columns, hive columns, missing columns.
         column_selectors: Option<Vec<ColumnSelector>>,
         predicate: Option<ScanIOPredicate>,
+        predicate_counter: Option<Arc<FilePredicateCounter>>,
     },
 
     /// No-op.
@@ -80,6 +85,7 @@ impl ApplyExtraOps {
                 final_output_schema,
                 projection,
                 cast_columns_policy,
+                per_column_cast_policy,
                 missing_columns_policy,
                 extra_ops:
                     ExtraOperations {
@@ -88,12 +94,14 @@ impl ApplyExtraOps {
                         pre_slice,
                         include_file_paths,
                         file_path_col_idx,
+                        virtual_columns,
                         predicate,
                     },
                 scan_source,
                 scan_source_idx,
                 hive_parts,
                 external_filter_mask,
+                predicate_counter,
             } => {
                 // Negative slice should have been resolved earlier.
                 if let Some(Slice::Negative { .. }) = pre_slice {
@@ -104,6 +112,7 @@ impl ApplyExtraOps {
                 let selector_builder = ColumnSelectorBuilder {
                     cast_columns_policy,
                     missing_columns_policy,
+                    per_column_cast_policy,
                 };
                 // Tracks if the input already has all columns in the right order and type.
                 let mut is_input_passthrough = incoming_schema.len() == final_output_schema.len();
@@ -124,6 +133,11 @@ impl ApplyExtraOps {
                                 ),
                             ),
                         )))
+                    } else if let Some((name, value)) =
+                        virtual_columns.iter().find(|(name, _)| name == output_name)
+                    {
+                        debug_assert_eq!(value.dtype(), output_dtype);
+                        ColumnSelector::Constant(Box::new((name.clone(), value.clone())))
                     } else if output_index == row_index_col_idx {
                         if let Some(ri) = &row_index {
                             // Row index is done by us (ApplyExtraOps). Insert a placeholder column.
@@ -207,6 +221,7 @@ impl ApplyExtraOps {
                     external_filter_mask,
                     row_index: row_index.map(|ri| (ri, row_index_col_idx)),
                     column_selectors,
+                    predicate_counter: predicate_counter.filter(|_| predicate.is_some()),
                     predicate,
                 };
 
@@ -219,6 +234,7 @@ impl ApplyExtraOps {
                         row_index: None,
                         column_selectors: None,
                         predicate: None,
+                        predicate_counter: _,
                     } => Self::Noop,
 
                     Initialized { .. } => out,
@@ -245,6 +261,7 @@ impl ApplyExtraOps {
             row_index,
             column_selectors,
             predicate,
+            predicate_counter,
         } = ({
             use ApplyExtraOps::*;
 
@@ -332,8 +349,13 @@ impl ApplyExtraOps {
         }
 
         if let Some(predicate) = predicate {
+            let rows_before = df.height();
             let mask = predicate.predicate.evaluate_io(df)?;
             *df = df.filter_seq(mask.bool().expect("predicate not boolean"))?;
+
+            if let Some(predicate_counter) = predicate_counter {
+                predicate_counter.add(rows_before, df.height());
+            }
         }
 
         Ok(())
@@ -1,3 +1,6 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::{Column, DataType, InitHashMaps, IntoColumn as _, PlHashMap};
 use polars_core::scalar::Scalar;
@@ -6,7 +9,9 @@ use polars_core::schema::iceberg::{IcebergColumn, IcebergColumnType, LIST_ELEMEN
 use polars_core::series::{IntoSeries, Series};
 use polars_core::utils::get_numeric_upcast_supertype_lossless;
 use polars_error::{PolarsResult, feature_gated, polars_bail};
-use polars_plan::dsl::{CastColumnsPolicy, ExtraColumnsPolicy, MissingColumnsPolicy};
+use polars_plan::dsl::{
+    CastColumnsPolicy, ExtraColumnsPolicy, MissingColumnsPolicy, PerColumnCastPolicy,
+};
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::nodes::io_sources::multi_scan::components::column_selector::{
@@ -19,10 +24,30 @@ use crate::nodes::io_sources::multi_scan::components::errors::missing_column_err
 pub struct ColumnSelectorBuilder {
     pub cast_columns_policy: CastColumnsPolicy,
     pub missing_columns_policy: MissingColumnsPolicy,
+    /// Overrides `cast_columns_policy` for individual output columns.
+    pub per_column_cast_policy: Option<Arc<PerColumnCastPolicy>>,
     // This doesn't take an `ExtraColumnsPolicy`, as it only gets called with the projected output columns.
 }
 
 impl ColumnSelectorBuilder {
+    /// Returns a builder that uses the cast policy configured for the output column `column_name`.
+    ///
+    /// Must be called with top-level column names, nested fields use the policy of their column.
+    pub fn for_column(&self, column_name: &str) -> Cow<'_, Self> {
+        match self
+            .per_column_cast_policy
+            .as_deref()
+            .and_then(|policies| policies.0.get(column_name))
+        {
+            Some(cast_columns_policy) => Cow::Owned(Self {
+                cast_columns_policy: cast_columns_policy.clone(),
+                missing_columns_policy: self.missing_columns_policy,
+                per_column_cast_policy: None,
+            }),
+            None => Cow::Borrowed(self),
+        }
+    }
+
     /// Build the selector for an output column.
     pub fn build_column_selector(
         &self,
@@ -30,11 +55,15 @@ impl ColumnSelectorBuilder {
         target_name: &PlSmallStr,
         target_dtype: &DataType,
     ) -> PolarsResult<ColumnSelector> {
+        // Resolve the policy before checking the incoming schema, so that the decision between
+        // casting and treating the column as missing is made under the same policy in every file.
+        let builder = self.for_column(target_name);
+
         let out = if let Some((index, _, incoming_dtype)) = incoming_schema.get_full(target_name) {
             let input = ColumnSelector::Position(index);
-            self.attach_transforms(input, incoming_dtype, target_dtype, target_name)?
+            builder.attach_transforms(input, incoming_dtype, target_dtype, target_name)?
         } else {
-            match &self.missing_columns_policy {
+            match &builder.missing_columns_policy {
                 MissingColumnsPolicy::Insert => ColumnSelector::Constant(Box::new((
                     target_name.clone(),
                     Scalar::null(target_dtype.clone()),
@@ -680,3 +709,67 @@ pub fn build_iceberg_default_value_impl(
             .transpose()?),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use polars_core::prelude::{DataType, PlIndexMap, PlIndexMapHashable};
+    use polars_core::schema::Schema;
+    use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy, PerColumnCastPolicy};
+    use polars_utils::pl_str::PlSmallStr;
+
+    use super::ColumnSelectorBuilder;
+    use crate::nodes::io_sources::multi_scan::components::column_selector::ColumnSelector;
+
+    #[test]
+    fn test_per_column_cast_policy() {
+        let allow_float = CastColumnsPolicy {
+            integer_to_float_cast: true,
+            ..CastColumnsPolicy::ERROR_ON_MISMATCH
+        };
+
+        let builder = ColumnSelectorBuilder {
+            cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+            missing_columns_policy: MissingColumnsPolicy::Insert,
+            per_column_cast_policy: Some(Arc::new(PerColumnCastPolicy(PlIndexMapHashable(
+                PlIndexMap::from_iter([(PlSmallStr::from_static("a"), allow_float)]),
+            )))),
+        };
+
+        let incoming_schema = Schema::from_iter([
+            (PlSmallStr::from_static("a"), DataType::Int64),
+            (PlSmallStr::from_static("b"), DataType::Int64),
+        ]);
+
+        let a = builder
+            .build_column_selector(
+                &incoming_schema,
+                &PlSmallStr::from_static("a"),
+                &DataType::Float64,
+            )
+            .unwrap();
+        assert!(matches!(a, ColumnSelector::Transformed(_)));
+
+        assert!(
+            builder
+                .build_column_selector(
+                    &incoming_schema,
+                    &PlSmallStr::from_static("b"),
+                    &DataType::Float64,
+                )
+                .is_err()
+        );
+
+        // Listed columns missing from the file follow the missing columns policy.
+        let missing_schema = Schema::from_iter([(PlSmallStr::from_static("b"), DataType::Int64)]);
+        let a = builder
+            .build_column_selector(
+                &missing_schema,
+                &PlSmallStr::from_static("a"),
+                &DataType::Float64,
+            )
+            .unwrap();
+        assert!(matches!(a, ColumnSelector::Constant(_)));
+    }
+}
@@ -7,7 +7,9 @@ use polars_core::schema::iceberg::{IcebergSchema, IcebergSchemaRef};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_error::{PolarsResult, polars_err};
 use polars_plan::dsl::default_values::IcebergDefaultFieldValues;
-use polars_plan::dsl::{CastColumnsPolicy, ColumnMapping, MissingColumnsPolicy};
+use polars_plan::dsl::{
+    CastColumnsPolicy, ColumnMapping, MissingColumnsPolicy, PerColumnCastPolicy,
+};
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::nodes::io_sources::multi_scan::components::column_selector::ColumnSelector;
@@ -35,7 +37,7 @@ pub enum ProjectionBuilder {
 
 impl ProjectionBuilder {
     /// Returns the full projected schema, keyed by the output name.
-    fn projected_schema(&self) -> &SchemaRef {
+    pub fn projected_schema(&self) -> &SchemaRef {
         match self {
             ProjectionBuilder::Plain(schema) => schema,
             ProjectionBuilder::Iceberg {
@@ -104,12 +106,14 @@ impl ProjectionBuilder {
         file_schema: Option<&Schema>,
         file_iceberg_schema: Option<&IcebergSchema>,
         cast_columns_policy: CastColumnsPolicy,
+        per_column_cast_policy: Option<Arc<PerColumnCastPolicy>>,
         scan_source_idx: usize,
     ) -> PolarsResult<Projection> {
         let selector_builder = ColumnSelectorBuilder {
             cast_columns_policy,
             // This should not be used by `attach_transforms()`.
             missing_columns_policy: MissingColumnsPolicy::Raise,
+            per_column_cast_policy,
         };
 
         Ok(match self {
@@ -134,12 +138,14 @@ impl ProjectionBuilder {
                         continue;
                     };
 
-                    match selector_builder.attach_transforms(
-                        ColumnSelector::Position(0),
-                        incoming_dtype,
-                        projected_dtype,
-                        projected_name,
-                    )? {
+                    match selector_builder
+                        .for_column(projected_name)
+                        .attach_transforms(
+                            ColumnSelector::Position(0),
+                            incoming_dtype,
+                            projected_dtype,
+                            projected_name,
+                        )? {
                         ColumnSelector::Position(0) => {},
                         selector => {
                             mapping
@@ -215,12 +221,14 @@ impl ProjectionBuilder {
                         continue;
                     };
 
-                    match selector_builder.attach_iceberg_transforms(
-                        ColumnSelector::Position(0),
-                        incoming_iceberg_column,
-                        output_iceberg_column,
-                        iceberg_default_value_provider,
-                    )? {
+                    match selector_builder
+                        .for_column(&output_iceberg_column.name)
+                        .attach_iceberg_transforms(
+                            ColumnSelector::Position(0),
+                            incoming_iceberg_column,
+                            output_iceberg_column,
+                            iceberg_default_value_provider,
+                        )? {
                         ColumnSelector::Position(0) => {
                             assert_eq!(incoming_iceberg_column.name, output_iceberg_column.name);
                         },
@@ -12,6 +12,8 @@ pub struct ReaderOperationPushdown<'a> {
     pub file_projection: Projection,
     pub reader_capabilities: ReaderCapabilities,
     pub external_filter_mask: Option<ExternalFilterMask>,
+    /// Keep the predicate in post-apply so that the rows it removes can be counted.
+    pub collect_predicate_stats: bool,
     /// Operations will be `take()`en out when pushed.
     pub extra_ops_post: &'a mut ExtraOperations,
 }
@@ -31,6 +33,7 @@ impl ReaderOperationPushdown<'_> {
             file_projection,
             reader_capabilities,
             external_filter_mask,
+            collect_predicate_stats,
             extra_ops_post,
         } = self;
 
@@ -85,6 +88,7 @@ impl ReaderOperationPushdown<'_> {
         let push_predicate = !(!reader_capabilities.contains(RC::MAPPED_COLUMN_PROJECTION)
             || unsupported_external_filter_mask
             || extra_ops_post.predicate.is_none()
+            || collect_predicate_stats
             || (extra_ops_post.row_index.is_some() || extra_ops_post.pre_slice.is_some())
             || !reader_capabilities.contains(RC::PARTIAL_FILTER));
 
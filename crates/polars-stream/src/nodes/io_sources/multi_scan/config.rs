@@ -1,12 +1,16 @@
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
+use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
 use polars_io::predicates::ScanIOPredicate;
 use polars_plan::dsl::deletion::DeletionFilesList;
+use polars_plan::dsl::predicate_stats::PredicateStatsCollector;
 use polars_plan::dsl::{
-    CastColumnsPolicy, MissingColumnsPolicy, PredicateFileSkip, ScanSources, TableStatistics,
+    CastColumnsPolicy, MissingColumnsPolicy, PerColumnCastPolicy, PredicateFileSkip, ScanSources,
+    TableStatistics,
 };
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::pl_str::PlSmallStr;
@@ -35,11 +39,23 @@ pub struct MultiScanConfig {
     pub pre_slice: Option<Slice>,
     pub predicate: Option<ScanIOPredicate>,
     pub predicate_file_skip_applied: Option<PredicateFileSkip>,
+    /// Stop opening new files once this many rows have been sent out, i.e. counted after
+    /// `predicate`. This is only a hint; the output is not truncated to it.
+    pub output_row_limit: Option<usize>,
+    /// Opt-in per-file row counts before / after `predicate`. Setting this prevents the predicate
+    /// from being pushed into the readers.
+    pub predicate_stats: Option<Arc<PredicateStatsCollector>>,
+    /// Set by the scan arguments, takes precedence over the computed `max_concurrent_scans`.
+    pub requested_max_concurrent_scans: Option<NonZeroUsize>,
 
     pub hive_parts: Option<Arc<HivePartitionsDf>>,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Constant columns added to the output of every file, e.g. for lineage.
+    pub virtual_columns: Vec<(PlSmallStr, Scalar)>,
     pub missing_columns_policy: MissingColumnsPolicy,
     pub cast_columns_policy: CastColumnsPolicy,
+    /// Overrides `cast_columns_policy` for individual columns.
+    pub per_column_cast_policy: Option<Arc<PerColumnCastPolicy>>,
     pub forbid_extra_columns: Option<ForbidExtraColumns>,
     pub deletion_files: Option<DeletionFilesList>,
     pub table_statistics: Option<TableStatistics>,
@@ -40,7 +40,15 @@ pub fn calc_n_readers_pre_init(
         .clamp(1, 128)
 }
 
-pub fn calc_max_concurrent_scans(num_pipelines: usize, num_sources: usize) -> usize {
+pub fn calc_max_concurrent_scans(
+    num_pipelines: usize,
+    num_sources: usize,
+    requested: Option<NonZeroUsize>,
+) -> usize {
+    if let Some(v) = requested {
+        return v.get();
+    }
+
     if let Ok(v) = std::env::var("POLARS_MAX_CONCURRENT_SCANS").map(|x| {
         x.parse::<NonZeroUsize>()
             .unwrap_or_else(|_| panic!("invalid value for POLARS_MAX_CONCURRENT_SCANS: {x}"))
@@ -215,6 +215,7 @@ impl MultiScanState {
         config.max_concurrent_scans.store(calc_max_concurrent_scans(
             num_pipelines,
             config.sources.len(),
+            config.requested_max_concurrent_scans,
         ));
 
         let InitializedPipelineState {
@@ -4,12 +4,13 @@ use std::sync::{Arc, Mutex};
 use futures::StreamExt;
 use polars_async::executor::{self, AbortOnDropHandle, TaskPriority};
 use polars_async::primitives::connector::{self};
-use polars_core::prelude::PlHashMap;
+use polars_core::prelude::{PlHashMap, PlHashSet};
 use polars_core::runtime::ASYNC;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_ensure};
 use polars_io::metrics::IOMetrics;
 use polars_mem_engine::scan_predicate::initialize_scan_predicate;
 use polars_plan::dsl::PredicateFileSkip;
+use polars_utils::relaxed_cell::RelaxedCell;
 use polars_utils::row_counter::RowCounter;
 use polars_utils::slice_enum::Slice;
 
@@ -59,12 +60,16 @@ pub fn initialize_multi_scan_pipeline(
 
     let bridge_state = Arc::new(Mutex::new(BridgeState::NotYetStarted));
 
-    let (bridge_handle, bridge_recv_port_tx, phase_channel_tx) = spawn_bridge(bridge_state.clone());
+    let rows_sent = Arc::new(RelaxedCell::new_u64(0));
+
+    let (bridge_handle, bridge_recv_port_tx, phase_channel_tx) =
+        spawn_bridge(bridge_state.clone(), rows_sent.clone());
 
     let task_handle = AbortOnDropHandle::new(executor::spawn(TaskPriority::Low, async move {
         finish_initialize_multi_scan_pipeline(
             config,
             bridge_recv_port_tx,
+            rows_sent,
             execution_state,
             io_metrics,
         )
@@ -83,6 +88,7 @@ pub fn initialize_multi_scan_pipeline(
 async fn finish_initialize_multi_scan_pipeline(
     config: Arc<MultiScanConfig>,
     bridge_recv_port_tx: connector::Sender<BridgeRecvPort>,
+    rows_sent: Arc<RelaxedCell<u64>>,
     execution_state: StreamingExecutionState,
     io_metrics: Option<Arc<IOMetrics>>,
 ) -> PolarsResult<()> {
@@ -237,9 +243,12 @@ async fn finish_initialize_multi_scan_pipeline(
         Arc::new(row_deletions);
 
     let cast_columns_policy = config.cast_columns_policy.clone();
+    let per_column_cast_policy = config.per_column_cast_policy.clone();
     let missing_columns_policy = config.missing_columns_policy;
     let include_file_paths = config.include_file_paths.clone();
 
+    validate_virtual_columns(&config)?;
+
     let extra_ops = ExtraOperations {
         row_index,
         row_index_col_idx: config.row_index.as_ref().map_or(usize::MAX, |x| {
@@ -250,6 +259,7 @@ async fn finish_initialize_multi_scan_pipeline(
         file_path_col_idx: config.include_file_paths.as_ref().map_or(usize::MAX, |x| {
             config.final_output_schema.index_of(x).unwrap()
         }),
+        virtual_columns: config.virtual_columns.clone(),
         predicate,
     };
 
@@ -435,14 +445,18 @@ async fn finish_initialize_multi_scan_pipeline(
             max_concurrent_scans,
             skip_files_mask,
             extra_ops,
+            output_row_limit: config.output_row_limit,
+            rows_sent,
             constant_args: StartReaderArgsConstant {
                 hive_parts,
                 final_output_schema,
                 reader_capabilities,
                 file_projection_builder,
                 cast_columns_policy,
+                per_column_cast_policy,
                 missing_columns_policy,
                 forbid_extra_columns: config.forbid_extra_columns.clone(),
+                predicate_stats: config.predicate_stats.clone(),
                 num_pipelines,
                 disable_morsel_split,
                 last_morsel_pipelines,
@@ -468,3 +482,30 @@ async fn finish_initialize_multi_scan_pipeline(
 
     Ok(())
 }
+
+/// Virtual columns may not collide with each other, or with any other column in the output.
+fn validate_virtual_columns(config: &MultiScanConfig) -> PolarsResult<()> {
+    let mut seen = PlHashSet::with_capacity(config.virtual_columns.len());
+
+    for (name, _) in &config.virtual_columns {
+        let collides = !seen.insert(name)
+            || config
+                .file_projection_builder
+                .projected_schema()
+                .contains(name)
+            || config.row_index.as_ref().is_some_and(|ri| &ri.name == name)
+            || config.include_file_paths.as_ref() == Some(name)
+            || config
+                .hive_parts
+                .as_ref()
+                .is_some_and(|hp| hp.schema().contains(name));
+
+        polars_ensure!(
+            !collides,
+            Duplicate: "virtual column '{}' collides with another column in the scan output",
+            name
+        );
+    }
+
+    Ok(())
+}
@@ -7,11 +7,13 @@ use polars_async::executor::AbortOnDropHandle;
 use polars_async::primitives::connector;
 use polars_async::primitives::wait_group::WaitToken;
 use polars_core::prelude::PlHashMap;
+use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
-use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy, ScanSource};
+use polars_plan::dsl::predicate_stats::PredicateStatsCollector;
+use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy, PerColumnCastPolicy, ScanSource};
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::row_counter::RowCounter;
@@ -45,6 +47,8 @@ pub struct ExtraOperations {
     pub include_file_paths: Option<PlSmallStr>,
     /// Index of the file path column in the final output.
     pub file_path_col_idx: usize,
+    /// `(name, value)` of constant columns added to the output.
+    pub virtual_columns: Vec<(PlSmallStr, Scalar)>,
     pub predicate: Option<ScanIOPredicate>,
 }
 
@@ -80,8 +84,10 @@ pub(super) struct StartReaderArgsConstant {
     pub(super) reader_capabilities: ReaderCapabilities,
     pub(super) file_projection_builder: ProjectionBuilder,
     pub(super) cast_columns_policy: CastColumnsPolicy,
+    pub(super) per_column_cast_policy: Option<Arc<PerColumnCastPolicy>>,
     pub(super) missing_columns_policy: MissingColumnsPolicy,
     pub(super) forbid_extra_columns: Option<ForbidExtraColumns>,
+    pub(super) predicate_stats: Option<Arc<PredicateStatsCollector>>,
     pub(super) num_pipelines: usize,
     pub(super) disable_morsel_split: bool,
     /// Precomputed last-morsel split factor; see `BeginReadArgs::last_morsel_pipelines`.
@@ -4,6 +4,7 @@ use polars_async::executor;
 use polars_async::executor::{JoinHandle, TaskPriority};
 use polars_async::primitives::connector;
 use polars_async::primitives::wait_group::WaitToken;
+use polars_utils::relaxed_cell::RelaxedCell;
 
 use crate::morsel::{MorselSeq, SourceToken};
 use crate::nodes::io_sources::multi_scan::components::bridge::{
@@ -13,6 +14,7 @@ use crate::pipe::PortSender;
 
 pub fn spawn_bridge(
     bridge_state: Arc<Mutex<BridgeState>>,
+    rows_sent: Arc<RelaxedCell<u64>>,
 ) -> (
     JoinHandle<()>,
     // For attaching file reader output port
@@ -29,6 +31,7 @@ pub fn spawn_bridge(
             incoming,
             outgoing,
             bridge_state,
+            rows_sent,
             source_token: SourceToken::new(),
         }
         .run(),
@@ -43,6 +46,8 @@ struct Bridge {
     incoming: connector::Receiver<BridgeRecvPort>,
     outgoing: connector::Receiver<(PortSender, WaitToken)>,
     bridge_state: Arc<Mutex<BridgeState>>,
+    /// Number of rows sent to the compute node output.
+    rows_sent: Arc<RelaxedCell<u64>>,
     source_token: SourceToken,
 }
 
@@ -104,6 +109,7 @@ impl Bridge {
             morsel.set_seq(MorselSeq::new(morsel_seq));
 
             morsel_seq = morsel_seq.saturating_add(1);
+            let height = morsel.height();
 
             while let Err(v) = tx.send(morsel).await {
                 drop(tx);
@@ -117,6 +123,8 @@ impl Bridge {
                 morsel = v;
             }
 
+            self.rows_sent.fetch_add(height as u64);
+
             if self.source_token.stop_requested() {
                 drop(tx);
                 drop(current_phase_wait_token);
@@ -15,6 +15,7 @@ use polars_error::{PolarsResult, polars_ensure};
 use polars_mem_engine::scan_predicate::skip_files_mask::SkipFilesMask;
 use polars_plan::dsl::{MissingColumnsPolicy, ScanSource};
 use polars_utils::IdxSize;
+use polars_utils::relaxed_cell::RelaxedCell;
 use polars_utils::row_counter::RowCounter;
 use polars_utils::slice_enum::Slice;
 
@@ -45,6 +46,9 @@ pub struct ReaderStarter {
     pub max_concurrent_scans: usize,
     pub skip_files_mask: Option<SkipFilesMask>,
     pub extra_ops: ExtraOperations,
+    /// Stop starting readers once `rows_sent` reaches this.
+    pub output_row_limit: Option<usize>,
+    pub rows_sent: Arc<RelaxedCell<u64>>,
     pub constant_args: StartReaderArgsConstant,
     pub verbose: bool,
 }
@@ -67,6 +71,8 @@ impl ReaderStarter {
             max_concurrent_scans,
             skip_files_mask,
             extra_ops,
+            output_row_limit,
+            rows_sent,
             constant_args,
             verbose,
         } = self;
@@ -111,6 +117,20 @@ impl ReaderStarter {
                 break;
             }
 
+            // Rows are counted after all extra operations (i.e. after the predicate) have been
+            // applied, so this also holds for selective filters.
+            if let Some(limit) = output_row_limit
+                && rows_sent.load() >= limit as u64
+            {
+                if verbose {
+                    eprintln!(
+                        "[ReaderStarter]: Stopping (output_row_limit: {limit}, rows_sent: {})",
+                        rows_sent.load()
+                    )
+                }
+                break;
+            }
+
             let Some(InitializedReaderState {
                 scan_source_idx,
                 scan_source,
@@ -415,8 +435,10 @@ async fn start_reader_impl(
         reader_capabilities,
         file_projection_builder,
         cast_columns_policy,
+        per_column_cast_policy,
         missing_columns_policy,
         forbid_extra_columns,
+        predicate_stats,
         num_pipelines,
         disable_morsel_split,
         last_morsel_pipelines,
@@ -448,6 +470,7 @@ async fn start_reader_impl(
         None,
         file_iceberg_schema.as_ref(),
         cast_columns_policy.clone(),
+        per_column_cast_policy.clone(),
         scan_source_idx,
     )?;
 
@@ -464,6 +487,7 @@ async fn start_reader_impl(
         file_projection: file_projection.clone(),
         reader_capabilities,
         external_filter_mask: external_filter_mask.clone(),
+        collect_predicate_stats: predicate_stats.is_some(),
         extra_ops_post: &mut extra_ops_post,
     }
     .push_operations();
@@ -541,6 +565,7 @@ async fn start_reader_impl(
                     Some(reader_file_schema.as_ref()),
                     None,
                     cast_columns_policy.clone(),
+                    per_column_cast_policy.clone(),
                     scan_source_idx,
                 )?
             },
@@ -550,6 +575,7 @@ async fn start_reader_impl(
         let mut external_predicate_cols = Vec::with_capacity(
             hive_parts.as_ref().map_or(0, |x| x.df().width())
                 + extra_ops_post.include_file_paths.is_some() as usize
+                + extra_ops_post.virtual_columns.len()
                 + projection_to_reader.num_missing_columns().unwrap(),
         );
 
@@ -586,6 +612,14 @@ async fn start_reader_impl(
             ))
         }
 
+        external_predicate_cols.extend(
+            extra_ops_post
+                .virtual_columns
+                .iter()
+                .filter(|(name, _)| predicate.live_columns.contains(name))
+                .cloned(),
+        );
+
         for (missing_col_name, dtype, default_value) in
             file_projection.iter_missing_columns(Some(&reader_file_schema))?
         {
@@ -640,6 +674,12 @@ async fn start_reader_impl(
 
     let first_morsel = reader_output_port.recv().await.ok();
 
+    // Registered even if the file is empty, so that it shows up in the report.
+    let predicate_counter = predicate_stats
+        .as_ref()
+        .filter(|_| extra_ops_post.predicate.is_some())
+        .map(|stats| stats.register_file(scan_source_idx));
+
     let ops_applier = if let Some(first_morsel) = &first_morsel {
         let final_output_schema = final_output_schema.clone();
         let extra_ops = extra_ops_post;
@@ -656,12 +696,14 @@ async fn start_reader_impl(
             final_output_schema,
             projection: projection_to_post,
             cast_columns_policy,
+            per_column_cast_policy,
             missing_columns_policy,
             extra_ops,
             scan_source: scan_source.clone(),
             scan_source_idx,
             hive_parts,
             external_filter_mask,
+            predicate_counter,
         }
         .initialize(first_morsel.df().await.schema())?
     } else {
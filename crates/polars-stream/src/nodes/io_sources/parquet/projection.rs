@@ -21,7 +21,13 @@ pub fn resolve_arrow_field_projections(
 ) -> PolarsResult<Arc<[ArrowFieldProjection]>> {
     let projection: Projection = match projection {
         Projection::Plain(projected_schema) => ProjectionBuilder::new(projected_schema, None, None)
-            .build_projection(Some(file_schema), None, cast_columns_policy, usize::MAX)?,
+            .build_projection(
+                Some(file_schema),
+                None,
+                cast_columns_policy,
+                None,
+                usize::MAX,
+            )?,
         Projection::Mapped { .. } => projection,
     };
 
@@ -489,7 +489,7 @@ fn need_more_right_side(
     let left_key = left.column(params.left.key_col())?.as_materialized_series();
     // SAFETY: We checked earlier that the dataframes are not empty
     let left_last_val = unsafe { left_key.get_unchecked(left_key.len() - 1) };
-    let right_range_end = match (options.strategy, options.allow_eq) {
+    let right_range_end = match (&options.strategy, options.allow_eq) {
         (Forward, true) | (Backward, false) => {
             right.binary_search(|x| *x >= left_last_val, params.right.key_col(), start..end)
         },
@@ -515,6 +515,7 @@ fn need_more_right_side(
                 first_greater..end,
             )
         },
+        (PerRow(_), _) => unreachable!("per-row asof joins are not streamed"),
     };
     Ok(right_range_end >= right.height())
 }
@@ -731,7 +732,7 @@ fn join_asof_ungrouped(
     let take_idx = _join_asof_dispatch(
         left_key,
         right_key,
-        options.strategy,
+        options.strategy.clone(),
         options.tolerance.clone().map(Scalar::into_value),
         options.allow_eq,
     )?;
@@ -818,7 +819,7 @@ fn compute_asof_join(
         let take_idx = _join_asof_dispatch(
             group_left_key.as_materialized_series(),
             group_right_key.as_materialized_series(),
-            options.strategy,
+            options.strategy.clone(),
             options.tolerance.clone().map(Scalar::into_value),
             options.allow_eq,
         )?;
@@ -518,9 +518,14 @@ fn visualize_plan_rec(
             pre_slice,
             predicate,
             predicate_file_skip_applied: _,
+            predicate_stats: _,
+            output_row_limit: _,
+            max_concurrent_scans: _,
             hive_parts,
             include_file_paths,
+            virtual_columns: _,
             cast_columns_policy: _,
+            per_column_cast_policy: _,
             missing_columns_policy: _,
             forbid_extra_columns: _,
             deletion_files,
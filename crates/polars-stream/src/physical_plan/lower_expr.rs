@@ -1135,6 +1135,13 @@ fn lower_exprs_with_ctx(
                         coalesce: Default::default(),
                         maintain_order: Default::default(),
                         build_side: None,
+                        string_key_normalization: None,
+                        case_insensitive_keys: false,
+                        indicator: None,
+                        right_unique: None,
+                        full_join_hash_seed: None,
+                        drop_keys: false,
+                        right_select: None,
                     },
                     output_bool: true,
                 };
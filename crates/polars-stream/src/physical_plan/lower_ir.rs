@@ -232,7 +232,21 @@ pub fn lower_ir(
         IR::Slice { input, offset, len } => {
             let offset = *offset;
             let len = *len as usize;
+            let input_is_scan = matches!(ir_arena.get(*input), IR::Scan { .. });
             let phys_input = lower_ir!(*input)?;
+
+            // A slice directly on top of a scan only needs the first `offset + len` output rows,
+            // so the scan can stop opening files once it has produced those. The scan is not
+            // shared through a cache here, so the hint cannot affect other consumers.
+            if input_is_scan
+                && offset >= 0
+                && let PhysNodeKind::MultiScan {
+                    output_row_limit, ..
+                } = &mut phys_sm[phys_input.node].kind
+            {
+                *output_row_limit = Some((offset as usize).saturating_add(len));
+            }
+
             return Ok(build_slice_stream(phys_input, offset, len, phys_sm));
         },
 
@@ -848,6 +862,7 @@ pub fn lower_ir(
                                 .include_file_paths
                                 .as_ref()
                                 .map(|x| x.as_str()),
+                            &unified_scan_args.virtual_columns,
                         );
 
                     let file_projection_builder = ProjectionBuilder::new(
@@ -898,11 +913,16 @@ pub fn lower_ir(
                         pre_slice,
                         predicate,
                         predicate_file_skip_applied,
+                        predicate_stats: unified_scan_args.predicate_stats,
+                        output_row_limit: None,
+                        max_concurrent_scans: unified_scan_args.max_concurrent_scans,
                         hive_parts,
                         cast_columns_policy: unified_scan_args.cast_columns_policy,
+                        per_column_cast_policy: unified_scan_args.per_column_cast_policy,
                         missing_columns_policy: unified_scan_args.missing_columns_policy,
                         forbid_extra_columns,
                         include_file_paths: unified_scan_args.include_file_paths,
+                        virtual_columns: unified_scan_args.virtual_columns,
                         deletion_files,
                         table_statistics: unified_scan_args.table_statistics,
                         file_schema,
@@ -1164,7 +1184,12 @@ pub fn lower_ir(
             let (mut by_descending, mut by_nulls_last) = (Default::default(), Default::default());
             #[cfg(feature = "asof_join")]
             let use_streaming_asof_join = 'use_asof_join: {
-                if !args.how.is_asof() {
+                if !args.how.is_asof()
+                    || matches!(
+                        asof_options().strategy,
+                        polars_ops::frame::AsofStrategy::PerRow(_)
+                    )
+                {
                     break 'use_asof_join false;
                 }
                 let (Some(left_by), Some(right_by)) =
@@ -1203,6 +1228,12 @@ pub fn lower_ir(
                 || use_streaming_asof_join
                 || args.how.is_range())
                 && !args.validation.needs_checks()
+                && !args.validation.requires_sorted_keys()
+                && args.string_key_normalization.is_none()
+                && !args.case_insensitive_keys
+                && args.indicator.is_none()
+                && args.right_unique.is_none()
+                && args.full_join_hash_seed.is_none()
             {
                 // When lowering the expressions for the keys we need to ensure we keep around the
                 // payload columns, otherwise the input nodes can get replaced by input-independent
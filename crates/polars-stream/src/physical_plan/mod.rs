@@ -9,6 +9,7 @@ use polars_core::frame::DataFrame;
 ))]
 use polars_core::prelude::DataType;
 use polars_core::prelude::{IdxSize, InitHashMaps, PlHashMap, PlIndexMap, SortMultipleOptions};
+use polars_core::scalar::Scalar;
 use polars_core::schema::{Schema, SchemaRef};
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
@@ -21,9 +22,10 @@ use polars_ops::frame::JoinArgs;
 ))]
 use polars_plan::dsl::StrptimeOptions;
 use polars_plan::dsl::deletion::DeletionFilesList;
+use polars_plan::dsl::predicate_stats::PredicateStatsCollector;
 use polars_plan::dsl::{
     CastColumnsPolicy, ColumnsUdf, FileSinkOptions, JoinTypeOptionsIR, MissingColumnsPolicy,
-    PartitionedSinkOptionsIR, PredicateFileSkip, ScanSources, TableStatistics,
+    PartitionedSinkOptionsIR, PerColumnCastPolicy, PredicateFileSkip, ScanSources, TableStatistics,
 };
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::hive::HivePartitionsDf;
@@ -388,10 +390,17 @@ pub enum PhysNodeKind {
         pre_slice: Option<Slice>,
         predicate: Option<ExprIR>,
         predicate_file_skip_applied: Option<PredicateFileSkip>,
+        predicate_stats: Option<Arc<PredicateStatsCollector>>,
+        /// Number of output rows after which no further files need to be opened. Set when the
+        /// scan is directly followed by a positive slice, the output itself is not truncated.
+        output_row_limit: Option<usize>,
+        max_concurrent_scans: Option<NonZeroUsize>,
 
         hive_parts: Option<HivePartitionsDf>,
         include_file_paths: Option<PlSmallStr>,
+        virtual_columns: Vec<(PlSmallStr, Scalar)>,
         cast_columns_policy: CastColumnsPolicy,
+        per_column_cast_policy: Option<Arc<PerColumnCastPolicy>>,
         missing_columns_policy: MissingColumnsPolicy,
         forbid_extra_columns: Option<ForbidExtraColumns>,
 
@@ -836,10 +836,15 @@ fn to_graph_rec<'a>(
             pre_slice,
             predicate,
             predicate_file_skip_applied,
+            predicate_stats,
+            output_row_limit,
+            max_concurrent_scans,
             hive_parts,
             missing_columns_policy,
             cast_columns_policy,
+            per_column_cast_policy,
             include_file_paths,
+            virtual_columns,
             forbid_extra_columns,
             deletion_files,
             table_statistics,
@@ -866,6 +871,9 @@ fn to_graph_rec<'a>(
                 .transpose()?
                 .map(|p| p.to_io(None, file_schema.clone()));
             let predicate_file_skip_applied = *predicate_file_skip_applied;
+            let predicate_stats = predicate_stats.clone();
+            let output_row_limit = *output_row_limit;
+            let requested_max_concurrent_scans = *max_concurrent_scans;
 
             let sources = scan_sources.clone();
             let file_reader_builder = file_reader_builder.clone();
@@ -878,9 +886,11 @@ fn to_graph_rec<'a>(
             let pre_slice = pre_slice.clone();
             let hive_parts = hive_parts.map(Arc::new);
             let include_file_paths = include_file_paths.clone();
+            let virtual_columns = virtual_columns.clone();
             let missing_columns_policy = *missing_columns_policy;
             let forbid_extra_columns = forbid_extra_columns.clone();
             let cast_columns_policy = cast_columns_policy.clone();
+            let per_column_cast_policy = per_column_cast_policy.clone();
             let deletion_files = deletion_files.clone();
             let table_statistics = table_statistics.clone();
             let disable_morsel_split = *disable_morsel_split;
@@ -898,11 +908,16 @@ fn to_graph_rec<'a>(
                     pre_slice,
                     predicate,
                     predicate_file_skip_applied,
+                    output_row_limit,
+                    predicate_stats,
+                    requested_max_concurrent_scans,
                     hive_parts,
                     include_file_paths,
+                    virtual_columns,
                     missing_columns_policy,
                     forbid_extra_columns,
                     cast_columns_policy,
+                    per_column_cast_policy,
                     deletion_files,
                     table_statistics,
                     // Initialized later
@@ -1105,6 +1120,7 @@ fn to_graph_rec<'a>(
                     force_parallel: false,
                     args: args.clone(),
                     options: options.clone(),
+                    coalesce_overridden: false,
                 }),
             });
 
@@ -1606,11 +1622,16 @@ fn to_graph_rec<'a>(
                     pre_slice,
                     predicate,
                     predicate_file_skip_applied,
+                    output_row_limit: None,
+                    predicate_stats: None,
+                    requested_max_concurrent_scans: None,
                     hive_parts,
                     include_file_paths,
+                    virtual_columns: vec![],
                     missing_columns_policy,
                     forbid_extra_columns,
                     cast_columns_policy,
+                    per_column_cast_policy: None,
                     deletion_files,
                     table_statistics,
                     // Initialized later
@@ -1,5 +1,9 @@
-use arrow::temporal_conversions::{EPOCH_DAYS_FROM_CE, MILLISECONDS, SECONDS_IN_DAY};
-use chrono::{Datelike, NaiveDate};
+use arrow::temporal_conversions::{
+    EPOCH_DAYS_FROM_CE, MICROSECONDS_IN_DAY, MILLISECONDS, MILLISECONDS_IN_DAY, NANOSECONDS_IN_DAY,
+    SECONDS_IN_DAY,
+};
+use chrono::{Datelike, NaiveDate, Timelike, Weekday};
+use polars_core::prelude::arity::{unary_elementwise, unary_elementwise_values};
 
 use super::*;
 
@@ -13,6 +17,14 @@ pub(crate) fn naive_datetime_to_date(v: NaiveDateTime) -> i32 {
     (datetime_to_timestamp_ms(v) / (MILLISECONDS * SECONDS_IN_DAY)) as i32
 }
 
+/// Re-wrap the output of a rolling kernel on the physical days as a date.
+#[cfg(feature = "rolling_window")]
+fn physical_to_date(s: Series) -> PolarsResult<DateChunked> {
+    // Weighted windows and quantiles are computed on floats.
+    let s = s.cast(&DataType::Int32)?;
+    Ok(s.i32()?.clone().into_date())
+}
+
 pub trait DateMethods: AsDate {
     /// Extract month from underlying NaiveDate representation.
     /// Returns the year number in the calendar date.
@@ -86,6 +98,132 @@ pub trait DateMethods: AsDate {
             .apply_kernel_cast::<Int16Type>(&date_to_ordinal)
     }
 
+    /// Returns the day of year starting from 1.
+    ///
+    /// Alias for [`DateMethods::ordinal`].
+    fn ordinal_day(&self) -> Int16Chunked {
+        self.ordinal()
+    }
+
+    /// Returns the ISO weekday number where monday = 1 and sunday = 7
+    fn weekday(&self) -> Int8Chunked {
+        let ca = self.as_date();
+        // Closed formula to find weekday, no need to go via Chrono.
+        // The 4 comes from the fact that 1970-01-01 was a Thursday.
+        // We do an extra `+ 7` then `% 7` to ensure the result is non-negative.
+        unary_elementwise_values(ca.physical(), |t| (((t - 4) % 7 + 7) % 7 + 1) as i8)
+    }
+
+    /// Apply a rolling min over a fixed window of the dates.
+    ///
+    /// Windows that are not yet filled with `min_periods` values produce nulls.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_min(&self, options: RollingOptionsFixedWindow) -> PolarsResult<DateChunked> {
+        let s = self.as_date().physical().clone().into_series();
+        physical_to_date(s.rolling_min(options)?)
+    }
+
+    /// Apply a rolling max over a fixed window of the dates.
+    ///
+    /// Windows that are not yet filled with `min_periods` values produce nulls.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_max(&self, options: RollingOptionsFixedWindow) -> PolarsResult<DateChunked> {
+        let s = self.as_date().physical().clone().into_series();
+        physical_to_date(s.rolling_max(options)?)
+    }
+
+    /// Apply a rolling median over a fixed window of the dates.
+    ///
+    /// For windows with an even number of values the lower of the two middle dates is taken, so
+    /// that the result is always a whole day.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_median(&self, mut options: RollingOptionsFixedWindow) -> PolarsResult<DateChunked> {
+        use polars_compute::rolling::{RollingFnParams, RollingQuantileParams};
+
+        options.fn_params = Some(RollingFnParams::Quantile(RollingQuantileParams {
+            prob: 0.5,
+            method: QuantileMethod::Lower,
+        }));
+        let s = self.as_date().physical().clone().into_series();
+        physical_to_date(s.rolling_quantile(options)?)
+    }
+
+    /// Clamp the dates into the range `[lower, upper]`, given as days since the Unix epoch.
+    ///
+    /// Either bound may be omitted. If `lower > upper` every date is set to `upper`.
+    fn clip(&self, lower: Option<i32>, upper: Option<i32>) -> DateChunked {
+        let ca = self.as_date();
+        let lower = lower.unwrap_or(i32::MIN);
+        let upper = upper.unwrap_or(i32::MAX);
+        let phys = ca.physical();
+        let mut out = unary_elementwise_values(phys, |v| v.max(lower).min(upper));
+        // Clamping is monotonic, so the order is unchanged.
+        out.set_sorted_flag(phys.is_sorted_flag());
+        out.into_date()
+    }
+
+    /// Convert to datetimes at the wall-clock time `at_time` (midnight if `None`) of each date.
+    ///
+    /// With a `time_zone` the wall-clock time is localized to it, where `ambiguous` and
+    /// `non_existent` determine how datetimes that are ambiguous or don't exist due to DST
+    /// transitions are handled. Datetimes that don't fit in the time unit become null.
+    fn to_datetime(
+        &self,
+        time_unit: TimeUnit,
+        time_zone: Option<TimeZone>,
+        at_time: Option<NaiveTime>,
+        ambiguous: &StringChunked,
+        non_existent: NonExistent,
+    ) -> PolarsResult<DatetimeChunked> {
+        let ca = self.as_date();
+        let (units_in_day, ns_in_unit) = match time_unit {
+            TimeUnit::Nanoseconds => (NANOSECONDS_IN_DAY, 1),
+            TimeUnit::Microseconds => (MICROSECONDS_IN_DAY, 1_000),
+            TimeUnit::Milliseconds => (MILLISECONDS_IN_DAY, 1_000_000),
+        };
+        let time_of_day = at_time.map_or(0, |t| {
+            (t.num_seconds_from_midnight() as i64 * 1_000_000_000 + t.nanosecond() as i64)
+                / ns_in_unit
+        });
+
+        let phys: Int64Chunked = unary_elementwise(ca.physical(), |days: Option<i32>| {
+            (days? as i64)
+                .checked_mul(units_in_day)?
+                .checked_add(time_of_day)
+        });
+        let naive = phys.into_datetime(time_unit, None);
+
+        match time_zone {
+            #[cfg(feature = "timezones")]
+            Some(tz) => polars_ops::chunked_array::replace_time_zone(
+                &naive,
+                Some(&tz),
+                ambiguous,
+                non_existent,
+            ),
+            _ => {
+                polars_ensure!(
+                    time_zone.is_none(),
+                    ComputeError: "cannot make use of the `time_zone` argument without the 'timezones' feature enabled."
+                );
+                Ok(naive)
+            },
+        }
+    }
+
+    /// Truncate each date to the first day of its week, where weeks start on `week_start`.
+    fn truncate_to_week(&self, week_start: Weekday) -> DateChunked {
+        let ca = self.as_date();
+        // The UNIX epoch is a Thursday.
+        let epoch_days_from_week_start = (Weekday::Thu.num_days_from_monday() as i32
+            - week_start.num_days_from_monday() as i32)
+            .rem_euclid(7);
+        let phys: Int32Chunked = unary_elementwise_values(ca.physical(), |days: i32| {
+            days - (days + epoch_days_from_week_start).rem_euclid(7)
+        });
+        phys.into_date()
+    }
+
     fn parse_from_str_slice(name: PlSmallStr, v: &[&str], fmt: &str) -> DateChunked;
 
     /// Construct a date ChunkedArray from individual time components.
@@ -140,3 +278,310 @@ impl AsDate for DateChunked {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn date_components() {
+        let dates = [
+            NaiveDate::from_ymd_opt(2020, 2, 29),
+            NaiveDate::from_ymd_opt(2020, 12, 31),
+            None,
+            NaiveDate::from_ymd_opt(2021, 1, 1),
+        ];
+        let ca = Int32Chunked::from_iter_options(
+            "date".into(),
+            dates.iter().map(|d| d.map(naive_date_to_date)),
+        )
+        .into_date();
+
+        assert_eq!(
+            Vec::from(&ca.year()),
+            &[Some(2020), Some(2020), None, Some(2021)]
+        );
+        assert_eq!(Vec::from(&ca.month()), &[Some(2), Some(12), None, Some(1)]);
+        assert_eq!(Vec::from(&ca.day()), &[Some(29), Some(31), None, Some(1)]);
+        assert_eq!(
+            Vec::from(&ca.ordinal_day()),
+            &[Some(60), Some(366), None, Some(1)]
+        );
+        // Saturday, Thursday, null, Friday.
+        assert_eq!(Vec::from(&ca.weekday()), &[Some(6), Some(4), None, Some(5)]);
+    }
+
+    #[test]
+    fn date_iso_week_year_boundaries() {
+        let dates = [
+            // Monday, starts week 1 of 2020.
+            NaiveDate::from_ymd_opt(2019, 12, 30),
+            // Thursday of the last week of 2020, which has 53 weeks.
+            NaiveDate::from_ymd_opt(2020, 12, 31),
+            // Friday, still in week 53 of 2020.
+            NaiveDate::from_ymd_opt(2021, 1, 1),
+            None,
+            // Monday, starts week 1 of 2021.
+            NaiveDate::from_ymd_opt(2021, 1, 4),
+        ];
+        let ca = Int32Chunked::from_iter_options(
+            "date".into(),
+            dates.iter().map(|d| d.map(naive_date_to_date)),
+        )
+        .into_date();
+
+        assert_eq!(
+            Vec::from(&ca.week()),
+            &[Some(1), Some(53), Some(53), None, Some(1)]
+        );
+        assert_eq!(
+            Vec::from(&ca.iso_year()),
+            &[Some(2020), Some(2020), Some(2020), None, Some(2021)]
+        );
+        assert_eq!(
+            Vec::from(&ca.quarter()),
+            &[Some(4), Some(4), Some(1), None, Some(1)]
+        );
+    }
+
+    #[test]
+    fn truncate_to_week() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).map(naive_date_to_date);
+        // Friday, Sunday, Monday, null and a Wednesday before the epoch.
+        let ca = Int32Chunked::from_iter_options(
+            "date".into(),
+            [
+                date(2024, 3, 1),
+                date(2024, 3, 3),
+                date(2024, 3, 4),
+                None,
+                date(1969, 12, 31),
+            ]
+            .into_iter(),
+        )
+        .into_date();
+
+        assert_eq!(
+            Vec::from(ca.truncate_to_week(Weekday::Mon).physical()),
+            &[
+                date(2024, 2, 26),
+                date(2024, 2, 26),
+                date(2024, 3, 4),
+                None,
+                date(1969, 12, 29)
+            ]
+        );
+        assert_eq!(
+            Vec::from(ca.truncate_to_week(Weekday::Sun).physical()),
+            &[
+                date(2024, 2, 25),
+                date(2024, 3, 3),
+                date(2024, 3, 3),
+                None,
+                date(1969, 12, 28)
+            ]
+        );
+    }
+
+    #[test]
+    fn date_leap_year_and_days_in_month() {
+        let to_date = |dates: &[Option<NaiveDate>]| {
+            Int32Chunked::from_iter_options(
+                "date".into(),
+                dates.iter().map(|d| d.map(naive_date_to_date)),
+            )
+            .into_date()
+        };
+
+        let ca = to_date(&[
+            NaiveDate::from_ymd_opt(2024, 2, 10),
+            NaiveDate::from_ymd_opt(2023, 2, 10),
+            NaiveDate::from_ymd_opt(2000, 2, 1),
+            NaiveDate::from_ymd_opt(1900, 2, 1),
+            None,
+        ]);
+        assert_eq!(
+            Vec::from(&ca.is_leap_year()),
+            &[Some(true), Some(false), Some(true), Some(false), None]
+        );
+        assert_eq!(
+            Vec::from(&ca.days_in_month()),
+            &[Some(29), Some(28), Some(29), Some(28), None]
+        );
+
+        let ca = to_date(
+            &(1..=12)
+                .map(|month| NaiveDate::from_ymd_opt(2023, month, 15))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            Vec::from(&ca.days_in_month()),
+            [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31].map(Some)
+        );
+    }
+
+    #[test]
+    fn date_clip() {
+        let ymd = |y, m, d| naive_date_to_date(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        let ca = Int32Chunked::new(
+            "date".into(),
+            &[
+                Some(ymd(2019, 6, 1)),
+                Some(ymd(2020, 3, 15)),
+                None,
+                Some(ymd(2021, 1, 1)),
+            ],
+        )
+        .into_date();
+
+        let out = ca.clip(Some(ymd(2020, 1, 1)), Some(ymd(2020, 12, 31)));
+        assert_eq!(out.dtype(), &DataType::Date);
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[
+                Some(ymd(2020, 1, 1)),
+                Some(ymd(2020, 3, 15)),
+                None,
+                Some(ymd(2020, 12, 31))
+            ]
+        );
+
+        let out = ca.clip(None, Some(ymd(2020, 1, 1)));
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[
+                Some(ymd(2019, 6, 1)),
+                Some(ymd(2020, 1, 1)),
+                None,
+                Some(ymd(2020, 1, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn date_to_datetime() -> PolarsResult<()> {
+        let ymd = |y, m, d| naive_date_to_date(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        let ms = |y, m, d, h, min| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_opt(h, min, 0)
+                .unwrap()
+                .and_utc()
+                .timestamp_millis()
+        };
+        let ca = Int32Chunked::new(
+            "date".into(),
+            &[Some(ymd(2024, 1, 15)), None, Some(ymd(2024, 7, 15))],
+        )
+        .into_date();
+        let raise = StringChunked::new("".into(), ["raise"]);
+        let at_time = NaiveTime::from_hms_opt(9, 30, 0);
+
+        let out = ca.to_datetime(
+            TimeUnit::Milliseconds,
+            None,
+            None,
+            &raise,
+            NonExistent::Raise,
+        )?;
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[
+                Some(ms(2024, 1, 15, 0, 0)),
+                None,
+                Some(ms(2024, 7, 15, 0, 0))
+            ]
+        );
+
+        let out = ca.to_datetime(
+            TimeUnit::Microseconds,
+            None,
+            at_time,
+            &raise,
+            NonExistent::Raise,
+        )?;
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[
+                Some(ms(2024, 1, 15, 9, 30) * 1000),
+                None,
+                Some(ms(2024, 7, 15, 9, 30) * 1000)
+            ]
+        );
+
+        #[cfg(feature = "timezones")]
+        {
+            let tz = unsafe { TimeZone::from_static("America/New_York") };
+            let out = ca.to_datetime(
+                TimeUnit::Milliseconds,
+                Some(tz.clone()),
+                at_time,
+                &raise,
+                NonExistent::Raise,
+            )?;
+            assert_eq!(
+                out.dtype(),
+                &DataType::Datetime(TimeUnit::Milliseconds, Some(tz.clone()))
+            );
+            // 09:30 is 14:30 UTC in winter and 13:30 UTC in summer.
+            assert_eq!(
+                Vec::from(out.physical()),
+                &[
+                    Some(ms(2024, 1, 15, 14, 30)),
+                    None,
+                    Some(ms(2024, 7, 15, 13, 30))
+                ]
+            );
+
+            // 02:30 does not exist on the day clocks move forward.
+            let dst = Int32Chunked::new("date".into(), &[ymd(2024, 3, 10)]).into_date();
+            let at_time = NaiveTime::from_hms_opt(2, 30, 0);
+            let to_datetime = |non_existent| {
+                dst.to_datetime(
+                    TimeUnit::Milliseconds,
+                    Some(tz.clone()),
+                    at_time,
+                    &raise,
+                    non_existent,
+                )
+            };
+            assert!(to_datetime(NonExistent::Raise).is_err());
+            assert_eq!(to_datetime(NonExistent::Null)?.null_count(), 1);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "rolling_window")]
+    fn date_rolling() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("date".into(), &[3, 1, 4, 1, 5]).into_date();
+        let options = RollingOptionsFixedWindow {
+            window_size: 3,
+            min_periods: 3,
+            ..Default::default()
+        };
+
+        let out = ca.rolling_min(options.clone())?;
+        assert_eq!(out.dtype(), &DataType::Date);
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[None, None, Some(1), Some(1), Some(1)]
+        );
+        let out = ca.rolling_max(options.clone())?;
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[None, None, Some(4), Some(4), Some(5)]
+        );
+        let out = ca.rolling_median(options)?;
+        assert_eq!(out.dtype(), &DataType::Date);
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[None, None, Some(3), Some(1), Some(4)]
+        );
+        Ok(())
+    }
+}
@@ -1,11 +1,20 @@
 use arrow::array::{Array, PrimitiveArray};
 use arrow::compute::temporal;
+#[cfg(any(feature = "dtype-date", feature = "dtype-time"))]
+use arrow::temporal_conversions::{MICROSECONDS_IN_DAY, MILLISECONDS_IN_DAY, NANOSECONDS_IN_DAY};
 use polars_compute::cast::{CastOptionsImpl, cast};
+#[cfg(any(feature = "dtype-date", feature = "dtype-time"))]
+use polars_core::chunked_array::ops::arity::broadcast_binary_elementwise_values;
 use polars_core::prelude::*;
+use polars_core::series::ops::NullBehavior;
 #[cfg(feature = "timezones")]
 use polars_ops::chunked_array::datetime::replace_time_zone;
+use polars_ops::series::ClosedInterval;
 
 use super::*;
+use crate::windows::duration::{Duration, ensure_duration_matches_dtype};
+use crate::windows::group_by::{ClosedWindow, StartBy, group_by_windows};
+use crate::windows::window::Window;
 
 fn cast_and_apply<
     F: Fn(&dyn Array) -> PolarsResult<PrimitiveArray<T::Native>>,
@@ -30,6 +39,60 @@ fn cast_and_apply<
     ChunkedArray::from_chunk_iter(ca.name().clone(), chunks)
 }
 
+/// Apply `op` to the wall-clock timestamps of `ca` and localize the result to the time zone of
+/// `ca` again, raising on ambiguous or non-existent datetimes.
+#[cfg(any(feature = "dtype-date", feature = "dtype-time"))]
+fn map_local_timestamps(
+    ca: &DatetimeChunked,
+    op: impl FnOnce(&Int64Chunked) -> Int64Chunked,
+) -> PolarsResult<DatetimeChunked> {
+    let tu = ca.time_unit();
+    match ca.time_zone() {
+        #[cfg(feature = "timezones")]
+        Some(tz) => {
+            let ambiguous = StringChunked::new(PlSmallStr::EMPTY, ["raise"]);
+            let local = replace_time_zone(ca, None, &ambiguous, NonExistent::Raise)?;
+            let out = op(local.physical()).into_datetime(tu, None);
+            replace_time_zone(&out, Some(tz), &ambiguous, NonExistent::Raise)
+        },
+        tz => Ok(op(ca.physical()).into_datetime(tu, tz.clone())),
+    }
+}
+
+#[cfg(any(feature = "dtype-date", feature = "dtype-time"))]
+fn units_in_day(tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => NANOSECONDS_IN_DAY,
+        TimeUnit::Microseconds => MICROSECONDS_IN_DAY,
+        TimeUnit::Milliseconds => MILLISECONDS_IN_DAY,
+    }
+}
+
+fn nanoseconds_in_unit(tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    }
+}
+
+/// Round the timestamps of `ca` to a multiple of `unit` with `round(timestamp, multiple)`.
+fn round_to_unit(
+    ca: &DatetimeChunked,
+    unit: TimeUnit,
+    round: impl Fn(i64, i64) -> i64,
+) -> DatetimeChunked {
+    let multiple = nanoseconds_in_unit(unit) / nanoseconds_in_unit(ca.time_unit());
+    if multiple <= 1 {
+        return ca.clone();
+    }
+    let phys = ca.physical();
+    let mut out = phys.apply_values(|v| round(v, multiple));
+    // Rounding is monotonic, so the order is unchanged.
+    out.set_sorted_flag(phys.is_sorted_flag());
+    out.into_datetime(ca.time_unit(), ca.time_zone().clone())
+}
+
 pub trait DatetimeMethods: AsDatetime {
     /// Extract month from underlying NaiveDateTime representation.
     /// Returns the year number in the calendar date.
@@ -188,6 +251,238 @@ pub trait DatetimeMethods: AsDatetime {
         ca_local.physical().apply_kernel_cast::<Int16Type>(&f)
     }
 
+    /// Compute the difference `v[i] - v[i - n]` as a duration in the time unit of the column.
+    ///
+    /// The difference is taken on the physical timestamps, so it doesn't depend on the time
+    /// zone. With [`NullBehavior::Ignore`] the first `n` values (or last `-n` values for negative
+    /// `n`) are null, with [`NullBehavior::Drop`] they are removed.
+    fn diff(&self, n: i64, null_behavior: NullBehavior) -> PolarsResult<DurationChunked> {
+        let ca = self.as_datetime();
+        let phys = ca.physical();
+        let out = match null_behavior {
+            NullBehavior::Ignore => phys - &phys.shift(n),
+            NullBehavior::Drop => {
+                let abs_n = n.unsigned_abs() as usize;
+                let len = phys.len().saturating_sub(abs_n);
+                let (lhs, rhs) = if n < 0 { (0, abs_n) } else { (abs_n, 0) };
+                &phys.slice(lhs as i64, len) - &phys.slice(rhs as i64, len)
+            },
+        };
+        Ok(out.into_duration(ca.time_unit()))
+    }
+
+    /// Replace the time of day with `time`, keeping the date.
+    ///
+    /// The date is taken in the time zone of the column. A `time` of length 1 is broadcast.
+    #[cfg(feature = "dtype-time")]
+    fn with_time(&self, time: &TimeChunked) -> PolarsResult<DatetimeChunked> {
+        let ca = self.as_datetime();
+        polars_ensure!(
+            time.len() == ca.len() || time.len() == 1,
+            ShapeMismatch: "`with_time` expected a time of length {} or 1, got {}",
+            ca.len(), time.len()
+        );
+        let day = units_in_day(ca.time_unit());
+        let ns_per_unit = NANOSECONDS_IN_DAY / day;
+        map_local_timestamps(ca, |phys| {
+            broadcast_binary_elementwise_values(phys, time.physical(), |t: i64, time_ns: i64| {
+                t.div_euclid(day) * day + time_ns / ns_per_unit
+            })
+        })
+    }
+
+    /// Add a time of day `offset` to each timestamp, e.g. to go from the start of a day to a
+    /// per-row opening time.
+    ///
+    /// The offset is added to the wall-clock time in the time zone of the column, so across a DST
+    /// transition the elapsed time differs from `offset`. An `offset` of length 1 is broadcast.
+    #[cfg(feature = "dtype-time")]
+    fn add_time_offset(&self, offset: &TimeChunked) -> PolarsResult<DatetimeChunked> {
+        let ca = self.as_datetime();
+        polars_ensure!(
+            offset.len() == ca.len() || offset.len() == 1,
+            ShapeMismatch: "`add_time_offset` expected an offset of length {} or 1, got {}",
+            ca.len(), offset.len()
+        );
+        let ns_per_unit = NANOSECONDS_IN_DAY / units_in_day(ca.time_unit());
+        map_local_timestamps(ca, |phys| {
+            broadcast_binary_elementwise_values(phys, offset.physical(), |t: i64, time_ns: i64| {
+                t + time_ns / ns_per_unit
+            })
+        })
+    }
+
+    /// Replace the date with `date`, keeping the time of day.
+    ///
+    /// The time of day is taken in the time zone of the column. A `date` of length 1 is
+    /// broadcast.
+    #[cfg(feature = "dtype-date")]
+    fn with_date(&self, date: &DateChunked) -> PolarsResult<DatetimeChunked> {
+        let ca = self.as_datetime();
+        polars_ensure!(
+            date.len() == ca.len() || date.len() == 1,
+            ShapeMismatch: "`with_date` expected a date of length {} or 1, got {}",
+            ca.len(), date.len()
+        );
+        let day = units_in_day(ca.time_unit());
+        map_local_timestamps(ca, |phys| {
+            broadcast_binary_elementwise_values(phys, date.physical(), |t: i64, days: i32| {
+                days as i64 * day + t.rem_euclid(day)
+            })
+        })
+    }
+
+    /// Round down to a whole number of `unit`, e.g. drop the sub-millisecond part of a
+    /// nanosecond column. The time unit of the column is kept, and rounding to a unit that is
+    /// not coarser than it is a no-op.
+    fn floor_to(&self, unit: TimeUnit) -> DatetimeChunked {
+        round_to_unit(self.as_datetime(), unit, |v, multiple| {
+            v.div_euclid(multiple) * multiple
+        })
+    }
+
+    /// Round up to a whole number of `unit`. See [`floor_to`](Self::floor_to).
+    fn ceil_to(&self, unit: TimeUnit) -> DatetimeChunked {
+        round_to_unit(self.as_datetime(), unit, |v, multiple| {
+            let floor = v.div_euclid(multiple) * multiple;
+            if floor == v { v } else { floor + multiple }
+        })
+    }
+
+    /// Whether each timestamp lies between `low` and `high`, with the bounds included as given by
+    /// `closed`.
+    ///
+    /// The bounds must have the same time zone as the column. Timestamps are compared as instants
+    /// in the finest time unit of the three, and bounds of length 1 are broadcast.
+    fn is_between(
+        &self,
+        low: &DatetimeChunked,
+        high: &DatetimeChunked,
+        closed: ClosedInterval,
+    ) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_datetime();
+        for (bound, name) in [(low, "low"), (high, "high")] {
+            polars_ensure!(
+                bound.len() == ca.len() || bound.len() == 1,
+                ShapeMismatch: "`is_between` expected a `{}` bound of length {} or 1, got {}",
+                name, ca.len(), bound.len()
+            );
+            polars_ensure!(
+                bound.time_zone() == ca.time_zone(),
+                SchemaMismatch: "`is_between` expected a `{}` bound with time zone {:?}, got {:?}",
+                name, ca.time_zone(), bound.time_zone()
+            );
+        }
+
+        let tu = [low.time_unit(), high.time_unit()]
+            .into_iter()
+            .fold(ca.time_unit(), |a, b| if b < a { b } else { a });
+        let (ca, low, high) = (
+            ca.cast_time_unit(tu),
+            low.cast_time_unit(tu),
+            high.cast_time_unit(tu),
+        );
+        let (phys, low, high) = (ca.physical(), low.physical(), high.physical());
+
+        let above = match closed {
+            ClosedInterval::None | ClosedInterval::Right => phys.gt(low),
+            ClosedInterval::Both | ClosedInterval::Left => phys.gt_eq(low),
+        };
+        let below = match closed {
+            ClosedInterval::None | ClosedInterval::Left => phys.lt(high),
+            ClosedInterval::Both | ClosedInterval::Right => phys.lt_eq(high),
+        };
+        Ok(above & below)
+    }
+
+    /// Daylight saving time offset in effect at each timestamp, as a millisecond duration.
+    ///
+    /// Only defined for time-zone-aware columns.
+    #[cfg(feature = "timezones")]
+    fn dst_offset(&self) -> PolarsResult<DurationChunked> {
+        let ca = self.as_datetime();
+        match ca.dtype() {
+            DataType::Datetime(time_unit, Some(tz)) => {
+                Ok(crate::dst_offset(ca, time_unit, &tz.to_chrono()?))
+            },
+            dt => polars_bail!(
+                opq = dst_offset,
+                got = dt,
+                expected = "time-zone-aware datetime"
+            ),
+        }
+    }
+
+    /// Offset from UTC of the time zone at each timestamp, not including daylight saving time,
+    /// as a millisecond duration.
+    ///
+    /// Only defined for time-zone-aware columns.
+    #[cfg(feature = "timezones")]
+    fn base_utc_offset(&self) -> PolarsResult<DurationChunked> {
+        let ca = self.as_datetime();
+        match ca.dtype() {
+            DataType::Datetime(time_unit, Some(tz)) => {
+                Ok(crate::base_utc_offset(ca, time_unit, &tz.to_chrono()?))
+            },
+            dt => polars_bail!(
+                opq = base_utc_offset,
+                got = dt,
+                expected = "time-zone-aware datetime"
+            ),
+        }
+    }
+
+    /// Assign the timestamps to the windows of a dynamic group-by, without going through a
+    /// `DataFrame`.
+    ///
+    /// The column must be sorted and free of nulls. Windows start every `every`, are `period`
+    /// long and are shifted by `offset`. For time-zone-aware columns the windows are computed on
+    /// the wall-clock time, so e.g. daily windows start at local midnight. Returns the slice
+    /// groups and the lower boundary of each window.
+    fn group_by_dynamic(
+        &self,
+        every: Duration,
+        period: Duration,
+        offset: Duration,
+        closed_window: ClosedWindow,
+        start_by: StartBy,
+    ) -> PolarsResult<(GroupsType, DatetimeChunked)> {
+        let ca = self.as_datetime();
+        polars_ensure!(ca.null_count() == 0, ComputeError: "null values in dynamic group_by not supported, fill nulls.");
+        polars_ensure!(!every.negative, ComputeError: "'every' argument must be positive");
+        ensure_duration_matches_dtype(every, ca.dtype(), "every")?;
+        ensure_duration_matches_dtype(offset, ca.dtype(), "offset")?;
+        ensure_duration_matches_dtype(period, ca.dtype(), "period")?;
+
+        let tu = ca.time_unit();
+        let tz = ca.time_zone().clone();
+        if ca.is_empty() {
+            let lower = Int64Chunked::full_null(ca.name().clone(), 0).into_datetime(tu, tz);
+            return Ok((GroupsType::new_slice(vec![], false, true), lower));
+        }
+
+        let phys = ca.physical().rechunk();
+        let ts = phys.cont_slice()?;
+        polars_ensure!(ts.is_sorted(), ComputeError: "input data is not sorted");
+
+        let overlapping = match closed_window {
+            ClosedWindow::Both => period >= every,
+            _ => period > every,
+        };
+        let (groups, lower, _) = group_by_windows(
+            Window::new(every, period, offset),
+            ts,
+            closed_window,
+            tu,
+            &tz,
+            true,
+            false,
+            start_by,
+        )?;
+        let lower = Int64Chunked::new_vec(ca.name().clone(), lower).into_datetime(tu, tz);
+        Ok((GroupsType::new_slice(groups, overlapping, true), lower))
+    }
+
     fn parse_from_str_slice(
         name: PlSmallStr,
         v: &[&str],
@@ -299,6 +594,17 @@ impl DatetimeMethods for DatetimeChunked {}
 mod test {
     use super::*;
 
+    /// Milliseconds since the epoch of a UTC datetime.
+    #[cfg(any(feature = "dtype-time", feature = "timezones"))]
+    fn ms(y: i32, m: u32, d: u32, h: u32, min: u32) -> i64 {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis()
+    }
+
     #[test]
     fn from_datetime() {
         let datetimes: Vec<_> = [
@@ -325,4 +631,407 @@ mod test {
             dt.physical().cont_slice().unwrap()
         );
     }
+
+    #[test]
+    fn datetime_diff() -> PolarsResult<()> {
+        let ca = Int64Chunked::new("dt".into(), &[Some(10), Some(15), None, Some(30), Some(28)])
+            .into_datetime(TimeUnit::Milliseconds, None);
+
+        let out = ca.diff(1, NullBehavior::Ignore)?;
+        assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[None, Some(5), None, None, Some(-2)]
+        );
+
+        let out = ca.diff(-2, NullBehavior::Ignore)?;
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[None, Some(-15), None, None, None]
+        );
+
+        let out = ca.diff(2, NullBehavior::Drop)?;
+        assert_eq!(Vec::from(out.physical()), &[None, Some(15), None]);
+
+        let out = ca.diff(-1, NullBehavior::Drop)?;
+        assert_eq!(Vec::from(out.physical()), &[Some(-5), None, None, Some(2)]);
+
+        let out = ca.diff(10, NullBehavior::Drop)?;
+        assert!(out.is_empty());
+
+        // The difference doesn't depend on the time zone.
+        let tz = unsafe { TimeZone::from_static("Europe/Amsterdam") };
+        let ca_tz = ca
+            .physical()
+            .clone()
+            .into_datetime(TimeUnit::Milliseconds, Some(tz));
+        let out_tz = ca_tz.diff(1, NullBehavior::Ignore)?;
+        assert_eq!(out_tz.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+        assert!(
+            out_tz
+                .physical()
+                .equal_missing(ca.diff(1, NullBehavior::Ignore)?.physical())
+                .all()
+        );
+        Ok(())
+    }
+
+    #[cfg(all(feature = "dtype-time", feature = "dtype-date"))]
+    #[test]
+    fn datetime_with_time_and_date() -> PolarsResult<()> {
+        let ca = Int64Chunked::new(
+            "dt".into(),
+            &[
+                Some(ms(2024, 1, 1, 10, 30)),
+                Some(ms(1969, 12, 31, 12, 0)),
+                None,
+            ],
+        )
+        .into_datetime(TimeUnit::Milliseconds, None);
+
+        // A single time is broadcast.
+        let noon = Int64Chunked::new("t".into(), &[12 * 3_600_000_000_000i64]).into_time();
+        let out = ca.with_time(&noon)?;
+        assert_eq!(out.dtype(), ca.dtype());
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[
+                Some(ms(2024, 1, 1, 12, 0)),
+                Some(ms(1969, 12, 31, 12, 0)),
+                None
+            ]
+        );
+
+        let times = Int64Chunked::new("t".into(), &[Some(0), None, Some(0)]).into_time();
+        let out = ca.with_time(&times)?;
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[Some(ms(2024, 1, 1, 0, 0)), None, None]
+        );
+
+        let date = Int32Chunked::new("d".into(), &[19_737]).into_date();
+        let out = ca.with_date(&date)?;
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[
+                Some(ms(2024, 1, 15, 10, 30)),
+                Some(ms(2024, 1, 15, 12, 0)),
+                None
+            ]
+        );
+
+        assert!(ca.with_time(&times.slice(0, 2)).is_err());
+        Ok(())
+    }
+
+    #[cfg(all(feature = "dtype-time", feature = "dtype-date", feature = "timezones"))]
+    #[test]
+    fn datetime_with_time_and_date_tz() -> PolarsResult<()> {
+        let tz = unsafe { TimeZone::from_static("Europe/Amsterdam") };
+        // 2024-07-01 01:30 in Amsterdam.
+        let ca = Int64Chunked::new("dt".into(), &[ms(2024, 6, 30, 23, 30)])
+            .into_datetime(TimeUnit::Milliseconds, Some(tz));
+
+        let noon = Int64Chunked::new("t".into(), &[12 * 3_600_000_000_000i64]).into_time();
+        let out = ca.with_time(&noon)?;
+        assert_eq!(out.dtype(), ca.dtype());
+        assert_eq!(out.physical().get(0), Some(ms(2024, 7, 1, 10, 0)));
+
+        // 2024-01-15, where the offset is one hour instead of two.
+        let date = Int32Chunked::new("d".into(), &[19_737]).into_date();
+        let out = ca.with_date(&date)?;
+        assert_eq!(out.physical().get(0), Some(ms(2024, 1, 15, 0, 30)));
+        Ok(())
+    }
+
+    #[cfg(feature = "timezones")]
+    #[test]
+    fn datetime_iso_week_tz() {
+        // Sunday 2019-12-29 23:30 UTC, which is Monday 2019-12-30 00:30 in Amsterdam.
+        let physical = Int64Chunked::new("dt".into(), &[Some(ms(2019, 12, 29, 23, 30)), None]);
+
+        let naive = physical.clone().into_datetime(TimeUnit::Milliseconds, None);
+        assert_eq!(Vec::from(&naive.week()), &[Some(52), None]);
+        assert_eq!(Vec::from(&naive.iso_year()), &[Some(2019), None]);
+        assert_eq!(Vec::from(&naive.quarter()), &[Some(4), None]);
+
+        let tz = unsafe { TimeZone::from_static("Europe/Amsterdam") };
+        let ca = physical.into_datetime(TimeUnit::Milliseconds, Some(tz));
+        assert_eq!(Vec::from(&ca.week()), &[Some(1), None]);
+        assert_eq!(Vec::from(&ca.iso_year()), &[Some(2020), None]);
+        assert_eq!(Vec::from(&ca.quarter()), &[Some(4), None]);
+    }
+
+    #[test]
+    fn datetime_is_between() -> PolarsResult<()> {
+        let ca = Int64Chunked::new("dt".into(), &[Some(999), Some(1_000), None, Some(1_500)])
+            .into_datetime(TimeUnit::Milliseconds, None);
+        // 1 second and 1.5 seconds, in other units.
+        let low = Int64Chunked::new("low".into(), &[1_000_000])
+            .into_datetime(TimeUnit::Microseconds, None);
+        let high = Int64Chunked::new("high".into(), &[1_500_000_000])
+            .into_datetime(TimeUnit::Nanoseconds, None);
+
+        for (closed, expected) in [
+            (ClosedInterval::Both, [false, true, false, true]),
+            (ClosedInterval::Left, [false, true, false, false]),
+            (ClosedInterval::Right, [false, false, false, true]),
+            (ClosedInterval::None, [false, false, false, false]),
+        ] {
+            let out = ca.is_between(&low, &high, closed)?;
+            assert_eq!(out.len(), ca.len());
+            assert_eq!(out.get(0), Some(expected[0]));
+            assert_eq!(out.get(1), Some(expected[1]));
+            assert_eq!(out.get(2), None);
+            assert_eq!(out.get(3), Some(expected[3]));
+        }
+
+        // A sub-millisecond bound isn't rounded to the unit of the column.
+        let low =
+            Int64Chunked::new("low".into(), &[999_500]).into_datetime(TimeUnit::Microseconds, None);
+        let out = ca.is_between(&low, &high, ClosedInterval::Both)?;
+        assert_eq!(out.get(0), Some(false));
+        assert_eq!(out.get(1), Some(true));
+
+        // Bounds of the same length are compared elementwise.
+        let low = Int64Chunked::new("low".into(), &[0, 1_001, 0, 1_500])
+            .into_datetime(TimeUnit::Milliseconds, None);
+        let high = Int64Chunked::new("high".into(), &[998, 2_000, 0, 1_500])
+            .into_datetime(TimeUnit::Milliseconds, None);
+        let out = ca.is_between(&low, &high, ClosedInterval::Both)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), Some(false), None, Some(true)]
+        );
+
+        assert!(
+            ca.is_between(&low.slice(0, 2), &high, ClosedInterval::Both)
+                .is_err()
+        );
+        #[cfg(feature = "timezones")]
+        {
+            let tz = unsafe { TimeZone::from_static("Europe/Amsterdam") };
+            let low_tz = low
+                .physical()
+                .clone()
+                .into_datetime(TimeUnit::Milliseconds, Some(tz));
+            assert!(ca.is_between(&low_tz, &high, ClosedInterval::Both).is_err());
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "dtype-time")]
+    #[test]
+    fn datetime_add_time_offset() -> PolarsResult<()> {
+        let ca = Int64Chunked::new(
+            "dt".into(),
+            &[
+                Some(ms(2024, 1, 1, 0, 0)),
+                Some(ms(2024, 12, 31, 23, 0)),
+                None,
+            ],
+        )
+        .into_datetime(TimeUnit::Milliseconds, None);
+
+        // A single offset is broadcast, and may cross midnight.
+        let offset = Int64Chunked::new("t".into(), &[2 * 3_600_000_000_000i64]).into_time();
+        let out = ca.add_time_offset(&offset)?;
+        assert_eq!(out.dtype(), ca.dtype());
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[Some(ms(2024, 1, 1, 2, 0)), Some(ms(2025, 1, 1, 1, 0)), None]
+        );
+
+        let offsets = Int64Chunked::new(
+            "t".into(),
+            &[
+                Some(9 * 3_600_000_000_000 + 30 * 60_000_000_000),
+                None,
+                Some(0),
+            ],
+        )
+        .into_time();
+        let out = ca.add_time_offset(&offsets)?;
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[Some(ms(2024, 1, 1, 9, 30)), None, None]
+        );
+
+        assert!(ca.add_time_offset(&offsets.slice(0, 2)).is_err());
+        Ok(())
+    }
+
+    #[cfg(all(feature = "dtype-time", feature = "timezones"))]
+    #[test]
+    fn datetime_add_time_offset_dst() -> PolarsResult<()> {
+        let tz = unsafe { TimeZone::from_static("Europe/Amsterdam") };
+        // Local midnight before and on the day the clocks move forward.
+        let ca = Int64Chunked::new(
+            "dt".into(),
+            &[ms(2024, 3, 29, 23, 0), ms(2024, 3, 30, 23, 0)],
+        )
+        .into_datetime(TimeUnit::Milliseconds, Some(tz));
+
+        let open = Int64Chunked::new("t".into(), &[(9 * 60 + 30) * 60_000_000_000i64]).into_time();
+        let out = ca.add_time_offset(&open)?;
+        assert_eq!(out.dtype(), ca.dtype());
+        // 09:30 local time on both days, which is an hour less elapsed on the second.
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[Some(ms(2024, 3, 30, 8, 30)), Some(ms(2024, 3, 31, 7, 30))]
+        );
+
+        // 02:30 doesn't exist on the second day.
+        let offset =
+            Int64Chunked::new("t".into(), &[(2 * 60 + 30) * 60_000_000_000i64]).into_time();
+        assert!(ca.add_time_offset(&offset).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_floor_ceil_to() {
+        let mut phys = Int64Chunked::new(
+            "dt".into(),
+            &[
+                Some(-1_500_000_001),
+                Some(-1_000_000),
+                None,
+                Some(0),
+                Some(1_999_999),
+                Some(2_000_001),
+            ],
+        );
+        phys.set_sorted_flag(IsSorted::Ascending);
+        let ca = phys.into_datetime(TimeUnit::Nanoseconds, None);
+
+        let out = ca.floor_to(TimeUnit::Milliseconds);
+        assert_eq!(out.dtype(), ca.dtype());
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[
+                Some(-1_501_000_000),
+                Some(-1_000_000),
+                None,
+                Some(0),
+                Some(1_000_000),
+                Some(2_000_000)
+            ]
+        );
+        assert_eq!(out.physical().is_sorted_flag(), IsSorted::Ascending);
+
+        let out = ca.ceil_to(TimeUnit::Milliseconds);
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[
+                Some(-1_500_000_000),
+                Some(-1_000_000),
+                None,
+                Some(0),
+                Some(2_000_000),
+                Some(3_000_000)
+            ]
+        );
+        assert_eq!(out.physical().is_sorted_flag(), IsSorted::Ascending);
+
+        let out = ca.ceil_to(TimeUnit::Microseconds);
+        assert_eq!(out.physical().get(4), Some(2_000_000));
+        assert_eq!(out.physical().get(0), Some(-1_500_000_000));
+
+        // A unit that isn't coarser than the column's is a no-op.
+        let ca_ms = ca.floor_to(TimeUnit::Milliseconds);
+        let ms = ca_ms
+            .physical()
+            .clone()
+            .into_datetime(TimeUnit::Milliseconds, None);
+        assert_eq!(
+            Vec::from(ms.ceil_to(TimeUnit::Nanoseconds).physical()),
+            Vec::from(ms.physical())
+        );
+    }
+
+    #[cfg(feature = "timezones")]
+    #[test]
+    fn datetime_dst_and_base_utc_offset() -> PolarsResult<()> {
+        let ms = |h, min| ms(2024, 3, 31, h, min);
+        let tz = unsafe { TimeZone::from_static("Europe/Amsterdam") };
+        // Amsterdam switches to summer time at 01:00 UTC.
+        let ca = Int64Chunked::new("dt".into(), &[Some(ms(0, 30)), None, Some(ms(1, 30))])
+            .into_datetime(TimeUnit::Milliseconds, Some(tz));
+
+        let out = ca.dst_offset()?;
+        assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+        assert_eq!(Vec::from(out.physical()), &[Some(0), None, Some(3_600_000)]);
+
+        let out = ca.base_utc_offset()?;
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[Some(3_600_000), None, Some(3_600_000)]
+        );
+
+        let naive = Int64Chunked::new("dt".into(), &[ms(0, 30)])
+            .into_datetime(TimeUnit::Milliseconds, None);
+        assert!(naive.dst_offset().is_err());
+        assert!(naive.base_utc_offset().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_group_by_dynamic() -> PolarsResult<()> {
+        let ca = Int64Chunked::new("dt".into(), &[0i64, 10, 20])
+            .into_datetime(TimeUnit::Milliseconds, None);
+        let every = Duration::parse("10ms");
+        let zero = Duration::parse("0ms");
+
+        let (groups, lower) =
+            ca.group_by_dynamic(every, every, zero, ClosedWindow::Left, StartBy::WindowBound)?;
+        assert_eq!(groups.unwrap_slice(), &[[0, 1], [1, 1], [2, 1]]);
+        assert!(!groups.is_overlapping());
+        assert_eq!(lower.physical().cont_slice()?, &[0, 10, 20]);
+
+        let (groups, _) =
+            ca.group_by_dynamic(every, every, zero, ClosedWindow::Both, StartBy::WindowBound)?;
+        assert_eq!(groups.unwrap_slice(), &[[0, 2], [1, 2], [2, 1]]);
+        assert!(groups.is_overlapping());
+
+        #[cfg(feature = "timezones")]
+        {
+            // Daily windows start at local midnight, which moves from 23:00 to 22:00 UTC when
+            // Amsterdam switches to summer time.
+            let ms = |d, h, min| ms(2024, 3, d, h, min);
+            let tz = unsafe { TimeZone::from_static("Europe/Amsterdam") };
+            let ca = Int64Chunked::new(
+                "dt".into(),
+                &[ms(30, 23, 30), ms(31, 10, 0), ms(31, 22, 30)],
+            )
+            .into_datetime(TimeUnit::Milliseconds, Some(tz));
+            let day = Duration::parse("1d");
+            let (groups, lower) = ca.group_by_dynamic(
+                day,
+                day,
+                Duration::parse("0d"),
+                ClosedWindow::Left,
+                StartBy::WindowBound,
+            )?;
+            assert_eq!(groups.unwrap_slice(), &[[0, 2], [2, 1]]);
+            assert_eq!(
+                lower.physical().cont_slice()?,
+                &[ms(30, 23, 0), ms(31, 22, 0)]
+            );
+        }
+
+        let unsorted =
+            Int64Chunked::new("dt".into(), &[10i64, 0]).into_datetime(TimeUnit::Milliseconds, None);
+        assert!(
+            unsorted
+                .group_by_dynamic(
+                    Duration::parse("10ms"),
+                    Duration::parse("10ms"),
+                    Duration::parse("0ms"),
+                    ClosedWindow::Left,
+                    StartBy::WindowBound
+                )
+                .is_err()
+        );
+        Ok(())
+    }
 }
@@ -72,8 +72,8 @@ pub trait StringMethods: AsString {
     /// Parsing string values and return a [`TimeChunked`]
     fn as_time(&self, fmt: Option<&str>, use_cache: bool) -> PolarsResult<TimeChunked> {
         let string_ca = self.as_string();
-        let fmt = match fmt {
-            Some(fmt) => fmt,
+        let (fmt, fallback_fmts) = match fmt {
+            Some(fmt) => (fmt, None),
             None => {
                 let Some(idx) = string_ca.first_non_null() else {
                     return Ok(
@@ -82,14 +82,20 @@ pub trait StringMethods: AsString {
                     );
                 };
                 let val = string_ca.get(idx).expect("should not be null");
-                sniff_fmt_time(val)?
+                (sniff_fmt_time(val)?, Some(patterns::TIME_H_M_S))
             },
         };
         let use_cache = use_cache && string_ca.len() > 50;
 
         let mut convert = LruCachedFunc::new(
             |s| {
-                let naive_time = NaiveTime::parse_from_str(s, fmt).ok()?;
+                // An inferred format is sniffed from the first value only, but later values may
+                // have a different precision, e.g. `12:34` followed by `12:34:56.789123`.
+                let naive_time = NaiveTime::parse_from_str(s, fmt).ok().or_else(|| {
+                    fallback_fmts?
+                        .iter()
+                        .find_map(|fmt| NaiveTime::parse_from_str(s, fmt).ok())
+                })?;
                 Some(time_to_time64ns(&naive_time))
             },
             (string_ca.len() as f64).sqrt() as usize,
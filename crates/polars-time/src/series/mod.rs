@@ -88,12 +88,7 @@ pub trait TemporalMethods: AsSeries {
         let s = self.as_series();
         match s.dtype() {
             #[cfg(feature = "dtype-date")]
-            DataType::Date => s.date().map(|ca| {
-                // Closed formula to find weekday, no need to go via Chrono.
-                // The 4 comes from the fact that 1970-01-01 was a Thursday.
-                // We do an extra `+ 7` then `% 7` to ensure the result is non-negative.
-                unary_elementwise_values(ca.physical(), |t| (((t - 4) % 7 + 7) % 7 + 1) as i8)
-            }),
+            DataType::Date => s.date().map(|ca| ca.weekday()),
             #[cfg(feature = "dtype-datetime")]
             DataType::Datetime(time_unit, time_zone) => s.datetime().map(|ca| {
                 match time_zone.as_deref().map(|x| x.as_str()) {
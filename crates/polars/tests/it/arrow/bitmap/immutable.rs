@@ -76,3 +76,241 @@ fn debug() {
         "Bitmap { len: 7, offset: 2, bytes: [0b111110__, 0b_______1] }"
     );
 }
+
+#[test]
+fn first_last_set() {
+    let bm = Bitmap::from_iter([false; 0]);
+    assert_eq!((bm.first_set(), bm.last_set()), (None, None));
+
+    let bm = Bitmap::from_iter([true]);
+    assert_eq!((bm.first_set(), bm.last_set()), (Some(0), Some(0)));
+
+    let bm = Bitmap::new_zeroed(200);
+    assert_eq!((bm.first_set(), bm.last_set()), (None, None));
+
+    let bm = Bitmap::from_iter((0..200).map(|i| i == 70 || i == 130));
+    assert_eq!((bm.first_set(), bm.last_set()), (Some(70), Some(130)));
+
+    // Indices are relative to the offset of the slice.
+    let sliced = bm.clone().sliced(65, 100);
+    assert_eq!((sliced.first_set(), sliced.last_set()), (Some(5), Some(65)));
+
+    let sliced = bm.clone().sliced(71, 59);
+    assert_eq!((sliced.first_set(), sliced.last_set()), (None, None));
+
+    let sliced = bm.sliced(130, 1);
+    assert_eq!((sliced.first_set(), sliced.last_set()), (Some(0), Some(0)));
+}
+
+fn naive_tile(pattern: &Bitmap, times: usize) -> Vec<bool> {
+    (0..times).flat_map(|_| pattern.iter()).collect()
+}
+
+#[test]
+fn tile() {
+    for times in [0, 1, 3, 64, 65, 200] {
+        for pattern in [
+            Bitmap::from_iter([true]),
+            Bitmap::from_iter([false]),
+            Bitmap::from_iter([true, false, false]),
+            Bitmap::from_iter((0..64).map(|i| i % 4 == 0 || i == 63)),
+            // Sliced patterns don't start at a byte boundary.
+            Bitmap::from_iter((0..70).map(|i| i % 3 == 0)).sliced(5, 64),
+        ] {
+            let tiled = Bitmap::tile(&pattern, times);
+            assert_eq!(tiled.len(), pattern.len() * times);
+            assert_eq!(
+                tiled.iter().collect::<Vec<_>>(),
+                naive_tile(&pattern, times)
+            );
+            assert_eq!(tiled.unset_bits(), pattern.unset_bits() * times);
+        }
+    }
+
+    let pattern = Bitmap::from_iter((0..128).map(|i| i % 5 == 0));
+    let tiled = Bitmap::tile(&pattern, 3);
+    assert_eq!(tiled.iter().collect::<Vec<_>>(), naive_tile(&pattern, 3));
+}
+
+#[test]
+fn serialize_roundtrip() {
+    let bm = Bitmap::from_iter((0..300).map(|i| i % 7 == 0));
+    for bitmap in [
+        Bitmap::new(),
+        Bitmap::from_iter([true, false, true]),
+        bm.clone(),
+        // Sliced bitmaps serialize their logical bits only.
+        bm.clone().sliced(3, 200),
+        bm.sliced(5, 60),
+        // Long runs are run-length encoded.
+        Bitmap::new_with_value(true, 1000),
+        Bitmap::from_iter((0..1000).map(|i| i >= 990)),
+    ] {
+        let mut buf = Vec::new();
+        bitmap.serialize(&mut buf).unwrap();
+        let out = Bitmap::deserialize(&mut buf.as_slice()).unwrap();
+        assert_eq!(out.offset(), 0);
+        assert_eq!(out, bitmap);
+        assert_eq!(out.unset_bits(), bitmap.unset_bits());
+    }
+
+    let mut buf = Vec::new();
+    Bitmap::new_with_value(true, 1000)
+        .serialize(&mut buf)
+        .unwrap();
+    assert!(buf.len() < 1000 / 8);
+
+    assert!(Bitmap::deserialize(&mut b"PLBM".as_slice()).is_err());
+    buf[4] = 0;
+    assert!(Bitmap::deserialize(&mut buf.as_slice()).is_err());
+}
+
+#[test]
+fn transition_point() {
+    let bm = Bitmap::from_iter([true; 100]);
+    assert_eq!(bm.transition_point(), None);
+
+    let bm = Bitmap::new_zeroed(100);
+    assert_eq!(bm.transition_point(), None);
+
+    let bm = Bitmap::from_iter([false; 0]);
+    assert_eq!(bm.transition_point(), None);
+
+    let bm = Bitmap::from_iter([false, true]);
+    assert_eq!(bm.transition_point(), Some(1));
+
+    for split in [1, 63, 64, 65, 150, 199] {
+        let bm = Bitmap::from_iter((0..200).map(|i| i >= split));
+        assert_eq!(bm.transition_point(), Some(split));
+
+        let bm = Bitmap::from_iter((0..200).map(|i| i < split));
+        assert_eq!(bm.transition_point(), Some(split));
+    }
+
+    // Indices are relative to the offset of the slice.
+    let bm = Bitmap::from_iter((0..200).map(|i| i >= 70));
+    assert_eq!(bm.clone().sliced(3, 100).transition_point(), Some(67));
+    assert_eq!(bm.sliced(71, 50).transition_point(), None);
+
+    let bm = Bitmap::from_iter((0..200).map(|i| (70..130).contains(&i)));
+    assert_eq!(bm.transition_point(), None);
+    assert_eq!(bm.sliced(0, 100).transition_point(), Some(70));
+
+    let bm = Bitmap::from_iter((0..200).map(|i| i % 3 == 0));
+    assert_eq!(bm.transition_point(), None);
+}
+
+#[test]
+fn take() {
+    let bm = Bitmap::from_iter((0..150).map(|i| i % 3 == 0 || i == 100));
+    let indices = (0..150).rev().chain([100, 0, 1, 149]).collect::<Vec<_>>();
+    let out = unsafe { bm.take(&indices) };
+    let expected = indices
+        .iter()
+        .map(|&i| bm.get_bit(i as usize))
+        .collect::<Vec<_>>();
+    assert_eq!(out.len(), indices.len());
+    assert_eq!(out.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(out.unset_bits(), expected.iter().filter(|b| !**b).count());
+
+    // Indices are relative to the offset of the slice.
+    let sliced = bm.sliced(100, 20);
+    let out = unsafe { sliced.take(&[0, 1, 2, 0]) };
+    assert_eq!(out.iter().collect::<Vec<_>>(), [true, false, true, true]);
+
+    let out = unsafe { Bitmap::new().take(&[]) };
+    assert!(out.is_empty());
+}
+
+#[test]
+fn runs() {
+    let naive_runs = |bm: &Bitmap| {
+        let mut runs: Vec<(bool, usize)> = Vec::new();
+        for bit in bm.iter() {
+            match runs.last_mut() {
+                Some((b, n)) if *b == bit => *n += 1,
+                _ => runs.push((bit, 1)),
+            }
+        }
+        runs
+    };
+
+    assert_eq!(Bitmap::new().runs().count(), 0);
+    assert_eq!(
+        Bitmap::new_with_value(true, 200).runs().collect::<Vec<_>>(),
+        [(true, 200)]
+    );
+    let alternating = Bitmap::from_iter((0..130).map(|i| i % 2 == 1));
+    assert_eq!(
+        alternating.runs().collect::<Vec<_>>(),
+        naive_runs(&alternating)
+    );
+    assert_eq!(alternating.runs().count(), 130);
+
+    let bm = Bitmap::from_iter((0..300).map(|i| (i / 7) % 3 == 0 || (64..200).contains(&i)));
+    for (offset, len) in [(0, 300), (3, 200), (61, 70), (64, 136), (199, 101)] {
+        let sliced = bm.clone().sliced(offset, len);
+        let runs = sliced.runs().collect::<Vec<_>>();
+        assert_eq!(runs, naive_runs(&sliced));
+        assert_eq!(runs.iter().map(|(_, n)| n).sum::<usize>(), len);
+    }
+}
+
+#[test]
+fn par_from_trusted_len() {
+    let f = |i: usize| i % 3 == 0 || (1000..1100).contains(&i);
+    for length in [0, 1, 63, 64, 65, 511, 512, 10_000, 100_003] {
+        let expected = Bitmap::from_trusted_len_iter((0..length).map(f));
+        let out = Bitmap::par_from_trusted_len(length, f);
+        assert_eq!(out.len(), length);
+        assert_eq!(out.unset_bits(), expected.unset_bits());
+        assert_eq!(out, expected);
+    }
+}
+
+#[test]
+fn count_ones_in_range() {
+    use rand::RngExt;
+
+    let mut rng = rand::rng();
+    let bm = Bitmap::from_iter((0..1000).map(|_| rng.random_bool(0.3)));
+    for bm in [bm.clone(), bm.clone().sliced(13, 900)] {
+        for _ in 0..1000 {
+            let start = rng.random_range(0..=bm.len());
+            let len = rng.random_range(0..=bm.len() - start);
+            assert_eq!(
+                bm.count_ones_in_range(start, len),
+                bm.clone().sliced(start, len).set_bits(),
+                "start={start} len={len}"
+            );
+        }
+    }
+    assert_eq!(bm.count_ones_in_range(bm.len(), 0), 0);
+}
+
+#[test]
+fn apply_mask() {
+    let bm = Bitmap::from_iter((0..200).map(|i| i % 3 == 0 || i == 100));
+    for keep in [
+        Bitmap::from_iter((0..200).map(|i| i % 2 == 0)),
+        Bitmap::from_iter((0..200).map(|i| (60..140).contains(&i) || i == 199)),
+        Bitmap::new_zeroed(200),
+        Bitmap::new_with_value(true, 200),
+    ] {
+        // Sliced bitmaps don't start at a byte boundary.
+        for (bm, keep) in [
+            (bm.clone(), keep.clone()),
+            (bm.clone().sliced(5, 130), keep.sliced(70, 130)),
+        ] {
+            let expected = bm
+                .iter()
+                .zip(keep.iter())
+                .filter_map(|(v, k)| k.then_some(v))
+                .collect::<Vec<_>>();
+            let out = bm.apply_mask(&keep);
+            assert_eq!(out.len(), keep.set_bits());
+            assert_eq!(out.iter().collect::<Vec<_>>(), expected);
+            assert_eq!(out.unset_bits(), expected.iter().filter(|b| !**b).count());
+        }
+    }
+}
@@ -440,3 +440,74 @@ fn shrink_to_fit() {
     a.shrink_to_fit();
     assert!(a.capacity() < 1025);
 }
+
+#[test]
+fn set_range() {
+    let initial = (0..200).map(|i| i % 3 == 0).collect::<Vec<_>>();
+    for (start, len) in [
+        (0, 0),
+        (5, 0),
+        (0, 200),
+        (3, 4),
+        (8, 8),
+        (7, 2),
+        (60, 10),
+        (63, 66),
+        (64, 64),
+        (1, 198),
+        (199, 1),
+    ] {
+        for value in [true, false] {
+            let mut bm = MutableBitmap::from_iter(initial.iter().copied());
+            bm.set_range(start, len, value);
+
+            let mut expected = MutableBitmap::from_iter(initial.iter().copied());
+            for i in start..start + len {
+                expected.set(i, value);
+            }
+            assert_eq!(bm, expected, "start: {start}, len: {len}, value: {value}");
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn set_range_out_of_bounds() {
+    let mut bm = MutableBitmap::from_len_zeroed(70);
+    bm.set_range(65, 6, true);
+}
+
+#[test]
+fn extend_from_bitmap_range() {
+    let src = Bitmap::from_iter((0..300).map(|i| i % 3 == 0 || i % 7 == 1));
+    let sliced = src.clone().sliced(3, 290);
+    // (source, offset, len), appended one after another so that both the source offset and
+    // the length of the target are unaligned.
+    let ranges = [
+        (&src, 0, 5),
+        (&src, 13, 130),
+        (&sliced, 1, 0),
+        (&sliced, 7, 64),
+        (&src, 200, 3),
+        (&sliced, 100, 190),
+        (&src, 8, 72),
+    ];
+
+    let mut out = MutableBitmap::new();
+    let mut expected = vec![];
+    for (bm, offset, len) in ranges {
+        out.extend_from_bitmap_range(bm, offset, len);
+        expected.extend(bm.iter().skip(offset).take(len));
+        assert_eq!(out.len(), expected.len());
+    }
+    let out: Bitmap = out.into();
+    assert_eq!(out.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(out.unset_bits(), expected.iter().filter(|b| !**b).count());
+}
+
+#[test]
+#[should_panic]
+fn extend_from_bitmap_range_out_of_bounds() {
+    let src = Bitmap::new_zeroed(10);
+    MutableBitmap::new().extend_from_bitmap_range(&src, 5, 6);
+}
@@ -174,3 +174,113 @@ fn test_duration_date_arithmetic() -> PolarsResult<()> {
 fn assert_series_eq(s1: &Series, s2: &Series) {
     assert!(s1.equals(s2))
 }
+
+#[test]
+#[cfg(feature = "dtype-datetime")]
+fn test_datetime_compare_different_units() -> PolarsResult<()> {
+    let datetime = |tu: TimeUnit, values: &[i64]| {
+        Int64Chunked::new("dt".into(), values)
+            .into_datetime(tu, None)
+            .into_series()
+    };
+    let ms = datetime(TimeUnit::Milliseconds, &[1, 2, 3]);
+    let us = datetime(TimeUnit::Microseconds, &[1_000, 2_001, 2_999]);
+    let ns = datetime(TimeUnit::Nanoseconds, &[1_000_000, 1_999_999, 3_000_001]);
+
+    assert_eq!(
+        Vec::from(&ms.equal(&us)?),
+        &[Some(true), Some(false), Some(false)]
+    );
+    assert_eq!(
+        Vec::from(&us.lt(&ms)?),
+        &[Some(false), Some(false), Some(true)]
+    );
+    assert_eq!(
+        Vec::from(&ms.equal(&ns)?),
+        &[Some(true), Some(false), Some(false)]
+    );
+    assert_eq!(
+        Vec::from(&ns.gt(&us)?),
+        &[Some(false), Some(false), Some(true)]
+    );
+
+    // Upscaling to the finer unit must not silently overflow.
+    let big = datetime(TimeUnit::Milliseconds, &[i64::MAX / 10]);
+    let ns = datetime(TimeUnit::Nanoseconds, &[0]);
+    assert!(big.equal(&ns).is_err());
+
+    let utc = Int64Chunked::new("dt".into(), &[1, 2, 3])
+        .into_datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC))
+        .into_series();
+    assert!(utc.equal(&us).is_err());
+    assert!(utc.lt(&ms).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
+fn test_sum_of_instants() -> PolarsResult<()> {
+    let s = Series::new("t".into(), &[1i64, 2, 3])
+        .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+    let err = s.sum_reduce().unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    assert!(err.to_string().contains("points in time"), "{err}");
+    assert!(s.sum::<f64>().is_err());
+    assert!(s.cast(&DataType::Date)?.sum_reduce().is_err());
+
+    // The mean and the internal physical sum are still available.
+    assert_eq!(s.mean(), Some(2.0));
+    assert_eq!(s._sum_as_f64(), 6.0);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
+fn test_sort_sorted_datelike() -> PolarsResult<()> {
+    // The physical sort returns a clone if the sorted flag matches the requested order, so the
+    // values buffer is shared.
+    let values_ptr = |s: &Series| -> PolarsResult<usize> {
+        let physical = s.to_physical_repr().into_owned();
+        Ok(match physical.dtype() {
+            DataType::Int32 => physical
+                .i32()?
+                .downcast_iter()
+                .next()
+                .unwrap()
+                .values()
+                .as_ptr() as usize,
+            _ => physical
+                .i64()?
+                .downcast_iter()
+                .next()
+                .unwrap()
+                .values()
+                .as_ptr() as usize,
+        })
+    };
+
+    let datetime = Int64Chunked::new("dt".into(), &[1, 2, 3])
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+    let date = Int32Chunked::new("d".into(), &[1, 2, 3])
+        .into_date()
+        .into_series();
+    for mut s in [datetime, date] {
+        s.set_sorted_flag(IsSorted::Ascending);
+
+        let out = s.sort(SortOptions::default())?;
+        assert_eq!(out.dtype(), s.dtype());
+        assert_eq!(values_ptr(&out)?, values_ptr(&s)?);
+        assert_eq!(out.is_sorted_flag(), IsSorted::Ascending);
+
+        // A descending sort still reorders.
+        let out = s.sort(SortOptions::default().with_order_descending(true))?;
+        assert_eq!(out.dtype(), s.dtype());
+        assert_eq!(
+            Vec::from(out.to_physical_repr().cast(&DataType::Int64)?.i64()?),
+            &[Some(3), Some(2), Some(1)]
+        );
+        assert_eq!(out.is_sorted_flag(), IsSorted::Descending);
+    }
+    Ok(())
+}
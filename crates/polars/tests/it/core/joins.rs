@@ -74,6 +74,633 @@ fn test_inner_join() {
     }
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_with_build_table() -> PolarsResult<()> {
+    let (temp, rain) = create_frames();
+    let table = JoinBuildTable::new(&rain, ["days"], false)?;
+
+    let joined = temp.join_with_build_table(&table, ["days"], JoinType::Inner, None)?;
+    let expected = df![
+        "days" => [1, 1, 2],
+        "temp" => [19.9, 19.9, 7.],
+        "rain" => [0.1, 0.1, 0.3],
+        "rain_right" => [0.1, 0.4, 0.2],
+    ]?;
+    assert!(joined.equals(&expected));
+
+    // The same table can be probed again.
+    let probe = df![
+        "d" => [Some(3), None, Some(0)],
+        "x" => ["a", "b", "c"],
+    ]?;
+    let joined = probe.join_with_build_table(&table, ["d"], JoinType::Left, None)?;
+    let expected = df![
+        "d" => [Some(3), None, Some(0)],
+        "x" => ["a", "b", "c"],
+        "rain" => [Some(0.3), None, None],
+    ]?;
+    assert!(joined.equals_missing(&expected));
+
+    let probe = df!["days" => [1.0, 2.0]]?;
+    assert!(
+        probe
+            .join_with_build_table(&table, ["days"], JoinType::Inner, None)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_string_key_normalization() -> PolarsResult<()> {
+    let left = df![
+        "name" => [" alice", "Bob", "carol "],
+        "id" => [1, 2, 3],
+    ]?;
+    let right = df![
+        "name" => ["ALICE", "Bob ", "Dave"],
+        "score" => [10, 20, 30],
+    ]?;
+    let join = |normalization| {
+        left.join(
+            &right,
+            ["name"],
+            ["name"],
+            JoinArgs::new(JoinType::Left).with_string_key_normalization(normalization),
+            None,
+        )
+    };
+
+    // Without normalization nothing matches.
+    let out = join(None)?;
+    assert_eq!(out.column("score")?.null_count(), 3);
+
+    let out = join(Some(StringKeyNormalization::Trim))?;
+    assert_eq!(
+        Vec::from(out.column("score")?.i32()?),
+        &[None, Some(20), None]
+    );
+    let out = join(Some(StringKeyNormalization::Lowercase))?;
+    assert_eq!(Vec::from(out.column("score")?.i32()?), &[None, None, None]);
+    let out = join(Some(StringKeyNormalization::TrimLowercase))?;
+    assert_eq!(
+        Vec::from(out.column("score")?.i32()?),
+        &[Some(10), Some(20), None]
+    );
+    // The output keys are not normalized.
+    assert_eq!(
+        Vec::from(out.column("name")?.str()?),
+        &[Some(" alice"), Some("Bob"), Some("carol ")]
+    );
+
+    let args = |how| {
+        JoinArgs::new(how)
+            .with_string_key_normalization(Some(StringKeyNormalization::TrimLowercase))
+    };
+    // Both build sides of the inner join.
+    let out = left.join(&right, ["name"], ["name"], args(JoinType::Inner), None)?;
+    assert_eq!(Vec::from(out.column("id")?.i32()?), &[Some(1), Some(2)]);
+    let out = right
+        .head(Some(2))
+        .join(&left, ["name"], ["name"], args(JoinType::Inner), None)?;
+    assert_eq!(Vec::from(out.column("id")?.i32()?), &[Some(1), Some(2)]);
+    let out = left.join(&right, ["name"], ["name"], args(JoinType::Full), None)?;
+    assert_eq!(out.height(), 4);
+    assert_eq!(out.column("score")?.null_count(), 1);
+    #[cfg(feature = "semi_anti_join")]
+    {
+        let out = left.join(&right, ["name"], ["name"], args(JoinType::Semi), None)?;
+        assert_eq!(Vec::from(out.column("id")?.i32()?), &[Some(1), Some(2)]);
+        let out = left.join(&right, ["name"], ["name"], args(JoinType::Anti), None)?;
+        assert_eq!(Vec::from(out.column("id")?.i32()?), &[Some(3)]);
+    }
+
+    // Multiple keys are normalized as well.
+    let mut left_k = left.clone();
+    left_k.with_column(Column::new("k".into(), ["x", "y", "z"]))?;
+    let mut right_k = right.clone();
+    right_k.with_column(Column::new("k".into(), ["X ", "y", "z"]))?;
+    let out = left_k.join(
+        &right_k,
+        ["name", "k"],
+        ["name", "k"],
+        args(JoinType::Inner),
+        None,
+    )?;
+    assert_eq!(Vec::from(out.column("id")?.i32()?), &[Some(1), Some(2)]);
+
+    // Validation applies to the normalized keys.
+    let dup = df!["name" => ["bob", "BOB"], "id" => [1, 2]]?;
+    assert!(
+        dup.join(
+            &right,
+            ["name"],
+            ["name"],
+            JoinArgs {
+                validation: JoinValidation::OneToOne,
+                ..args(JoinType::Left)
+            },
+            None,
+        )
+        .is_err()
+    );
+
+    // Non-string keys are an error rather than silently joined on their raw values.
+    let err = left
+        .join(
+            &df!["id" => [1, 2], "x" => ["a", "b"]]?,
+            ["id"],
+            ["id"],
+            args(JoinType::Inner),
+            None,
+        )
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_right_unique() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+    let right = df![
+        "a" => [2, 1, 2, 1, 4],
+        "v" => [10, 20, 30, 40, 50],
+    ]?;
+    let join = |right_unique| {
+        let mut args = JoinArgs::new(JoinType::Left).with_right_unique(right_unique);
+        args.maintain_order = MaintainOrderJoin::Left;
+        left.join(&right, ["a"], ["a"], args, None)
+    };
+
+    // Without deduplication the left rows fan out.
+    assert_eq!(join(None)?.height(), 5);
+
+    let out = join(Some(RightUnique::FirstMatch))?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(20), Some(10), None]
+    );
+    let out = join(Some(RightUnique::LastMatch))?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(40), Some(30), None]
+    );
+    assert!(join(Some(RightUnique::Error)).is_err());
+
+    // Unique keys pass, also when combined over multiple columns.
+    let right = df![
+        "a" => [1, 1, 2],
+        "b" => ["x", "y", "y"],
+        "v" => [10, 20, 30],
+    ]?;
+    let out = left.join(
+        &right,
+        ["a", "b"],
+        ["a", "b"],
+        JoinArgs::new(JoinType::Inner).with_right_unique(Some(RightUnique::Error)),
+        None,
+    )?;
+    assert_eq!(out.height(), 2);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_full_join_hash_seed() -> PolarsResult<()> {
+    let left = df![
+        "k" => (0..50).map(|i| format!("l{i}")).collect::<Vec<_>>(),
+    ]?;
+    let right = df![
+        "k" => (0..200).map(|i| format!("r{i}")).collect::<Vec<_>>(),
+        "v" => (0..200).collect::<Vec<i32>>(),
+    ]?;
+    // None of the rows match, so the rows of the hashed side come out in hash table order.
+    let join = |right: &DataFrame, full_join_hash_seed| {
+        let args = JoinArgs::new(JoinType::Full).with_full_join_hash_seed(full_join_hash_seed);
+        left.join(right, ["k"], ["k"], args, None)
+    };
+    let mut rechunked = right.slice(0, 100);
+    rechunked.vstack_mut(&right.slice(100, 100))?;
+    assert_eq!(rechunked.first_col_n_chunks(), 2);
+
+    let out = join(&right, Some(42))?;
+    assert_eq!(out.height(), 250);
+    assert!(out.equals_missing(&join(&rechunked, Some(42))?));
+    assert!(!out.equals_missing(&join(&right, Some(7))?));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_case_insensitive_keys() -> PolarsResult<()> {
+    let left = df![
+        "key" => ["Foo", "straße", " baz"],
+        "id" => [1, 2, 3],
+    ]?;
+    let right = df![
+        "key" => ["foo", "STRASSE", "BAZ"],
+        "score" => [10, 20, 30],
+    ]?;
+    let args = JoinArgs::new(JoinType::Inner)
+        .with_coalesce(JoinCoalesce::KeepColumns)
+        .with_case_insensitive_keys(true);
+
+    let out = left
+        .join(&right, ["key"], ["key"], args.clone(), None)?
+        .sort(["id"], Default::default())?;
+    // Both original spellings are kept.
+    assert_eq!(
+        Vec::from(out.column("key")?.str()?),
+        &[Some("Foo"), Some("straße")]
+    );
+    assert_eq!(
+        Vec::from(out.column("key_right")?.str()?),
+        &[Some("foo"), Some("STRASSE")]
+    );
+
+    // Lowercasing is not case folding: "ß" does not match "SS".
+    let out = left.join(
+        &right,
+        ["key"],
+        ["key"],
+        JoinArgs::new(JoinType::Inner)
+            .with_string_key_normalization(Some(StringKeyNormalization::Lowercase)),
+        None,
+    )?;
+    assert_eq!(out.height(), 1);
+
+    // Combines with the other string key normalizations.
+    let out = left.join(
+        &right,
+        ["key"],
+        ["key"],
+        args.with_string_key_normalization(Some(StringKeyNormalization::Trim)),
+        None,
+    )?;
+    assert_eq!(out.height(), 3);
+
+    // A coalesced key takes the value of the left side.
+    let out = left.join(
+        &right,
+        ["key"],
+        ["key"],
+        JoinArgs::new(JoinType::Left).with_case_insensitive_keys(true),
+        None,
+    )?;
+    assert_eq!(
+        Vec::from(out.column("key")?.str()?),
+        &[Some("Foo"), Some("straße"), Some(" baz")]
+    );
+    assert_eq!(
+        Vec::from(out.column("score")?.i32()?),
+        &[Some(10), Some(20), None]
+    );
+
+    let err = left
+        .join(
+            &df!["id" => [1, 2], "x" => ["a", "b"]]?,
+            ["id"],
+            ["id"],
+            JoinArgs::new(JoinType::Inner).with_case_insensitive_keys(true),
+            None,
+        )
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_drop_keys() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+    let right = df![
+        "a" => [2, 3, 4],
+        "c" => [20, 30, 40],
+    ]?;
+    let names = |df: &DataFrame| {
+        df.get_column_names()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    for how in [
+        JoinType::Inner,
+        JoinType::Left,
+        JoinType::Right,
+        JoinType::Full,
+    ] {
+        for coalesce in [JoinCoalesce::CoalesceColumns, JoinCoalesce::KeepColumns] {
+            let args = JoinArgs::new(how.clone()).with_coalesce(coalesce);
+            let expected = left
+                .join(&right, ["a"], ["a"], args.clone(), None)?
+                .drop_many(["a", "a_right"]);
+            let out = left.join(&right, ["a"], ["a"], args.with_drop_keys(true), None)?;
+            assert_eq!(names(&out), ["b", "c"]);
+            assert!(out.equals_missing(&expected));
+        }
+    }
+
+    // A right key that isn't named like a left column is not suffixed.
+    let mut right = right;
+    right.rename("a", "k".into())?;
+    let args = JoinArgs::new(JoinType::Inner)
+        .with_coalesce(JoinCoalesce::KeepColumns)
+        .with_drop_keys(true);
+    let out = left.join(&right, ["a"], ["k"], args, None)?;
+    assert_eq!(names(&out), ["b", "c"]);
+    assert_eq!(out.column("b")?.str()?.get(0), Some("y"));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_full_join_coalesce_null_keys() -> PolarsResult<()> {
+    let left = df![
+        "a" => [Some(1), None, Some(3)],
+        "x" => [10, 0, 30],
+    ]?;
+    let right = df![
+        "a" => [Some(3), None, Some(4)],
+        "y" => [30, 0, 40],
+    ]?;
+    let join = |nulls_equal| -> PolarsResult<Vec<Option<i32>>> {
+        let args = JoinArgs {
+            nulls_equal,
+            ..JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns)
+        };
+        let out = left.join(&right, ["a"], ["a"], args, None)?.sort(
+            ["x", "y"],
+            SortMultipleOptions::default().with_nulls_last(true),
+        )?;
+        assert_eq!(out.width(), 3);
+        Ok(Vec::from(out.column("a")?.i32()?))
+    };
+
+    // Rows that only exist on the right take the key of the right side, null keys stay null.
+    assert_eq!(join(false)?, [None, Some(1), Some(3), None, Some(4)]);
+    // Matching null keys coalesce to null.
+    assert_eq!(join(true)?, [None, Some(1), Some(3), Some(4)]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_list_keys() -> PolarsResult<()> {
+    let tags = |values: &[Option<&[Option<i64>]>]| {
+        let values = values
+            .iter()
+            .map(|v| v.map(|v| Series::new(PlSmallStr::EMPTY, v)))
+            .collect::<Vec<_>>();
+        Series::new("tags".into(), values).into_column()
+    };
+    let left = DataFrame::new_infer_height(vec![
+        tags(&[
+            Some(&[Some(1), Some(2)]),
+            Some(&[Some(2), Some(1)]),
+            Some(&[Some(1), None]),
+            None,
+            Some(&[]),
+        ]),
+        Column::new("x".into(), [0, 1, 2, 3, 4]),
+    ])?;
+    let right = DataFrame::new_infer_height(vec![
+        tags(&[
+            Some(&[Some(1), Some(2)]),
+            Some(&[Some(1), None]),
+            None,
+            Some(&[]),
+        ]),
+        Column::new("y".into(), [10, 20, 30, 40]),
+    ])?;
+    let join = |nulls_equal| -> PolarsResult<(Vec<Option<i32>>, Vec<Option<i32>>)> {
+        let args = JoinArgs {
+            nulls_equal,
+            ..JoinArgs::new(JoinType::Inner)
+        };
+        let out = left
+            .join(&right, ["tags"], ["tags"], args, None)?
+            .sort(["x"], Default::default())?;
+        Ok((
+            Vec::from(out.column("x")?.i32()?),
+            Vec::from(out.column("y")?.i32()?),
+        ))
+    };
+
+    // Keys match element-wise in order, null elements are equal to each other.
+    let (x, y) = join(false)?;
+    assert_eq!(x, [Some(0), Some(2), Some(4)]);
+    assert_eq!(y, [Some(10), Some(20), Some(40)]);
+    // Null keys only match with `nulls_equal`.
+    let (x, y) = join(true)?;
+    assert_eq!(x, [Some(0), Some(2), Some(3), Some(4)]);
+    assert_eq!(y, [Some(10), Some(20), Some(30), Some(40)]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_right_select() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "c" => ["x", "y", "z"],
+    ]?;
+    let right = df![
+        "a" => [2, 3, 4],
+        "b" => [20, 30, 40],
+        "c" => ["p", "q", "r"],
+        "d" => [true, false, true],
+    ]?;
+    let names = |df: &DataFrame| {
+        df.get_column_names()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>()
+    };
+    let select = |names: &[&str]| -> Option<Vec<PlSmallStr>> {
+        Some(names.iter().map(|&name| name.into()).collect())
+    };
+
+    let args = JoinArgs::new(JoinType::Left).with_right_select(select(&["c"]));
+    let out = left.join(&right, ["a"], ["a"], args, None)?;
+    assert_eq!(names(&out), ["a", "c", "c_right"]);
+    assert_eq!(
+        Vec::from(out.column("c_right")?.str()?),
+        &[None, Some("p"), Some("q")]
+    );
+
+    // Uncoalesced key columns are kept.
+    let args = JoinArgs::new(JoinType::Inner)
+        .with_coalesce(JoinCoalesce::KeepColumns)
+        .with_right_select(select(&["d"]));
+    let out = left.join(&right, ["a"], ["a"], args, None)?;
+    assert_eq!(names(&out), ["a", "c", "a_right", "d"]);
+
+    // Selecting nothing only keeps the keys.
+    let args = JoinArgs::new(JoinType::Full).with_right_select(select(&[]));
+    let out = left.join(&right, ["a"], ["a"], args, None)?;
+    assert_eq!(names(&out), ["a", "c", "a_right"]);
+
+    let args = JoinArgs::new(JoinType::Inner).with_right_select(select(&["e"]));
+    let err = left.join(&right, ["a"], ["a"], args, None).unwrap_err();
+    assert!(err.to_string().contains("`right_select` column \"e\""));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_require_sorted_keys() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+    let mut right = df![
+        "a" => [2, 3, 4],
+        "c" => [20, 30, 40],
+    ]?;
+    let join = |left: &DataFrame, right: &DataFrame, verify_values: bool| {
+        let args = JoinArgs {
+            validation: JoinValidation::RequireSortedKeys { verify_values },
+            ..JoinArgs::new(JoinType::Inner)
+        };
+        left.join(right, ["a"], ["a"], args, None)
+    };
+
+    // Flag-only: sorted values without a sorted flag are rejected.
+    let err = join(&left, &right, false).unwrap_err();
+    assert!(err.to_string().contains("join key `a` on the left side"));
+    assert!(err.to_string().contains("not flagged as sorted"));
+
+    // Verify-values: the actual order is checked.
+    assert_eq!(join(&left, &right, true)?.height(), 2);
+
+    // Flag-only: both sides flagged as sorted.
+    let left = left.sort(["a"], Default::default())?;
+    right = right.sort(
+        ["a"],
+        SortMultipleOptions::default().with_order_descending(true),
+    )?;
+    assert_eq!(join(&left, &right, false)?.height(), 2);
+
+    // Verify-values: unsorted values are rejected.
+    let left = df!["a" => [3, 1, 2]]?;
+    let err = join(&left, &right, true).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("did not fulfill sorted validation")
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_full_join_indicator() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+    let right = df![
+        "a" => [2, 3, 4],
+        "b" => ["y", "q", "w"],
+    ]?;
+    let indicator = |df: &DataFrame| -> PolarsResult<Vec<Option<String>>> {
+        let s = df.column("_merge")?.cast(&DataType::String)?;
+        Ok(s.str()?.iter().map(|v| v.map(String::from)).collect())
+    };
+    let expected = |values: &[&str]| {
+        values
+            .iter()
+            .map(|v| Some(v.to_string()))
+            .collect::<Vec<_>>()
+    };
+
+    let mut args = JoinArgs::new(JoinType::Full)
+        .with_indicator(Some("_merge".into()))
+        .with_coalesce(JoinCoalesce::CoalesceColumns);
+    args.maintain_order = MaintainOrderJoin::LeftRight;
+
+    let out = left.join(&right, ["a"], ["a"], args.clone(), None)?;
+    assert_eq!(out.get_column_names(), &["a", "b", "b_right", "_merge"]);
+    assert_eq!(out.column("_merge")?.dtype(), &join_indicator_dtype());
+    assert_eq!(
+        indicator(&out)?,
+        expected(&["left_only", "both", "both", "right_only"])
+    );
+
+    // Multiple keys.
+    let out = left.join(&right, ["a", "b"], ["a", "b"], args.clone(), None)?;
+    assert_eq!(
+        indicator(&out)?,
+        expected(&["left_only", "both", "left_only", "right_only", "right_only"])
+    );
+
+    // Only full joins support an indicator column.
+    let args = JoinArgs::new(JoinType::Left).with_indicator(Some("_merge".into()));
+    assert!(left.join(&right, ["a"], ["a"], args, None).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "performant")]
+#[cfg_attr(miri, ignore)]
+fn test_sorted_merge_join_matches_hash_join() -> PolarsResult<()> {
+    let left = df![
+        "k" => [8, 1, 3, 5, 1, 2],
+        "l" => ["a", "b", "c", "d", "e", "f"],
+    ]?;
+    let right = df![
+        "k" => [10, 2, 0, 5, 9, 1, 2],
+        "r" => [1, 2, 3, 4, 5, 6, 7],
+    ]?;
+    let sort_opts = SortMultipleOptions::default().with_maintain_order(true);
+
+    // Sorting sets the sorted flag on the keys, which selects the sorted merge join.
+    let sorted = |df: &DataFrame| df.sort(["k"], sort_opts.clone());
+    let (left_sorted, right_sorted) = (sorted(&left)?, sorted(&right)?);
+    assert_eq!(
+        left_sorted.column("k")?.is_sorted_flag(),
+        IsSorted::Ascending
+    );
+    // Taking in sorted order doesn't set the flag, so these take the hash join path.
+    let unflagged = |df: &DataFrame| {
+        let idx = df
+            .column("k")?
+            .as_materialized_series()
+            .arg_sort(Default::default());
+        df.take(&idx)
+    };
+    let (left_hash, right_hash) = (unflagged(&left)?, unflagged(&right)?);
+    assert_eq!(left_hash.column("k")?.is_sorted_flag(), IsSorted::Not);
+
+    let cols = ["k", "l", "r"];
+    for how in [JoinType::Inner, JoinType::Left] {
+        let hash = left_hash.join(&right_hash, ["k"], ["k"], how.clone().into(), None)?;
+        let hash = hash.sort(cols, sort_opts.clone())?;
+        // With only one side sorted the other side may be sorted for the merge.
+        for (l, r) in [
+            (&left_sorted, &right_sorted),
+            (&left_sorted, &right),
+            (&left, &right_sorted),
+        ] {
+            let merge = l.join(r, ["k"], ["k"], how.clone().into(), None)?;
+            assert!(
+                merge.sort(cols, sort_opts.clone())?.equals_missing(&hash),
+                "{how:?}"
+            );
+        }
+    }
+
+    Ok(())
+}
+
 #[test]
 #[allow(clippy::float_cmp)]
 #[cfg_attr(miri, ignore)]
@@ -625,6 +625,42 @@ fn test_skip_rows() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_skip_lines_and_skip_rows() -> PolarsResult<()> {
+    // The preamble isn't valid CSV, so it has to be skipped by lines before skipping rows.
+    let csv = r#"preamble with "unbalanced quote
+second preamble line
+dropped,row
+a,b
+1,2
+3,4
+"#;
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .with_has_header(true)
+        .with_skip_lines(2)
+        .with_skip_rows(1)
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(df.get_column_names(), &["a", "b"]);
+    assert_eq!(df.height(), 2);
+    assert_eq!(df.column("a")?.i64()?.get(0), Some(1));
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .with_has_header(false)
+        .with_skip_lines(2)
+        .with_skip_rows(2)
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(df.shape(), (2, 2));
+    assert_eq!(df.column("column_1")?.i64()?.get(1), Some(3));
+    Ok(())
+}
+
 #[test]
 fn test_projection_idx() -> PolarsResult<()> {
     let csv = r"#0 NA 0 0 57 0
@@ -793,6 +829,51 @@ fn test_automatic_datetime_parsing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "temporal", feature = "dtype-time"))]
+fn test_automatic_time_parsing_sub_second() -> PolarsResult<()> {
+    let csv = r"t_us,t_mixed
+12:34:56.789123,12:34
+00:00:00.000001,12:34:56.789123
+23:59:59.999999,
+";
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_try_parse_dates(true))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    let time = |h, m, s, us| chrono::NaiveTime::from_hms_micro_opt(h, m, s, us);
+    let t_us = df.column("t_us")?;
+    assert_eq!(t_us.dtype(), &DataType::Time);
+    assert_eq!(
+        t_us.as_materialized_series()
+            .time()?
+            .as_time_iter()
+            .collect::<Vec<_>>(),
+        [
+            time(12, 34, 56, 789_123),
+            time(0, 0, 0, 1),
+            time(23, 59, 59, 999_999)
+        ]
+    );
+
+    // The format is sniffed from `12:34`, the fractional seconds of later values are kept.
+    let t_mixed = df.column("t_mixed")?;
+    assert_eq!(t_mixed.dtype(), &DataType::Time);
+    assert_eq!(
+        t_mixed
+            .as_materialized_series()
+            .time()?
+            .as_time_iter()
+            .collect::<Vec<_>>(),
+        [time(12, 34, 0, 0), time(12, 34, 56, 789_123), None]
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "temporal")]
 fn test_automatic_datetime_parsing_default_formats() -> PolarsResult<()> {
@@ -965,6 +1046,37 @@ fn test_infer_schema_0_rows() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_infer_schema_0_rows_header_only() -> PolarsResult<()> {
+    let csv = r#"a,b,c
+skipped,row,here
+1,x,1.5
+2,y,2.5
+"#;
+    let df = CsvReadOptions::default()
+        .with_infer_schema_length(Some(0))
+        .with_skip_rows_after_header(1)
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    let expected = df![
+        "a" => ["1", "2"],
+        "b" => ["x", "y"],
+        "c" => ["1.5", "2.5"],
+    ]?;
+    assert!(df.equals(&expected));
+
+    // Without a header, a data row is still needed to determine the number of columns.
+    let df = CsvReadOptions::default()
+        .with_has_header(false)
+        .with_infer_schema_length(Some(0))
+        .into_reader_with_file_handle(Cursor::new("1,x\n2,y\n"))
+        .finish()?;
+    assert_eq!(df.shape(), (2, 2));
+    assert_eq!(df.dtypes(), &[DataType::String, DataType::String]);
+
+    Ok(())
+}
+
 #[test]
 fn test_infer_schema_eol() -> PolarsResult<()> {
     // no eol after header
@@ -1367,6 +1479,25 @@ fn test_try_parse_dates_3380() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_try_parse_dates_columns() -> PolarsResult<()> {
+    let csv = "a,b,c
+2024-01-02,2024-01-03,20240101
+2024-02-02,2024-02-03,20240201
+";
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_try_parse_dates_columns(["a"]))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Date, DataType::String, DataType::Int64]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_leading_whitespace_with_quote() -> PolarsResult<()> {
     let csv = r#"
@@ -1398,3 +1529,458 @@ fn test_single_column_leading_empty_line_25166() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "json")]
+fn test_infer_json_columns() -> PolarsResult<()> {
+    let csv = r#"id,payload
+1,"{""a"":1}"
+2,"{""a"":2}"
+"#;
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_infer_json_columns(true))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(
+        df.column("payload")?.dtype(),
+        &DataType::Struct(vec![Field::new("a".into(), DataType::Int64)])
+    );
+    let a = df.column("payload")?.struct_()?.field_by_name("a")?;
+    assert_eq!(
+        a.i64()?.into_iter().collect::<Vec<_>>(),
+        &[Some(1), Some(2)]
+    );
+
+    // Objects and arrays cannot be merged, the column stays a String.
+    let csv = r#"id,payload
+1,"{""a"":1}"
+2,"[1,2]"
+"#;
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_infer_json_columns(true))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(df.column("payload")?.dtype(), &DataType::String);
+    assert_eq!(df.column("payload")?.str()?.get(1), Some("[1,2]"));
+    Ok(())
+}
+
+#[test]
+fn test_infer_escaped_quoted_fields() -> PolarsResult<()> {
+    let csv = r#""a""b",date,quoted_date,path
+1,"2021-01-01","""2021-01-02""","C:\dir"
+2,"2021-01-03","""2021-01-04""","C:\""dir"""
+"#;
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_try_parse_dates(true))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(
+        df.get_column_names(),
+        &[r#"a"b"#, "date", "quoted_date", "path"]
+    );
+    assert_eq!(df.column("date")?.dtype(), &DataType::Date);
+    // Escaped quotes are part of the value, so the field isn't a date.
+    assert_eq!(df.column("quoted_date")?.dtype(), &DataType::String);
+    assert_eq!(
+        df.column("quoted_date")?.str()?.get(0),
+        Some(r#""2021-01-02""#)
+    );
+    // Backslashes don't escape the quote char.
+    assert_eq!(df.column("path")?.dtype(), &DataType::String);
+    assert_eq!(
+        df.column("path")?.str()?.into_iter().collect::<Vec<_>>(),
+        &[Some(r"C:\dir"), Some(r#"C:\"dir""#)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn test_date_formats() -> PolarsResult<()> {
+    let csv = "date,datetime\n01/02/2021,01/02/2021 10.30\n02/03/2021,2021-03-02 10:30:00\n";
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options
+                .with_try_parse_dates(true)
+                .with_date_formats(["%d/%m/%Y", "%d/%m/%Y %H.%M"])
+        })
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    // The user supplied day-first format wins over the built-in patterns.
+    let date = df.column("date")?;
+    assert_eq!(date.dtype(), &DataType::Date);
+    assert_eq!(
+        Vec::from(date.date()?.physical()),
+        &[Some(18659), Some(18688)]
+    );
+
+    // Values that don't match any user format fall back to the built-in patterns.
+    let datetime = df.column("datetime")?;
+    assert_eq!(
+        datetime.dtype(),
+        &DataType::Datetime(TimeUnit::Microseconds, None)
+    );
+    assert_eq!(
+        Vec::from(datetime.datetime()?.physical()),
+        &[Some(1_612_175_400_000_000), Some(1_614_681_000_000_000)]
+    );
+
+    // The formats only apply to columns where dates are parsed, also when reading a column as
+    // a datetime through the schema.
+    let read = |parse_dates_columns: &[&str]| {
+        let file = Cursor::new("datetime\n01/02/2021 10.30\n");
+        CsvReadOptions::default()
+            .with_ignore_errors(true)
+            .with_schema_overwrite(Some(Arc::new(Schema::from_iter([Field::new(
+                "datetime".into(),
+                DataType::Datetime(TimeUnit::Microseconds, None),
+            )]))))
+            .map_parse_options(|parse_options| {
+                parse_options
+                    .with_try_parse_dates_columns(parse_dates_columns.iter().copied())
+                    .with_date_formats(["%d/%m/%Y %H.%M"])
+            })
+            .into_reader_with_file_handle(file)
+            .finish()
+    };
+    let datetime = read(&[])?.column("datetime")?.datetime()?.physical().get(0);
+    assert_eq!(datetime, None);
+    let datetime = read(&["datetime"])?
+        .column("datetime")?
+        .datetime()?
+        .physical()
+        .get(0);
+    assert_eq!(datetime, Some(1_612_175_400_000_000));
+    Ok(())
+}
+
+#[test]
+fn test_type_confidence() -> PolarsResult<()> {
+    let csv = "a\n1\n2\n3\n4\n5\n6\n7\n8\n9\nn/a\n";
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    assert_eq!(df.column("a")?.dtype(), &DataType::String);
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .with_ignore_errors(true)
+        .map_parse_options(|parse_options| parse_options.with_type_confidence(Some(0.9)))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    assert_eq!(df.column("a")?.dtype(), &DataType::Int64);
+    assert_eq!(df.column("a")?.null_count(), 1);
+
+    let file = Cursor::new(csv);
+    let result = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_type_confidence(Some(1.5)))
+        .into_reader_with_file_handle(file)
+        .finish();
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_duplicate_header_template() -> PolarsResult<()> {
+    let csv = "a,b,a,a\n1,2,3,4\n";
+
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options.with_duplicate_header_template(Some("{name} ({n})"))
+        })
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["a", "b", "a (2)", "a (3)"]);
+
+    // A template without `{n}` collides, so the default names are used.
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options.with_duplicate_header_template(Some("{name}_copy"))
+        })
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(
+        df.get_column_names(),
+        &["a", "b", "a_duplicated_0", "a_duplicated_1"]
+    );
+
+    // Names that clash with another header also fall back.
+    let csv = "a,a,a (2)\n1,2,3\n";
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options.with_duplicate_header_template(Some("{name} ({n})"))
+        })
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["a", "a_duplicated_0", "a (2)"]);
+    Ok(())
+}
+
+#[test]
+fn test_integral_floats_as_int() -> PolarsResult<()> {
+    let csv = "a,b\n1,1.0\n2.0,2.5\n\"3.00\",3\n";
+
+    let df = CsvReadOptions::default()
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.column("a")?.dtype(), &DataType::Float64);
+
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_integral_floats_as_int(true))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    let a = df.column("a")?;
+    assert_eq!(a.dtype(), &DataType::Int64);
+    assert_eq!(Vec::from(a.i64()?), &[Some(1), Some(2), Some(3)]);
+    // A single fractional value keeps the column a float.
+    assert_eq!(df.column("b")?.dtype(), &DataType::Float64);
+    Ok(())
+}
+
+#[test]
+fn test_large_ints_as_float() -> PolarsResult<()> {
+    let csv = "a,b\n1234567890123456789012345,1\n-2,9223372036854775807\n";
+
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_large_ints_as_float(true))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.dtypes(), &[DataType::Float64, DataType::Int64]);
+    assert_eq!(
+        Vec::from(df.column("a")?.f64()?),
+        &[Some(1.2345678901234568e24), Some(-2.0)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_trim_fields_for_inference() -> PolarsResult<()> {
+    let csv = "id,amount,name\n   1,    10, ab \n   2,      ,cd\n  30,  -400,\" e \"\n";
+
+    let df = CsvReadOptions::default()
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.column("amount")?.dtype(), &DataType::String);
+
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_trim_fields_for_inference(true))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Int64, DataType::Int64, DataType::String]
+    );
+    assert_eq!(
+        Vec::from(df.column("id")?.i64()?),
+        &[Some(1), Some(2), Some(30)]
+    );
+    assert_eq!(
+        Vec::from(df.column("amount")?.i64()?),
+        &[Some(10), None, Some(-400)]
+    );
+    // Quoted fields are kept as is.
+    assert_eq!(
+        Vec::from(df.column("name")?.str()?),
+        &[Some("ab"), Some("cd"), Some(" e ")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_multiline_quoted_field() -> PolarsResult<()> {
+    let csv = "id,note,value\r\n1,\"first\r\nsecond\",1.5\r\n2,\"a,\nb\",2.5\r\n";
+
+    let df = CsvReadOptions::default()
+        .with_infer_schema_length(Some(2))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Int64, DataType::String, DataType::Float64]
+    );
+    assert_eq!(
+        Vec::from(df.column("note")?.str()?),
+        &[Some("first\r\nsecond"), Some("a,\nb")]
+    );
+    assert_eq!(
+        Vec::from(df.column("value")?.f64()?),
+        &[Some(1.5), Some(2.5)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_max_inferred_columns() -> PolarsResult<()> {
+    // A tab-delimited file with comma-separated lists in the second column.
+    let csv = "1\ta,b,c,d,e,f\n2\tg,h\n";
+    let read = |separator| {
+        CsvReadOptions::default()
+            .with_has_header(false)
+            .with_max_inferred_columns(Some(4))
+            .map_parse_options(|parse_options| parse_options.with_separator(separator))
+            .into_reader_with_file_handle(Cursor::new(csv))
+            .finish()
+    };
+
+    let err = read(b',').unwrap_err().to_string();
+    assert!(err.contains("the data row has 6 fields"), "{err}");
+    assert!(err.contains("is the separator ',' correct?"), "{err}");
+    assert_eq!(read(b'\t')?.shape(), (2, 2));
+
+    let csv = "id\ta,b,c,d,e\n1\tx\n";
+    let err = CsvReadOptions::default()
+        .with_max_inferred_columns(Some(4))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()
+        .unwrap_err();
+    assert!(err.to_string().contains("the header has 5 fields"), "{err}");
+    Ok(())
+}
+
+#[test]
+fn test_types_row() -> PolarsResult<()> {
+    // A header followed by Frictionless-style type names.
+    let csv = "id,score,label,extra\ninteger,number,string,geopoint\n1,2,3,4\n2,2.5,x,5\n";
+    let read = |options: CsvReadOptions| {
+        options
+            .with_types_row(true)
+            .into_reader_with_file_handle(Cursor::new(csv))
+            .finish()
+    };
+
+    let df = read(CsvReadOptions::default())?;
+    assert_eq!(df.shape(), (2, 4));
+    // `label` would be inferred as `String` anyway, the unknown `geopoint` falls back to
+    // inference.
+    assert_eq!(
+        df.dtypes(),
+        &[
+            DataType::Int64,
+            DataType::Float64,
+            DataType::String,
+            DataType::Int64
+        ]
+    );
+    assert_eq!(
+        Vec::from(df.column("score")?.f64()?),
+        &[Some(2.0), Some(2.5)]
+    );
+
+    // The declared dtype is used even if the data would be inferred differently.
+    let df = read(CsvReadOptions::default().with_n_rows(Some(1)))?;
+    assert_eq!(df.column("label")?.dtype(), &DataType::String);
+    assert_eq!(df.column("label")?.str()?.get(0), Some("3"));
+
+    // An explicit override takes precedence.
+    let df = read(
+        CsvReadOptions::default().with_schema_overwrite(Some(Arc::new(Schema::from_iter([
+            Field::new("id".into(), DataType::String),
+        ])))),
+    )?;
+    assert_eq!(df.column("id")?.dtype(), &DataType::String);
+
+    assert!(read(CsvReadOptions::default().with_has_header(false)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_infer_schema_n_rows() -> PolarsResult<()> {
+    let csv = "a,b\n1,x\n2,y\n3.5,z\n";
+    let read = |n_rows| {
+        CsvReadOptions::default()
+            .with_n_rows(n_rows)
+            .into_reader_with_file_handle(Cursor::new(csv))
+            .finish()
+    };
+
+    // The float past `n_rows` doesn't affect the inferred type.
+    let df = read(Some(2))?;
+    assert_eq!(df.shape(), (2, 2));
+    assert_eq!(df.dtypes(), &[DataType::Int64, DataType::String]);
+
+    let df = read(None)?;
+    assert_eq!(df.dtypes(), &[DataType::Float64, DataType::String]);
+
+    // Inference still sees a row if none are read.
+    let df = read(Some(0))?;
+    assert_eq!(df.shape(), (0, 2));
+    assert_eq!(df.dtypes(), &[DataType::Int64, DataType::String]);
+    Ok(())
+}
+
+#[test]
+fn test_float_special_values() -> PolarsResult<()> {
+    let csv = "a,b\n1.5,1.5\nNaN,nan\ninf,-Infinity\n";
+
+    // `NaN` and `inf` are always recognized, other spellings only with `float_special_values`.
+    let df = CsvReadOptions::default()
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.dtypes(), &[DataType::Float64, DataType::String]);
+
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_float_special_values(true))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.dtypes(), &[DataType::Float64, DataType::Float64]);
+    let b = df.column("b")?.f64()?;
+    assert_eq!(b.get(0), Some(1.5));
+    assert!(b.get(1).unwrap().is_nan());
+    assert_eq!(b.get(2), Some(f64::NEG_INFINITY));
+    Ok(())
+}
+
+#[test]
+fn test_field_type_inferer() -> PolarsResult<()> {
+    // Digits with a leading zero are codes rather than numbers.
+    struct ZeroPaddedCodes;
+    impl FieldTypeInferer for ZeroPaddedCodes {
+        fn infer(&self, value: &str) -> Option<DataType> {
+            (value.len() > 1 && value.starts_with('0') && value.bytes().all(|b| b.is_ascii_digit()))
+                .then_some(DataType::String)
+        }
+    }
+
+    let csv = "zip,n\n98765,1\n01234,2\n00501,3\n";
+    let df = CsvReadOptions::default()
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.dtypes(), &[DataType::Int64, DataType::Int64]);
+
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options.with_field_type_inferer(Some(Arc::new(ZeroPaddedCodes)))
+        })
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.dtypes(), &[DataType::String, DataType::Int64]);
+    assert_eq!(
+        Vec::from(df.column("zip")?.str()?),
+        &[Some("98765"), Some("01234"), Some("00501")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_fixed_width_hint_keeps_ragged_reads() -> PolarsResult<()> {
+    // The uneven field counts only warn, the short line is filled with nulls.
+    let csv = "a b c\n1 2 3\n4\n";
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_separator(b' '))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.shape(), (2, 3));
+    assert_eq!(df.column("c")?.null_count(), 1);
+    Ok(())
+}
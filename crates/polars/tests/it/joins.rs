@@ -56,3 +56,205 @@ fn join_empty_datasets() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "lazy")]
+fn join_drop_keys() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?
+    .lazy();
+    let right = df![
+        "k" => [2, 4, 6],
+        "c" => [20, 40, 60],
+    ]?
+    .lazy();
+
+    let out = left
+        .clone()
+        .join_builder()
+        .with(right.clone())
+        .left_on([col("a")])
+        .right_on([col("k")])
+        .how(JoinType::Inner)
+        .drop_keys(true)
+        .finish()
+        .collect()?;
+    let expected = df![
+        "b" => ["y"],
+        "c" => [20],
+    ]?;
+    assert!(out.equals(&expected));
+
+    // The left key is an expression, so only the right key column is dropped.
+    let out = left
+        .clone()
+        .join_builder()
+        .with(right.clone())
+        .left_on([col("a") * lit(2)])
+        .right_on([col("k")])
+        .how(JoinType::Left)
+        .maintain_order(MaintainOrderJoin::Left)
+        .drop_keys(true)
+        .finish()
+        .collect()?;
+    let expected = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+        "c" => [20, 40, 60],
+    ]?;
+    assert!(out.equals(&expected));
+
+    // Scalar keys are materialized as temporary columns, which never show up in the output.
+    let out = left
+        .join_builder()
+        .with(right)
+        .left_on([col("a"), lit(1)])
+        .right_on([col("k"), lit(1)])
+        .how(JoinType::Inner)
+        .drop_keys(true)
+        .finish();
+    assert_eq!(
+        out.collect_schema()?
+            .iter_names()
+            .cloned()
+            .collect::<Vec<_>>(),
+        ["b", "c"]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn join_right_select() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?
+    .lazy();
+    let right = df![
+        "k" => [2, 3, 4],
+        "c" => [20, 30, 40],
+        "d" => [true, false, true],
+        "e" => ["p", "q", "r"],
+    ]?
+    .lazy();
+    let join = |right_select: Vec<&str>| {
+        left.clone()
+            .join_builder()
+            .with(right.clone())
+            .left_on([col("a")])
+            .right_on([col("k") + lit(0)])
+            .how(JoinType::Inner)
+            .maintain_order(MaintainOrderJoin::Left)
+            .right_select(Some(right_select.into_iter().map(Into::into).collect()))
+            .finish()
+    };
+
+    // The key column is kept, since the key expression refers to it.
+    let out = join(vec!["e"]);
+    assert_eq!(
+        out.clone()
+            .collect_schema()?
+            .iter_names()
+            .cloned()
+            .collect::<Vec<_>>(),
+        ["a", "b", "k", "e"]
+    );
+    let expected = df![
+        "a" => [2, 3],
+        "b" => ["y", "z"],
+        "k" => [2, 3],
+        "e" => ["p", "q"],
+    ]?;
+    assert!(out.collect()?.equals(&expected));
+
+    assert!(join(vec!["f"]).collect().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn join_list_keys_upcast() -> PolarsResult<()> {
+    let tags = |values: [&[i64]; 3]| {
+        let values = values
+            .iter()
+            .map(|&v| Series::new(PlSmallStr::EMPTY, v))
+            .collect::<Vec<_>>();
+        Series::new("tags".into(), values).into_column()
+    };
+    let left = DataFrame::new_infer_height(vec![
+        tags([&[1, 2], &[3], &[4, 5]]),
+        Column::new("x".into(), [0, 1, 2]),
+    ])?
+    .lazy()
+    .with_column(col("tags").cast(DataType::List(Box::new(DataType::Int32))));
+    let right = DataFrame::new_infer_height(vec![
+        tags([&[4, 5], &[2, 1], &[1, 2]]),
+        Column::new("y".into(), [10, 20, 30]),
+    ])?
+    .lazy();
+
+    // The inner types of the keys are upcast to a common type.
+    let out = left
+        .join(
+            right,
+            [col("tags")],
+            [col("tags")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .sort(["x"], Default::default())
+        .collect()?;
+    assert_eq!(
+        out.column("tags")?.dtype(),
+        &DataType::List(Box::new(DataType::Int64))
+    );
+    assert_eq!(Vec::from(out.column("x")?.i32()?), [Some(0), Some(2)]);
+    assert_eq!(Vec::from(out.column("y")?.i32()?), [Some(30), Some(10)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn join_with_build_table() -> PolarsResult<()> {
+    let dim = df![
+        "id" => [1, 2, 3],
+        "name" => ["a", "b", "c"],
+    ]?;
+    let table = Arc::new(JoinBuildTable::new(&dim, ["id"], false)?);
+
+    let facts = df![
+        "dim_id" => [3, 1, 4],
+        "name" => ["x", "y", "z"],
+    ]?;
+    let lf = facts
+        .lazy()
+        .join_with_build_table(table.clone(), ["dim_id"], JoinType::Left, None)
+        .filter(col("name_right").is_not_null());
+    assert_eq!(
+        lf.clone()
+            .collect_schema()?
+            .iter_names()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>(),
+        ["dim_id", "name", "name_right"]
+    );
+
+    let expected = df![
+        "dim_id" => [3, 1],
+        "name" => ["x", "y"],
+        "name_right" => ["c", "a"],
+    ]?;
+    assert!(lf.collect()?.equals(&expected));
+
+    // The key data types are checked against the table.
+    let lf = df!["dim_id" => [1.0]]?.lazy().join_with_build_table(
+        table,
+        ["dim_id"],
+        JoinType::Inner,
+        None,
+    );
+    assert!(lf.collect().is_err());
+    Ok(())
+}